@@ -0,0 +1,32 @@
+use crate::config::error::ConfigError;
+use iam::{
+    application::ports::outbound::audit_log::AuditLogPort,
+    infrastructure::persistence::{
+        in_memory::audit_log::InMemoryAuditLog, json_lines::audit_log::JsonLinesAuditLog,
+    },
+};
+use std::{path::PathBuf, sync::Arc};
+
+pub struct AuditConfig {
+    pub log_path: Option<PathBuf>,
+}
+
+impl AuditConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let log_path = std::env::var("AUDIT_LOG_PATH")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(PathBuf::from);
+
+        Ok(Self { log_path })
+    }
+
+    pub fn audit_log(&self) -> Result<Arc<dyn AuditLogPort>, ConfigError> {
+        match &self.log_path {
+            Some(path) => JsonLinesAuditLog::open(path)
+                .map(|log| Arc::new(log) as Arc<dyn AuditLogPort>)
+                .map_err(|_| ConfigError::Invalid("AUDIT_LOG_PATH")),
+            None => Ok(Arc::new(InMemoryAuditLog::new())),
+        }
+    }
+}
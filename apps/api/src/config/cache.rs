@@ -0,0 +1,78 @@
+use crate::config::error::ConfigError;
+use communities::{
+    application::ports::outbound::community_repository::CommunityRepositoryPort,
+    infrastructure::persistence::caching::community_repository::CachingCommunityRepository,
+};
+use iam::{
+    application::ports::outbound::account_repository::AccountRepositoryPort,
+    infrastructure::persistence::caching::account_repository::CachingAccountRepository,
+};
+use shared::{application::ports::clock::ClockPort, infrastructure::cache::CachePolicy};
+use std::{sync::Arc, time::Duration};
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+const DEFAULT_CACHE_NEGATIVE_TTL: Duration = Duration::from_secs(5);
+
+pub struct CacheConfig {
+    pub policy: Option<CachePolicy>,
+}
+
+impl CacheConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let capacity = match std::env::var("REPOSITORY_CACHE_CAPACITY") {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map_err(|_| ConfigError::Invalid("REPOSITORY_CACHE_CAPACITY"))?,
+            Err(_) => 0,
+        };
+        let ttl = seconds_from_env("REPOSITORY_CACHE_TTL_SECONDS", DEFAULT_CACHE_TTL)?;
+        let negative_ttl = seconds_from_env(
+            "REPOSITORY_CACHE_NEGATIVE_TTL_SECONDS",
+            DEFAULT_CACHE_NEGATIVE_TTL,
+        )?;
+
+        Ok(Self {
+            policy: (capacity > 0).then_some(CachePolicy {
+                capacity,
+                ttl,
+                negative_ttl,
+            }),
+        })
+    }
+
+    pub fn account_repository(
+        &self,
+        inner: Arc<dyn AccountRepositoryPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Arc<dyn AccountRepositoryPort> {
+        match self.policy {
+            Some(policy) => Arc::new(CachingAccountRepository::new(inner, policy, clock)),
+            None => inner,
+        }
+    }
+
+    pub fn community_repository(
+        &self,
+        inner: Arc<dyn CommunityRepositoryPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Arc<dyn CommunityRepositoryPort> {
+        match self.policy {
+            Some(policy) => Arc::new(CachingCommunityRepository::new(inner, policy, clock)),
+            None => inner,
+        }
+    }
+}
+
+fn seconds_from_env(name: &'static str, default: Duration) -> Result<Duration, ConfigError> {
+    match std::env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .map(Duration::from_secs)
+            .ok_or(ConfigError::Invalid(name)),
+        Err(_) => Ok(default),
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{config::error::ConfigError, state::persistence::Persistence};
+use communities::infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository;
+use iam::infrastructure::persistence::in_memory::{
+    account_repository::InMemoryAccountRepository, profile_repository::InMemoryProfileRepository,
+};
+use membership::infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository as InMemoryMembershipRepository;
+use shared::infrastructure::{
+    outbox::InMemoryOutboxStore,
+    persistence::{
+        json_snapshot::JsonSnapshotStore,
+        sqlite::{SqliteDatabase, SqliteOutboxStore},
+    },
+};
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+const DEFAULT_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+pub struct DatabaseConfig {
+    pub path: Option<PathBuf>,
+    pub snapshot_dir: Option<PathBuf>,
+    pub snapshot_interval: Duration,
+}
+
+impl DatabaseConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let path = std::env::var("DATABASE_PATH")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(PathBuf::from);
+        let snapshot_dir = std::env::var("SNAPSHOT_DIR")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(PathBuf::from);
+        let snapshot_interval = match std::env::var("SNAPSHOT_INTERVAL_SECONDS") {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .ok()
+                .filter(|seconds| *seconds > 0)
+                .map(Duration::from_secs)
+                .ok_or(ConfigError::Invalid("SNAPSHOT_INTERVAL_SECONDS"))?,
+            Err(_) => DEFAULT_SNAPSHOT_INTERVAL,
+        };
+
+        Ok(Self {
+            path,
+            snapshot_dir,
+            snapshot_interval,
+        })
+    }
+
+    pub fn persistence(&self) -> Result<Persistence, ConfigError> {
+        match (&self.path, &self.snapshot_dir) {
+            (Some(path), _) => {
+                let database = SqliteDatabase::open(path)
+                    .map(Arc::new)
+                    .map_err(|_| ConfigError::Invalid("DATABASE_PATH"))?;
+                let outbox = Arc::new(SqliteOutboxStore::new(database.clone()));
+
+                Ok(Persistence::Sqlite { database, outbox })
+            }
+            (None, Some(directory)) => {
+                let open = |name| {
+                    JsonSnapshotStore::open(directory, name)
+                        .map(Arc::new)
+                        .map_err(ConfigError::Snapshot)
+                };
+                let outbox = InMemoryOutboxStore::with_snapshots(open("outbox")?)
+                    .map(Arc::new)
+                    .map_err(ConfigError::Snapshot)?;
+                let accounts =
+                    InMemoryAccountRepository::with_snapshots(outbox.clone(), open("accounts")?)
+                        .map(Arc::new)
+                        .map_err(ConfigError::Snapshot)?;
+                let profiles = InMemoryProfileRepository::with_snapshots(open("profiles")?)
+                    .map(Arc::new)
+                    .map_err(ConfigError::Snapshot)?;
+                let communities = InMemoryCommunityRepository::with_snapshots(
+                    outbox.clone(),
+                    open("communities")?,
+                )
+                .map(Arc::new)
+                .map_err(ConfigError::Snapshot)?;
+                let memberships =
+                    InMemoryMembershipRepository::with_snapshots(open("memberships")?)
+                        .map(Arc::new)
+                        .map_err(ConfigError::Snapshot)?;
+
+                Ok(Persistence::Snapshot {
+                    outbox,
+                    accounts,
+                    profiles,
+                    communities,
+                    memberships,
+                    interval: self.snapshot_interval,
+                })
+            }
+            (None, None) => Ok(Persistence::InMemory {
+                outbox: Arc::new(InMemoryOutboxStore::new()),
+                profiles: Arc::new(InMemoryProfileRepository::new()),
+                memberships: Arc::new(InMemoryMembershipRepository::new()),
+            }),
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use shared::infrastructure::persistence::json_snapshot::SnapshotError;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub enum ConfigError {
     Missing(&'static str),
+    Invalid(&'static str),
+    Snapshot(SnapshotError),
 }
 
 impl Display for ConfigError {
@@ -11,6 +14,10 @@ impl Display for ConfigError {
             ConfigError::Missing(var) => {
                 write!(f, "Missing required environment variable: {}", var)
             }
+            ConfigError::Invalid(var) => {
+                write!(f, "Invalid value for environment variable: {}", var)
+            }
+            ConfigError::Snapshot(e) => write!(f, "Could not recover snapshots: {}", e),
         }
     }
 }
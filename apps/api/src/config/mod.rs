@@ -1,2 +1,6 @@
+pub mod audit;
+pub mod cache;
+pub mod database;
 pub mod error;
 pub mod jwt;
+pub mod username;
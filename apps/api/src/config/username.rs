@@ -0,0 +1,40 @@
+use crate::config::error::ConfigError;
+use iam::application::policies::username_policy::{
+    DEFAULT_RESERVED_USERNAMES, UsernameCharset, UsernamePolicy,
+};
+
+pub struct UsernameConfig {
+    pub charset: UsernameCharset,
+    pub reserved: Vec<String>,
+}
+
+impl UsernameConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let charset = match std::env::var("USERNAME_CHARSET") {
+            Ok(value) => {
+                UsernameCharset::parse(&value).ok_or(ConfigError::Invalid("USERNAME_CHARSET"))?
+            }
+            Err(_) => UsernameCharset::Ascii,
+        };
+
+        let mut reserved: Vec<String> = DEFAULT_RESERVED_USERNAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        if let Ok(value) = std::env::var("USERNAME_RESERVED") {
+            reserved.extend(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string),
+            );
+        }
+
+        Ok(Self { charset, reserved })
+    }
+
+    pub fn policy(&self) -> UsernamePolicy {
+        UsernamePolicy::new(self.charset, &self.reserved)
+    }
+}
@@ -0,0 +1,7 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
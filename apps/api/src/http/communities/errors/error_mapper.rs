@@ -1,19 +1,37 @@
 use crate::http::common::errors::api_error_response::ApiErrorResponse;
 use axum::{
+    Json,
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
 use communities::{
     application::errors::error_codes::{
-        COMMUNITIES_REPOSITORY_ERROR, COMMUNITIES_SLUG_ALREADY_EXISTS,
+        COMMUNITIES_COMMUNITY_NOT_FOUND, COMMUNITIES_CONCURRENT_MODIFICATION,
+        COMMUNITIES_INVALID_CURSOR, COMMUNITIES_INVALID_SORT, COMMUNITIES_MEMBERSHIP_UNAVAILABLE,
+        COMMUNITIES_REPOSITORY_ERROR, COMMUNITIES_REPOSITORY_UNAVAILABLE,
+        COMMUNITIES_SLUG_ALREADY_EXISTS,
     },
     domain::errors::error_codes::{
+        COMMUNITIES_INSUFFICIENT_PERMISSIONS, COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION,
         COMMUNITIES_INVALID_COMMUNITY_NAME, COMMUNITIES_INVALID_COMMUNITY_SLUG,
+        COMMUNITIES_INVALID_MEMBERSHIP_POLICY, COMMUNITIES_NOT_OWNER,
     },
 };
 use iam::domain::errors::error_codes::{IAM_INVALID_ACCOUNT_ID, IAM_INVALID_ACCOUNT_ID_FORMAT};
-use shared::error::SystemError;
+use membership::application::errors::{
+    application_error::ApplicationError,
+    error_codes::{
+        MEMBERSHIP_ALREADY_MEMBER, MEMBERSHIP_BANNED, MEMBERSHIP_CONFLICT, MEMBERSHIP_ERROR,
+        MEMBERSHIP_INVITATION_REQUIRED, MEMBERSHIP_JOINING_CLOSED,
+    },
+};
+use shared::{
+    application::{
+        common_application_error::CommonApplicationError,
+        errors::error_codes::{SHARED_UNIT_OF_WORK_CONFLICT, SHARED_UNIT_OF_WORK_ERROR},
+    },
+    error::SystemError,
+};
 
 pub fn map_application_error(error: SystemError) -> Response {
     let (status, code, message) = match error {
@@ -35,14 +53,82 @@ pub fn map_application_error(error: SystemError) -> Response {
     (status, Json(body)).into_response()
 }
 
+pub fn map_membership_error(error: ApplicationError) -> Response {
+    let (status, code, message) = match error {
+        ApplicationError::CommunityNotFound => (
+            StatusCode::NOT_FOUND,
+            COMMUNITIES_COMMUNITY_NOT_FOUND,
+            "Community not found",
+        ),
+        ApplicationError::AlreadyMember => (
+            StatusCode::CONFLICT,
+            MEMBERSHIP_ALREADY_MEMBER,
+            "Already a member of this community",
+        ),
+        ApplicationError::InvitationRequired => (
+            StatusCode::FORBIDDEN,
+            MEMBERSHIP_INVITATION_REQUIRED,
+            "This community can only be joined by invitation",
+        ),
+        ApplicationError::JoiningClosed => (
+            StatusCode::FORBIDDEN,
+            MEMBERSHIP_JOINING_CLOSED,
+            "This community is not accepting new members",
+        ),
+        ApplicationError::Conflict => (
+            StatusCode::CONFLICT,
+            MEMBERSHIP_CONFLICT,
+            "The community changed while joining, please try again",
+        ),
+        ApplicationError::Banned => (
+            StatusCode::FORBIDDEN,
+            MEMBERSHIP_BANNED,
+            "You have been banned from this community",
+        ),
+        ApplicationError::Common(CommonApplicationError::Unauthorized) => {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+        ApplicationError::Common(CommonApplicationError::Infrastructure) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            COMMUNITIES_MEMBERSHIP_UNAVAILABLE,
+            "Memberships are temporarily unavailable",
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            MEMBERSHIP_ERROR,
+            "Unexpected membership error",
+        ),
+    };
+
+    let body = ApiErrorResponse {
+        code: code.to_string(),
+        message: message.to_string(),
+    };
+
+    (status, Json(body)).into_response()
+}
+
 fn status_from_error_code(code: &str) -> StatusCode {
     match code {
-        COMMUNITIES_SLUG_ALREADY_EXISTS => StatusCode::CONFLICT,
+        COMMUNITIES_SLUG_ALREADY_EXISTS
+        | COMMUNITIES_CONCURRENT_MODIFICATION
+        | SHARED_UNIT_OF_WORK_CONFLICT => StatusCode::CONFLICT,
+        COMMUNITIES_COMMUNITY_NOT_FOUND => StatusCode::NOT_FOUND,
+        COMMUNITIES_INSUFFICIENT_PERMISSIONS | COMMUNITIES_NOT_OWNER => StatusCode::FORBIDDEN,
         COMMUNITIES_INVALID_COMMUNITY_NAME
+        | COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION
         | COMMUNITIES_INVALID_COMMUNITY_SLUG
+        | COMMUNITIES_INVALID_MEMBERSHIP_POLICY
+        | COMMUNITIES_INVALID_SORT
+        | COMMUNITIES_INVALID_CURSOR
         | IAM_INVALID_ACCOUNT_ID
         | IAM_INVALID_ACCOUNT_ID_FORMAT => StatusCode::BAD_REQUEST,
-        COMMUNITIES_REPOSITORY_ERROR => StatusCode::INTERNAL_SERVER_ERROR,
+        COMMUNITIES_REPOSITORY_ERROR | SHARED_UNIT_OF_WORK_ERROR => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+        COMMUNITIES_REPOSITORY_UNAVAILABLE | COMMUNITIES_MEMBERSHIP_UNAVAILABLE => {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
         _ => StatusCode::INTERNAL_SERVER_ERROR,
     }
 }
@@ -0,0 +1,42 @@
+use crate::{
+    http::communities::{
+        errors::error_mapper::map_application_error,
+        responses::{community::CommunityResponse, moved::MovedResponse},
+    },
+    middleware::auth::authenticate_optional,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use communities::application::commands::get_community::GetCommunity;
+use http::{HeaderMap, StatusCode, header};
+
+pub async fn get_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Path(identifier): Path<String>,
+) -> Response {
+    let auth_context = match authenticate_optional(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .communities
+        .get_community
+        .execute(GetCommunity { identifier }, auth_context)
+        .await
+    {
+        Ok(result) if result.moved_from.is_some() => (
+            StatusCode::MOVED_PERMANENTLY,
+            [(header::LOCATION, format!("/communities/{}", result.slug))],
+            Json(MovedResponse { slug: result.slug }),
+        )
+            .into_response(),
+        Ok(result) => (StatusCode::OK, Json(CommunityResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
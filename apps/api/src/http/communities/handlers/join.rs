@@ -0,0 +1,35 @@
+use crate::{
+    http::communities::{
+        errors::error_mapper::map_membership_error, responses::joined::JoinedResponse,
+    },
+    middleware::auth::authenticate,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use http::{HeaderMap, StatusCode};
+use membership::application::commands::join_community::JoinCommunity;
+
+pub async fn join_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Response {
+    let auth_context = match authenticate(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .communities
+        .join_community
+        .execute(JoinCommunity { slug }, auth_context)
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(JoinedResponse::from(result))).into_response(),
+        Err(err) => map_membership_error(err),
+    }
+}
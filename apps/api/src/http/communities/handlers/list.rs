@@ -0,0 +1,40 @@
+use crate::{
+    http::communities::{
+        errors::error_mapper::map_application_error, requests::list::ListPublicCommunitiesQuery,
+        responses::public_communities::PublicCommunitiesResponse,
+    },
+    middleware::auth::authenticate_optional,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+};
+use communities::application::commands::list_public_communities::ListPublicCommunities;
+use http::{HeaderMap, StatusCode};
+
+pub async fn list_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Query(query): Query<ListPublicCommunitiesQuery>,
+) -> Response {
+    let auth_context = match authenticate_optional(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .communities
+        .list_public_communities
+        .execute(ListPublicCommunities::from(query), auth_context)
+        .await
+    {
+        Ok(result) => (
+            StatusCode::OK,
+            Json(PublicCommunitiesResponse::from(result)),
+        )
+            .into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
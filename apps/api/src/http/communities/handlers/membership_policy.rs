@@ -0,0 +1,37 @@
+use crate::{
+    http::communities::{
+        errors::error_mapper::map_application_error,
+        requests::membership_policy::MembershipPolicyRequest,
+        responses::community::CommunityResponse,
+    },
+    middleware::auth::authenticate,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use http::{HeaderMap, StatusCode};
+
+pub async fn membership_policy_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    Json(request): Json<MembershipPolicyRequest>,
+) -> Response {
+    let auth_context = match authenticate(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .communities
+        .change_membership_policy
+        .execute(request.into_command(slug), auth_context)
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(CommunityResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
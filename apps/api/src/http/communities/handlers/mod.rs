@@ -1 +1,7 @@
+pub mod change_slug;
 pub mod create;
+pub mod get;
+pub mod join;
+pub mod list;
+pub mod membership_policy;
+pub mod update;
@@ -0,0 +1,16 @@
+use communities::application::commands::change_community_slug::ChangeCommunitySlug;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeSlugRequest {
+    pub slug: String,
+}
+
+impl ChangeSlugRequest {
+    pub fn into_command(self, slug: String) -> ChangeCommunitySlug {
+        ChangeCommunitySlug {
+            slug,
+            new_slug: self.slug,
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub struct CreateRequest {
     pub slug: String,
     pub name: String,
     pub is_public: bool,
+    pub membership_policy: Option<String>,
 }
 
 impl From<CreateRequest> for CreateCommunity {
@@ -14,6 +15,7 @@ impl From<CreateRequest> for CreateCommunity {
             slug: req.slug,
             name: req.name,
             is_public: req.is_public,
+            membership_policy: req.membership_policy,
         }
     }
 }
@@ -0,0 +1,21 @@
+use communities::application::commands::list_public_communities::ListPublicCommunities;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ListPublicCommunitiesQuery {
+    pub q: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+}
+
+impl From<ListPublicCommunitiesQuery> for ListPublicCommunities {
+    fn from(query: ListPublicCommunitiesQuery) -> Self {
+        ListPublicCommunities {
+            query: query.q,
+            sort: query.sort,
+            limit: query.limit,
+            cursor: query.cursor,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use communities::application::commands::change_membership_policy::ChangeMembershipPolicy;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct MembershipPolicyRequest {
+    pub policy: String,
+}
+
+impl MembershipPolicyRequest {
+    pub fn into_command(self, slug: String) -> ChangeMembershipPolicy {
+        ChangeMembershipPolicy {
+            slug,
+            policy: self.policy,
+        }
+    }
+}
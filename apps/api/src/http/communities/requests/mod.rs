@@ -1 +1,5 @@
+pub mod change_slug;
 pub mod create;
+pub mod list;
+pub mod membership_policy;
+pub mod update;
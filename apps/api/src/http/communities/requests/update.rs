@@ -0,0 +1,20 @@
+use communities::application::commands::update_community::UpdateCommunity;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRequest {
+    pub name: Option<String>,
+    pub is_public: Option<bool>,
+    pub description: Option<String>,
+}
+
+impl UpdateRequest {
+    pub fn into_command(self, slug: String) -> UpdateCommunity {
+        UpdateCommunity {
+            slug,
+            name: self.name,
+            is_public: self.is_public,
+            description: self.description,
+        }
+    }
+}
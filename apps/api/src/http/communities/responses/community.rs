@@ -0,0 +1,46 @@
+use communities::application::results::{
+    community_retrieved::CommunityRetrieved, community_updated::CommunityUpdated,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CommunityResponse {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub membership_policy: String,
+    pub owner_id: String,
+    pub member_count: u64,
+}
+
+impl From<CommunityRetrieved> for CommunityResponse {
+    fn from(dto: CommunityRetrieved) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            slug: dto.slug,
+            description: dto.description,
+            is_public: dto.is_public,
+            membership_policy: dto.membership_policy,
+            owner_id: dto.owner_id,
+            member_count: dto.member_count,
+        }
+    }
+}
+
+impl From<CommunityUpdated> for CommunityResponse {
+    fn from(dto: CommunityUpdated) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            slug: dto.slug,
+            description: dto.description,
+            is_public: dto.is_public,
+            membership_policy: dto.membership_policy,
+            owner_id: dto.owner_id,
+            member_count: dto.member_count,
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub struct CreatedResponse {
     pub id: String,
     pub name: String,
     pub slug: String,
+    pub membership_policy: String,
 }
 
 impl From<CommunityCreated> for CreatedResponse {
@@ -14,6 +15,7 @@ impl From<CommunityCreated> for CreatedResponse {
             id: dto.id,
             name: dto.name,
             slug: dto.slug,
+            membership_policy: dto.membership_policy,
         }
     }
 }
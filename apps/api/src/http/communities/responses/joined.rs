@@ -0,0 +1,17 @@
+use membership::application::results::community_joined::CommunityJoined;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct JoinedResponse {
+    pub community_id: String,
+    pub status: String,
+}
+
+impl From<CommunityJoined> for JoinedResponse {
+    fn from(dto: CommunityJoined) -> Self {
+        Self {
+            community_id: dto.community_id,
+            status: dto.status,
+        }
+    }
+}
@@ -1 +1,5 @@
+pub mod community;
 pub mod created;
+pub mod joined;
+pub mod moved;
+pub mod public_communities;
@@ -0,0 +1,47 @@
+use crate::http::common::time::unix_seconds;
+use communities::application::results::public_communities_listed::{
+    CommunityResult, PublicCommunitiesListed,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PublicCommunityResponse {
+    pub name: String,
+    pub slug: String,
+    pub created_at: u64,
+    pub member_count: u64,
+}
+
+#[derive(Serialize)]
+pub struct PublicCommunitiesResponse {
+    pub communities: Vec<PublicCommunityResponse>,
+    pub next_cursor: Option<String>,
+    pub previous_cursor: Option<String>,
+    pub total_estimate: u64,
+}
+
+impl From<CommunityResult> for PublicCommunityResponse {
+    fn from(dto: CommunityResult) -> Self {
+        Self {
+            name: dto.name,
+            slug: dto.slug,
+            created_at: unix_seconds(dto.created_at),
+            member_count: dto.member_count,
+        }
+    }
+}
+
+impl From<PublicCommunitiesListed> for PublicCommunitiesResponse {
+    fn from(dto: PublicCommunitiesListed) -> Self {
+        Self {
+            communities: dto
+                .communities
+                .into_iter()
+                .map(PublicCommunityResponse::from)
+                .collect(),
+            next_cursor: dto.next_cursor,
+            previous_cursor: dto.previous_cursor,
+            total_estimate: dto.total_estimate,
+        }
+    }
+}
@@ -1,19 +1,25 @@
 use crate::http::common::errors::api_error_response::ApiErrorResponse;
 use axum::{
+    Json,
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
 use iam::{
     application::errors::error_codes::{
-        IAM_ACCOUNT_REPOSITORY_ERROR, IAM_CANNOT_AUTHENTICATE, IAM_LOGIN_FAILED,
-        IAM_PASSWORD_TOO_SHORT, IAM_TOKEN_GENERATOR_ERROR,
+        IAM_ACCOUNT_CONCURRENT_MODIFICATION, IAM_ACCOUNT_REPOSITORY_ERROR,
+        IAM_ACCOUNT_REPOSITORY_UNAVAILABLE, IAM_AUDIT_LOG_ERROR, IAM_CANNOT_AUTHENTICATE,
+        IAM_LOGIN_FAILED, IAM_PASSWORD_TOO_SHORT, IAM_PROFILE_REPOSITORY_ERROR,
+        IAM_TOKEN_GENERATOR_ERROR, IAM_USERNAME_INVALID_CHARACTERS, IAM_USERNAME_MIXED_SCRIPT,
+        IAM_USERNAME_RESERVED,
     },
     domain::errors::error_codes::{
         IAM_ACCOUNT_EMAIL_ALREADY_EXISTS, IAM_ACCOUNT_INVALID_VERIFICATION, IAM_ACCOUNT_NOT_FOUND,
-        IAM_ACCOUNT_USERNAME_ALREADY_EXISTS, IAM_INVALID_ACCOUNT_ID, IAM_INVALID_ACCOUNT_ID_FORMAT,
-        IAM_INVALID_ACCOUNT_STATUS_TRANSITION, IAM_INVALID_CODE_VALIDATION, IAM_INVALID_EMAIL,
-        IAM_INVALID_HASHED_PASSWORD, IAM_INVALID_USERNAME,
+        IAM_ACCOUNT_USERNAME_ALREADY_EXISTS, IAM_ACCOUNT_VERIFICATION_EXPIRED,
+        IAM_INVALID_ACCOUNT_ID, IAM_INVALID_ACCOUNT_ID_FORMAT,
+        IAM_INVALID_ACCOUNT_STATUS_TRANSITION, IAM_INVALID_AVATAR, IAM_INVALID_BIO,
+        IAM_INVALID_CODE_VALIDATION, IAM_INVALID_DISPLAY_NAME, IAM_INVALID_EMAIL,
+        IAM_INVALID_HASHED_PASSWORD, IAM_INVALID_LOCALE, IAM_INVALID_PROFILE_VISIBILITY,
+        IAM_INVALID_TIMEZONE, IAM_INVALID_USERNAME,
     },
 };
 use shared::error::SystemError;
@@ -40,9 +46,9 @@ pub fn map_application_error(error: SystemError) -> Response {
 
 fn status_from_error_code(code: &str) -> StatusCode {
     match code {
-        IAM_ACCOUNT_EMAIL_ALREADY_EXISTS | IAM_ACCOUNT_USERNAME_ALREADY_EXISTS => {
-            StatusCode::CONFLICT
-        }
+        IAM_ACCOUNT_EMAIL_ALREADY_EXISTS
+        | IAM_ACCOUNT_USERNAME_ALREADY_EXISTS
+        | IAM_ACCOUNT_CONCURRENT_MODIFICATION => StatusCode::CONFLICT,
         IAM_ACCOUNT_NOT_FOUND => StatusCode::NOT_FOUND,
         IAM_LOGIN_FAILED | IAM_CANNOT_AUTHENTICATE => StatusCode::UNAUTHORIZED,
         IAM_INVALID_ACCOUNT_ID
@@ -52,11 +58,23 @@ fn status_from_error_code(code: &str) -> StatusCode {
         | IAM_INVALID_EMAIL
         | IAM_INVALID_HASHED_PASSWORD
         | IAM_INVALID_USERNAME
+        | IAM_INVALID_DISPLAY_NAME
+        | IAM_INVALID_BIO
+        | IAM_INVALID_AVATAR
+        | IAM_INVALID_LOCALE
+        | IAM_INVALID_TIMEZONE
+        | IAM_INVALID_PROFILE_VISIBILITY
         | IAM_PASSWORD_TOO_SHORT
-        | IAM_ACCOUNT_INVALID_VERIFICATION => StatusCode::BAD_REQUEST,
-        IAM_ACCOUNT_REPOSITORY_ERROR | IAM_TOKEN_GENERATOR_ERROR => {
-            StatusCode::INTERNAL_SERVER_ERROR
-        }
+        | IAM_USERNAME_INVALID_CHARACTERS
+        | IAM_USERNAME_MIXED_SCRIPT
+        | IAM_USERNAME_RESERVED
+        | IAM_ACCOUNT_INVALID_VERIFICATION
+        | IAM_ACCOUNT_VERIFICATION_EXPIRED => StatusCode::BAD_REQUEST,
+        IAM_ACCOUNT_REPOSITORY_ERROR
+        | IAM_AUDIT_LOG_ERROR
+        | IAM_PROFILE_REPOSITORY_ERROR
+        | IAM_TOKEN_GENERATOR_ERROR => StatusCode::INTERNAL_SERVER_ERROR,
+        IAM_ACCOUNT_REPOSITORY_UNAVAILABLE => StatusCode::SERVICE_UNAVAILABLE,
         _ => StatusCode::INTERNAL_SERVER_ERROR,
     }
 }
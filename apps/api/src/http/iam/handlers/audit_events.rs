@@ -0,0 +1,37 @@
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error,
+        requests::list_audit_events::ListAuditEventsQuery,
+        responses::audit_events::AuditEventsResponse,
+    },
+    middleware::auth::authenticate,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use iam::application::commands::list_audit_events::ListAuditEvents;
+
+pub async fn audit_events_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Query(query): Query<ListAuditEventsQuery>,
+) -> Response {
+    let auth_context = match authenticate(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .iam
+        .list_audit_events
+        .execute(ListAuditEvents::from(query), auth_context)
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(AuditEventsResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    http::iam::{errors::error_mapper::map_application_error, responses::profile::ProfileResponse},
+    middleware::auth::authenticate,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+pub async fn get_profile_handler(headers: HeaderMap, State(state): State<AppState>) -> Response {
+    let auth_context = match authenticate(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state.iam.get_own_profile.execute(auth_context).await {
+        Ok(result) => (StatusCode::OK, Json(ProfileResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
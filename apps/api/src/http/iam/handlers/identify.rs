@@ -1,14 +1,18 @@
-use axum::Json;
-use axum::extract::State;
-use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error, requests::identify::IdentifyRequest,
+        responses::identified::IdentifiedResponse,
+    },
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use iam::application::commands::identify_account::IdentifyAccount;
 
-use crate::http::iam::errors::error_mapper::map_application_error;
-use crate::http::iam::requests::identify::IdentifyRequest;
-use crate::http::iam::responses::identified::IdentifiedResponse;
-use crate::state::app::AppState;
-
 pub async fn identify_handler(
     State(state): State<AppState>,
     Json(request): Json<IdentifyRequest>,
@@ -17,6 +21,7 @@ pub async fn identify_handler(
         .iam
         .identify_account
         .execute(IdentifyAccount::from(request))
+        .await
     {
         Ok(result) => (StatusCode::OK, Json(IdentifiedResponse::from(result))).into_response(),
         Err(err) => map_application_error(err),
@@ -1,4 +1,9 @@
+pub mod audit_events;
+pub mod get_profile;
 pub mod identify;
+pub mod me;
+pub mod public_profile;
 pub mod sign_in;
 pub mod sign_up;
+pub mod update_profile;
 pub mod verify;
@@ -0,0 +1,36 @@
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error,
+        responses::public_profile::PublicProfileResponse,
+    },
+    middleware::auth::authenticate_optional,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use iam::application::commands::view_public_profile::ViewPublicProfile;
+
+pub async fn public_profile_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Path(username): Path<String>,
+) -> Response {
+    let auth_context = match authenticate_optional(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .iam
+        .get_public_profile
+        .execute(ViewPublicProfile { username }, auth_context)
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(PublicProfileResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
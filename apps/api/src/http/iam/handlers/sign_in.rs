@@ -1,22 +1,34 @@
-use axum::Json;
-use axum::extract::State;
-use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error, requests::sign_in::SignInRequest,
+        responses::signed_in::SignedInResponse,
+    },
+    middleware::request_context::request_context,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{ConnectInfo, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
 use iam::application::commands::authenticate_account::AuthenticateAccount;
-
-use crate::http::iam::errors::error_mapper::map_application_error;
-use crate::http::iam::requests::sign_in::SignInRequest;
-use crate::http::iam::responses::signed_in::SignedInResponse;
-use crate::state::app::AppState;
+use std::net::SocketAddr;
 
 pub async fn sign_in_handler(
+    headers: HeaderMap,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
     Json(request): Json<SignInRequest>,
 ) -> Response {
     match state
         .iam
         .authenticate_account
-        .execute(AuthenticateAccount::from(request))
+        .execute(
+            AuthenticateAccount::from(request),
+            request_context(&headers, peer),
+        )
+        .await
     {
         Ok(result) => (StatusCode::OK, Json(SignedInResponse::from(result))).into_response(),
         Err(err) => map_application_error(err),
@@ -1,14 +1,18 @@
-use axum::Json;
-use axum::extract::State;
-use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error, requests::sign_up::SignUpRequest,
+        responses::signed_up::SignedUpResponse,
+    },
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use iam::application::commands::register_account::RegisterAccount;
 
-use crate::http::iam::errors::error_mapper::map_application_error;
-use crate::http::iam::requests::sign_up::SignUpRequest;
-use crate::http::iam::responses::signed_up::SignedUpResponse;
-use crate::state::app::AppState;
-
 pub async fn sign_up_handler(
     State(state): State<AppState>,
     Json(request): Json<SignUpRequest>,
@@ -17,6 +21,7 @@ pub async fn sign_up_handler(
         .iam
         .register_account
         .execute(RegisterAccount::from(request))
+        .await
     {
         Ok(result) => (StatusCode::CREATED, Json(SignedUpResponse::from(result))).into_response(),
         Err(err) => map_application_error(err),
@@ -0,0 +1,36 @@
+use crate::{
+    http::iam::{
+        errors::error_mapper::map_application_error,
+        requests::update_profile::UpdateProfileRequest, responses::profile::ProfileResponse,
+    },
+    middleware::auth::authenticate,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use iam::application::commands::update_profile::UpdateProfile;
+
+pub async fn update_profile_handler(
+    headers: HeaderMap,
+    State(state): State<AppState>,
+    Json(request): Json<UpdateProfileRequest>,
+) -> Response {
+    let auth_context = match authenticate(&headers, state.token_validator) {
+        Ok(auth) => auth,
+        Err(_) => return StatusCode::UNAUTHORIZED.into_response(),
+    };
+
+    match state
+        .iam
+        .update_profile
+        .execute(UpdateProfile::from(request), auth_context)
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(ProfileResponse::from(result))).into_response(),
+        Err(err) => map_application_error(err),
+    }
+}
@@ -1,21 +1,31 @@
-use axum::Json;
-use axum::extract::State;
-use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
+use crate::{
+    http::iam::{errors::error_mapper::map_application_error, requests::verify::VerifyRequest},
+    middleware::request_context::request_context,
+    state::app::AppState,
+};
+use axum::{
+    Json,
+    extract::{ConnectInfo, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
 use iam::application::commands::verify_account::VerifyAccount;
-
-use crate::http::iam::errors::error_mapper::map_application_error;
-use crate::http::iam::requests::verify::VerifyRequest;
-use crate::state::app::AppState;
+use std::net::SocketAddr;
 
 pub async fn verify_handler(
+    headers: HeaderMap,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
     Json(request): Json<VerifyRequest>,
 ) -> Response {
     match state
         .iam
         .verify_account
-        .execute(VerifyAccount::from(request))
+        .execute(
+            VerifyAccount::from(request),
+            request_context(&headers, peer),
+        )
+        .await
     {
         Ok(result) => (StatusCode::OK, Json(result)).into_response(),
         Err(err) => map_application_error(err),
@@ -0,0 +1,13 @@
+use iam::application::commands::list_audit_events::ListAuditEvents;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ListAuditEventsQuery {
+    pub limit: Option<usize>,
+}
+
+impl From<ListAuditEventsQuery> for ListAuditEvents {
+    fn from(query: ListAuditEventsQuery) -> Self {
+        ListAuditEvents { limit: query.limit }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod identify;
+pub mod list_audit_events;
 pub mod sign_in;
 pub mod sign_up;
+pub mod update_profile;
 pub mod verify;
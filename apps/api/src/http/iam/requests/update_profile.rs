@@ -0,0 +1,25 @@
+use iam::application::commands::update_profile::UpdateProfile;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateProfileRequest {
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub visibility: Option<String>,
+}
+
+impl From<UpdateProfileRequest> for UpdateProfile {
+    fn from(req: UpdateProfileRequest) -> Self {
+        UpdateProfile {
+            display_name: req.display_name,
+            bio: req.bio,
+            avatar: req.avatar,
+            locale: req.locale,
+            timezone: req.timezone,
+            visibility: req.visibility,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::http::common::time::unix_seconds;
+use iam::application::results::audit_events_listed::{AuditEventListed, AuditEventsListed};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct AuditEventResponse {
+    pub id: String,
+    pub event_type: String,
+    pub actor_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub occurred_at: u64,
+}
+
+#[derive(Serialize)]
+pub struct AuditEventsResponse {
+    pub events: Vec<AuditEventResponse>,
+}
+
+impl From<AuditEventListed> for AuditEventResponse {
+    fn from(dto: AuditEventListed) -> Self {
+        Self {
+            id: dto.id,
+            event_type: dto.event_type,
+            actor_id: dto.actor_id,
+            ip_address: dto.ip_address,
+            user_agent: dto.user_agent,
+            occurred_at: unix_seconds(dto.occurred_at),
+        }
+    }
+}
+
+impl From<AuditEventsListed> for AuditEventsResponse {
+    fn from(dto: AuditEventsListed) -> Self {
+        Self {
+            events: dto
+                .events
+                .into_iter()
+                .map(AuditEventResponse::from)
+                .collect(),
+        }
+    }
+}
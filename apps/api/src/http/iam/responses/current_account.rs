@@ -0,0 +1,32 @@
+use crate::http::common::time::unix_seconds;
+use iam::application::results::current_account_retrieved::CurrentAccountRetrieved;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CurrentAccountResponse {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub status: String,
+    pub verified: bool,
+    pub roles: Vec<String>,
+    pub created_at: u64,
+    pub verified_at: Option<u64>,
+    pub last_login_at: Option<u64>,
+}
+
+impl From<CurrentAccountRetrieved> for CurrentAccountResponse {
+    fn from(dto: CurrentAccountRetrieved) -> Self {
+        Self {
+            id: dto.id,
+            username: dto.username,
+            email: dto.email,
+            status: dto.status,
+            verified: dto.verified,
+            roles: dto.roles,
+            created_at: unix_seconds(dto.created_at),
+            verified_at: dto.verified_at.map(unix_seconds),
+            last_login_at: dto.last_login_at.map(unix_seconds),
+        }
+    }
+}
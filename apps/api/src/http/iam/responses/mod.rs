@@ -1,3 +1,7 @@
+pub mod audit_events;
+pub mod current_account;
 pub mod identified;
+pub mod profile;
+pub mod public_profile;
 pub mod signed_in;
 pub mod signed_up;
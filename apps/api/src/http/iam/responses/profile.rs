@@ -0,0 +1,27 @@
+use iam::application::results::profile_retrieved::ProfileRetrieved;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProfileResponse {
+    pub account_id: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub locale: String,
+    pub timezone: String,
+    pub visibility: String,
+}
+
+impl From<ProfileRetrieved> for ProfileResponse {
+    fn from(dto: ProfileRetrieved) -> Self {
+        Self {
+            account_id: dto.account_id,
+            display_name: dto.display_name,
+            bio: dto.bio,
+            avatar: dto.avatar,
+            locale: dto.locale,
+            timezone: dto.timezone,
+            visibility: dto.visibility,
+        }
+    }
+}
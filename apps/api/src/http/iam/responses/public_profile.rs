@@ -0,0 +1,24 @@
+use crate::http::common::time::unix_seconds;
+use iam::application::results::public_profile_retrieved::PublicProfileRetrieved;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PublicProfileResponse {
+    pub username: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub member_since: u64,
+}
+
+impl From<PublicProfileRetrieved> for PublicProfileResponse {
+    fn from(dto: PublicProfileRetrieved) -> Self {
+        Self {
+            username: dto.username,
+            display_name: dto.display_name,
+            bio: dto.bio,
+            avatar: dto.avatar,
+            member_since: unix_seconds(dto.member_since),
+        }
+    }
+}
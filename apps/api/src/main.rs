@@ -5,32 +5,96 @@ mod middleware;
 mod routes;
 mod state;
 
-use std::sync::Arc;
-
+use crate::{
+    authentication::token_validator::JwtValidator,
+    config::{
+        audit::AuditConfig, cache::CacheConfig, database::DatabaseConfig, jwt::JwtConfig,
+        username::UsernameConfig,
+    },
+    routes::{communities_router, iam_router, me_router, users_router},
+    state::{
+        app::AppState, communities::CommunitiesState, iam::IamState, persistence::Persistence,
+    },
+};
 use axum::Router;
 use iam::infrastructure::security::token_generator::jwt_token_generator::JwtTokenGenerator;
+use shared::{
+    application::outbox::OutboxRelay,
+    infrastructure::{clock::SystemClock, events::SyncEventBus, outbox::OutboxRelayWorker},
+};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
-use crate::authentication::token_validator::JwtValidator;
-use crate::config::jwt::JwtConfig;
-use crate::routes::{communities_router, iam_router};
-use crate::state::app::AppState;
-use crate::state::communities::CommunitiesState;
-use crate::state::iam::IamState;
+const OUTBOX_RELAY_INTERVAL: Duration = Duration::from_secs(1);
 
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
+    tracing_subscriber::fmt::init();
+    let persistence = DatabaseConfig::from_env()
+        .and_then(|config| config.persistence())
+        .unwrap_or_else(|e| {
+            eprintln!("Configuration error: {}", e);
+            std::process::exit(1);
+        });
+    if let Some(command) = std::env::args().nth(1) {
+        run_command(&command, &persistence);
+        return;
+    }
     let jwt_config = JwtConfig::from_env().unwrap_or_else(|e| {
         eprintln!("Configuration error: {}", e);
         std::process::exit(1);
     });
+    let username_config = UsernameConfig::from_env().unwrap_or_else(|e| {
+        eprintln!("Configuration error: {}", e);
+        std::process::exit(1);
+    });
+    let audit_log = AuditConfig::from_env()
+        .and_then(|config| config.audit_log())
+        .unwrap_or_else(|e| {
+            eprintln!("Configuration error: {}", e);
+            std::process::exit(1);
+        });
+    let cache_config = CacheConfig::from_env().unwrap_or_else(|e| {
+        eprintln!("Configuration error: {}", e);
+        std::process::exit(1);
+    });
+    let clock = Arc::new(SystemClock::new());
+    let event_bus = Arc::new(SyncEventBus::new());
+    persistence.migrate().unwrap_or_else(|e| {
+        eprintln!("Database migration error: {}", e);
+        std::process::exit(1);
+    });
+    let outbox = persistence.outbox();
     let token_generator = Arc::new(JwtTokenGenerator::new(
         jwt_config.secret,
         jwt_config.expiration_time,
+        clock.clone(),
     ));
 
-    let iam_state = IamState::initialize(token_generator.clone());
-    let communities_state = CommunitiesState::initialize();
+    let iam_state = IamState::initialize(
+        token_generator.clone(),
+        username_config.policy(),
+        audit_log,
+        clock.clone(),
+        &persistence,
+        &cache_config,
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("Configuration error: {}", e);
+        std::process::exit(1);
+    });
+    let communities_state =
+        CommunitiesState::initialize(clock.clone(), &persistence, &cache_config).unwrap_or_else(
+            |e| {
+                eprintln!("Configuration error: {}", e);
+                std::process::exit(1);
+            },
+        );
+    let _snapshot_worker = persistence.snapshot_worker();
+    let _outbox_relay = OutboxRelayWorker::spawn(
+        OutboxRelay::new(outbox.clone(), event_bus.clone(), clock.clone()),
+        OUTBOX_RELAY_INTERVAL,
+    );
 
     let state = AppState {
         iam: iam_state,
@@ -41,9 +105,44 @@ async fn main() {
     let app = Router::new()
         .nest("/auth", iam_router::router())
         .nest("/communities", communities_router::router())
+        .nest("/me", me_router::router())
+        .nest("/users", users_router::router())
         .with_state(state);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
 
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
+}
+
+fn run_command(command: &str, persistence: &Persistence) {
+    match command {
+        "migrate" => {
+            if !matches!(persistence, Persistence::Sqlite { .. }) {
+                eprintln!("DATABASE_PATH must be set to run migrations");
+                std::process::exit(1);
+            }
+            let applied = persistence.migrate().unwrap_or_else(|e| {
+                eprintln!("Database migration error: {}", e);
+                std::process::exit(1);
+            });
+            if applied.is_empty() {
+                println!("Database schema is up to date");
+            }
+            for migration in applied {
+                println!(
+                    "Applied {} migration {} ({})",
+                    migration.context, migration.version, migration.name
+                );
+            }
+        }
+        _ => {
+            eprintln!("Unknown command: {}", command);
+            std::process::exit(2);
+        }
+    }
 }
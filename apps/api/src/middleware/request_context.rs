@@ -0,0 +1,13 @@
+use http::{HeaderMap, header::USER_AGENT};
+use shared::application::request_context::RequestContext;
+use std::net::SocketAddr;
+
+pub fn request_context(headers: &HeaderMap, peer: SocketAddr) -> RequestContext {
+    RequestContext {
+        ip_address: Some(peer.ip().to_string()),
+        user_agent: headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned),
+    }
+}
@@ -1,9 +1,25 @@
-use axum::Router;
-use axum::routing::post;
-
-use crate::http::communities::handlers::create::create_handler;
-use crate::state::app::AppState;
+use crate::{
+    http::communities::handlers::{
+        change_slug::change_slug_handler, create::create_handler, get::get_handler,
+        join::join_handler, list::list_handler, membership_policy::membership_policy_handler,
+        update::update_handler,
+    },
+    state::app::AppState,
+};
+use axum::{
+    Router,
+    routing::{get, post, put},
+};
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/create", post(create_handler))
+    Router::new()
+        .route("/", get(list_handler))
+        .route("/create", post(create_handler))
+        .route("/{identifier}", get(get_handler).patch(update_handler))
+        .route("/{identifier}/slug", put(change_slug_handler))
+        .route(
+            "/{identifier}/membership-policy",
+            put(membership_policy_handler),
+        )
+        .route("/{identifier}/join", post(join_handler))
 }
@@ -0,0 +1,17 @@
+use crate::{
+    http::iam::handlers::{
+        audit_events::audit_events_handler, get_profile::get_profile_handler,
+        update_profile::update_profile_handler,
+    },
+    state::app::AppState,
+};
+use axum::{Router, routing::get};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/profile",
+            get(get_profile_handler).patch(update_profile_handler),
+        )
+        .route("/audit-events", get(audit_events_handler))
+}
@@ -1,2 +1,4 @@
 pub mod communities_router;
 pub mod iam_router;
+pub mod me_router;
+pub mod users_router;
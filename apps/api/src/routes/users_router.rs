@@ -0,0 +1,6 @@
+use crate::{http::iam::handlers::public_profile::public_profile_handler, state::app::AppState};
+use axum::{Router, routing::get};
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/{username}", get(public_profile_handler))
+}
@@ -1,22 +1,101 @@
+use crate::{
+    config::{cache::CacheConfig, error::ConfigError},
+    state::persistence::Persistence,
+};
+use communities::application::{
+    ports::inbound::{
+        community_creation::CommunityCreationPort, community_retrieval::CommunityRetrievalPort,
+        community_slug_change::CommunitySlugChangePort, community_update::CommunityUpdatePort,
+        membership_policy_change::MembershipPolicyChangePort,
+        public_communities_listing::PublicCommunitiesListingPort,
+    },
+    use_cases::{
+        change_community_slug::ChangeCommunitySlugUseCase,
+        change_membership_policy::ChangeMembershipPolicyUseCase,
+        create_community::CreateCommunityUseCase, get_community::GetCommunityUseCase,
+        list_public_communities::ListPublicCommunitiesUseCase,
+        update_community::UpdateCommunityUseCase,
+    },
+};
+use membership::{
+    application::{
+        ports::inbound::community_join::CommunityJoinPort,
+        use_cases::join_community::JoinCommunityUseCase,
+    },
+    infrastructure::{
+        community_directory::CommunityDirectoryAdapter, membership_check::MembershipCheckAdapter,
+        owner_membership::OwnerMembershipAdapter,
+    },
+};
+use shared::application::ports::clock::ClockPort;
 use std::sync::Arc;
 
-use communities::application::ports::inbound::community_creation::CommunityCreationPort;
-use communities::application::use_cases::create_community::CreateCommunityUseCase;
-use communities::infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository;
-
 #[derive(Clone)]
 pub struct CommunitiesState {
     pub create_community: Arc<dyn CommunityCreationPort + Send + Sync>,
+    pub list_public_communities: Arc<dyn PublicCommunitiesListingPort + Send + Sync>,
+    pub get_community: Arc<dyn CommunityRetrievalPort + Send + Sync>,
+    pub update_community: Arc<dyn CommunityUpdatePort + Send + Sync>,
+    pub change_community_slug: Arc<dyn CommunitySlugChangePort + Send + Sync>,
+    pub change_membership_policy: Arc<dyn MembershipPolicyChangePort + Send + Sync>,
+    pub join_community: Arc<dyn CommunityJoinPort + Send + Sync>,
 }
 
 impl CommunitiesState {
-    pub fn initialize() -> Self {
-        let community_repository = Arc::new(InMemoryCommunityRepository::new());
+    pub fn initialize(
+        clock: Arc<dyn ClockPort>,
+        persistence: &Persistence,
+        cache: &CacheConfig,
+    ) -> Result<Self, ConfigError> {
+        let community_repository =
+            cache.community_repository(persistence.community_repository()?, clock.clone());
 
-        let create_community = CreateCommunityUseCase::new(community_repository.clone());
+        let owner_membership = Arc::new(OwnerMembershipAdapter::new(
+            persistence.membership_community_repository()?,
+        ));
+        let create_community = CreateCommunityUseCase::new(
+            community_repository.clone(),
+            owner_membership,
+            persistence.unit_of_work(),
+            clock.clone(),
+        );
+        let list_public_communities =
+            ListPublicCommunitiesUseCase::new(community_repository.clone());
+        let membership_check = Arc::new(MembershipCheckAdapter::new(
+            persistence.membership_repository()?,
+        ));
+        let get_community =
+            GetCommunityUseCase::new(community_repository.clone(), membership_check.clone());
+        let update_community = UpdateCommunityUseCase::new(
+            community_repository.clone(),
+            membership_check.clone(),
+            clock.clone(),
+        );
+        let change_community_slug = ChangeCommunitySlugUseCase::new(
+            community_repository.clone(),
+            membership_check.clone(),
+            clock.clone(),
+        );
+        let change_membership_policy = ChangeMembershipPolicyUseCase::new(
+            community_repository.clone(),
+            membership_check,
+            clock,
+        );
+        let community_directory = Arc::new(CommunityDirectoryAdapter::new(community_repository));
+        let join_community = JoinCommunityUseCase::new(
+            community_directory,
+            persistence.membership_community_repository()?,
+            persistence.unit_of_work(),
+        );
 
-        Self {
+        Ok(Self {
             create_community: Arc::new(create_community),
-        }
+            list_public_communities: Arc::new(list_public_communities),
+            get_community: Arc::new(get_community),
+            update_community: Arc::new(update_community),
+            change_community_slug: Arc::new(change_community_slug),
+            change_membership_policy: Arc::new(change_membership_policy),
+            join_community: Arc::new(join_community),
+        })
     }
 }
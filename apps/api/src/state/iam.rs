@@ -1,45 +1,99 @@
+use crate::{
+    config::{cache::CacheConfig, error::ConfigError},
+    state::persistence::Persistence,
+};
+use iam::{
+    application::{
+        policies::username_policy::UsernamePolicy,
+        ports::{
+            inbound::{
+                account_authentication::AccountAuthenticationPort,
+                account_identification::AccountIdentificationPort,
+                account_registration::AccountRegistrationPort,
+                account_verification::AccountVerificationPort,
+                audit_events_listing::AuditEventsListingPort,
+                current_account_retrieval::CurrentAccountRetrievalPort,
+                own_profile_retrieval::OwnProfileRetrievalPort, profile_update::ProfileUpdatePort,
+                public_profile_retrieval::PublicProfileRetrievalPort,
+            },
+            outbound::audit_log::AuditLogPort,
+        },
+        use_cases::{
+            authenticate_account::AuthenticateAccountUseCase,
+            get_current_account::GetCurrentAccountUseCase, get_own_profile::GetOwnProfileUseCase,
+            get_public_profile::GetPublicProfileUseCase, identify_account::IdentifyAccountUseCase,
+            list_audit_events::ListAuditEventsUseCase, register_account::RegisterAccountUseCase,
+            update_profile::UpdateProfileUseCase, verify_account::VerifyAccountUseCase,
+        },
+    },
+    infrastructure::security::{
+        password_hasher::argon2_password_hasher::Argon2PasswordHasher,
+        token_generator::jwt_token_generator::JwtTokenGenerator,
+    },
+};
+use shared::application::ports::clock::ClockPort;
 use std::sync::Arc;
 
-use iam::application::ports::inbound::account_authentication::AccountAuthenticationPort;
-use iam::application::ports::inbound::account_identification::AccountIdentificationPort;
-use iam::application::ports::inbound::account_registration::AccountRegistrationPort;
-use iam::application::ports::inbound::account_verification::AccountVerificationPort;
-use iam::application::use_cases::authenticate_account::AuthenticateAccountUseCase;
-use iam::application::use_cases::identify_account::IdentifyAccountUseCase;
-use iam::application::use_cases::register_account::RegisterAccountUseCase;
-use iam::application::use_cases::verify_account::VerifyAccountUseCase;
-use iam::infrastructure::persistence::in_memory::account_repository::InMemoryAccountRepository;
-use iam::infrastructure::security::password_hasher::argon2_password_hasher::Argon2PasswordHasher;
-use iam::infrastructure::security::token_generator::jwt_token_generator::JwtTokenGenerator;
-
 #[derive(Clone)]
 pub struct IamState {
     pub register_account: Arc<dyn AccountRegistrationPort + Send + Sync>,
     pub authenticate_account: Arc<dyn AccountAuthenticationPort + Send + Sync>,
     pub verify_account: Arc<dyn AccountVerificationPort + Send + Sync>,
     pub identify_account: Arc<dyn AccountIdentificationPort + Send + Sync>,
+    pub get_current_account: Arc<dyn CurrentAccountRetrievalPort + Send + Sync>,
+    pub get_own_profile: Arc<dyn OwnProfileRetrievalPort + Send + Sync>,
+    pub update_profile: Arc<dyn ProfileUpdatePort + Send + Sync>,
+    pub get_public_profile: Arc<dyn PublicProfileRetrievalPort + Send + Sync>,
+    pub list_audit_events: Arc<dyn AuditEventsListingPort + Send + Sync>,
 }
 
 impl IamState {
-    pub fn initialize(token_generator: Arc<JwtTokenGenerator>) -> Self {
-        let account_repository = Arc::new(InMemoryAccountRepository::new());
+    pub fn initialize(
+        token_generator: Arc<JwtTokenGenerator>,
+        username_policy: UsernamePolicy,
+        audit_log: Arc<dyn AuditLogPort>,
+        clock: Arc<dyn ClockPort>,
+        persistence: &Persistence,
+        cache: &CacheConfig,
+    ) -> Result<Self, ConfigError> {
+        let account_repository =
+            cache.account_repository(persistence.account_repository()?, clock.clone());
+        let profile_repository = persistence.profile_repository()?;
         let password_hasher = Arc::new(Argon2PasswordHasher::new());
 
-        let register_account =
-            RegisterAccountUseCase::new(account_repository.clone(), password_hasher.clone());
+        let register_account = RegisterAccountUseCase::new(
+            account_repository.clone(),
+            password_hasher.clone(),
+            clock.clone(),
+        )
+        .with_username_policy(username_policy);
         let authenticate_account = AuthenticateAccountUseCase::new(
             account_repository.clone(),
             password_hasher.clone(),
             token_generator,
+            audit_log.clone(),
+            clock.clone(),
         );
-        let verify_account = VerifyAccountUseCase::new(account_repository.clone());
+        let verify_account =
+            VerifyAccountUseCase::new(account_repository.clone(), audit_log.clone(), clock.clone());
         let identify_account = IdentifyAccountUseCase::new(account_repository.clone());
+        let get_current_account = GetCurrentAccountUseCase::new(account_repository.clone());
+        let get_own_profile = GetOwnProfileUseCase::new(profile_repository.clone());
+        let update_profile = UpdateProfileUseCase::new(profile_repository.clone());
+        let get_public_profile =
+            GetPublicProfileUseCase::new(account_repository.clone(), profile_repository.clone());
+        let list_audit_events = ListAuditEventsUseCase::new(audit_log.clone());
 
-        Self {
+        Ok(Self {
             register_account: Arc::new(register_account),
             authenticate_account: Arc::new(authenticate_account),
             verify_account: Arc::new(verify_account),
             identify_account: Arc::new(identify_account),
-        }
+            get_current_account: Arc::new(get_current_account),
+            get_own_profile: Arc::new(get_own_profile),
+            update_profile: Arc::new(update_profile),
+            get_public_profile: Arc::new(get_public_profile),
+            list_audit_events: Arc::new(list_audit_events),
+        })
     }
 }
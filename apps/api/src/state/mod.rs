@@ -1,3 +1,4 @@
 pub mod app;
 pub mod communities;
 pub mod iam;
+pub mod persistence;
@@ -0,0 +1,191 @@
+use crate::config::error::ConfigError;
+use communities::{
+    application::ports::outbound::community_repository::CommunityRepositoryPort,
+    infrastructure::persistence::{
+        in_memory::community_repository::InMemoryCommunityRepository,
+        sqlite::{
+            community_repository::SqliteCommunityRepository, migrations as communities_migrations,
+        },
+    },
+};
+use iam::{
+    application::ports::outbound::{
+        account_repository::AccountRepositoryPort, profile_repository::ProfileRepositoryPort,
+    },
+    infrastructure::persistence::{
+        in_memory::{
+            account_repository::InMemoryAccountRepository,
+            profile_repository::InMemoryProfileRepository,
+        },
+        sqlite::{
+            account_repository::SqliteAccountRepository, migrations as iam_migrations,
+            profile_repository::SqliteProfileRepository,
+        },
+    },
+};
+use membership::{
+    application::ports::outbound::{
+        community_repository::CommunityRepositoryPort as MembershipCommunityRepositoryPort,
+        membership_repository::MembershipRepositoryPort,
+    },
+    infrastructure::persistence::{
+        in_memory::community_repository::InMemoryCommunityRepository as InMemoryMembershipRepository,
+        sqlite::{
+            community_repository::SqliteCommunityRepository as SqliteMembershipRepository,
+            migrations as membership_migrations,
+        },
+    },
+};
+use shared::{
+    application::ports::{outbox_store::OutboxStorePort, unit_of_work::UnitOfWorkPort},
+    infrastructure::{
+        outbox::InMemoryOutboxStore,
+        persistence::{
+            json_snapshot::{SnapshotSource, SnapshotWorker},
+            sqlite::{
+                AppliedMigration, MigrationError, MigrationSet, SqliteDatabase, SqliteOutboxStore,
+                SqliteUnitOfWorkFactory,
+            },
+        },
+        unit_of_work::InMemoryUnitOfWorkFactory,
+    },
+};
+use std::{sync::Arc, time::Duration};
+
+const MIGRATIONS: [&MigrationSet; 4] = [
+    &SqliteOutboxStore::MIGRATIONS,
+    &iam_migrations::MIGRATIONS,
+    &communities_migrations::MIGRATIONS,
+    &membership_migrations::MIGRATIONS,
+];
+
+pub enum Persistence {
+    InMemory {
+        outbox: Arc<InMemoryOutboxStore>,
+        profiles: Arc<InMemoryProfileRepository>,
+        memberships: Arc<InMemoryMembershipRepository>,
+    },
+    Snapshot {
+        outbox: Arc<InMemoryOutboxStore>,
+        accounts: Arc<InMemoryAccountRepository>,
+        profiles: Arc<InMemoryProfileRepository>,
+        communities: Arc<InMemoryCommunityRepository>,
+        memberships: Arc<InMemoryMembershipRepository>,
+        interval: Duration,
+    },
+    Sqlite {
+        database: Arc<SqliteDatabase>,
+        outbox: Arc<SqliteOutboxStore>,
+    },
+}
+
+impl Persistence {
+    pub fn outbox(&self) -> Arc<dyn OutboxStorePort> {
+        match self {
+            Persistence::InMemory { outbox, .. } => outbox.clone(),
+            Persistence::Snapshot { outbox, .. } => outbox.clone(),
+            Persistence::Sqlite { outbox, .. } => outbox.clone(),
+        }
+    }
+
+    pub fn unit_of_work(&self) -> Arc<dyn UnitOfWorkPort> {
+        match self {
+            Persistence::InMemory { .. } | Persistence::Snapshot { .. } => {
+                Arc::new(InMemoryUnitOfWorkFactory::new())
+            }
+            Persistence::Sqlite { database, .. } => {
+                Arc::new(SqliteUnitOfWorkFactory::new(database.clone()))
+            }
+        }
+    }
+
+    pub fn migrate(&self) -> Result<Vec<AppliedMigration>, MigrationError> {
+        match self {
+            Persistence::InMemory { .. } | Persistence::Snapshot { .. } => Ok(Vec::new()),
+            Persistence::Sqlite { database, .. } => {
+                let applied = database.migrate_all(&MIGRATIONS)?;
+                communities_migrations::index_search_text(database)?;
+                Ok(applied)
+            }
+        }
+    }
+
+    pub fn account_repository(&self) -> Result<Arc<dyn AccountRepositoryPort>, ConfigError> {
+        match self {
+            Persistence::InMemory { outbox, .. } => {
+                Ok(Arc::new(InMemoryAccountRepository::new(outbox.clone())))
+            }
+            Persistence::Snapshot { accounts, .. } => Ok(accounts.clone()),
+            Persistence::Sqlite { database, .. } => {
+                Ok(Arc::new(SqliteAccountRepository::new(database.clone())))
+            }
+        }
+    }
+
+    pub fn profile_repository(&self) -> Result<Arc<dyn ProfileRepositoryPort>, ConfigError> {
+        match self {
+            Persistence::InMemory { profiles, .. } => Ok(profiles.clone()),
+            Persistence::Snapshot { profiles, .. } => Ok(profiles.clone()),
+            Persistence::Sqlite { database, .. } => {
+                Ok(Arc::new(SqliteProfileRepository::new(database.clone())))
+            }
+        }
+    }
+
+    pub fn community_repository(&self) -> Result<Arc<dyn CommunityRepositoryPort>, ConfigError> {
+        match self {
+            Persistence::InMemory { outbox, .. } => {
+                Ok(Arc::new(InMemoryCommunityRepository::new(outbox.clone())))
+            }
+            Persistence::Snapshot { communities, .. } => Ok(communities.clone()),
+            Persistence::Sqlite { database, .. } => {
+                Ok(Arc::new(SqliteCommunityRepository::new(database.clone())))
+            }
+        }
+    }
+
+    pub fn membership_repository(&self) -> Result<Arc<dyn MembershipRepositoryPort>, ConfigError> {
+        match self {
+            Persistence::InMemory { memberships, .. } => Ok(memberships.clone()),
+            Persistence::Snapshot { memberships, .. } => Ok(memberships.clone()),
+            Persistence::Sqlite { database, .. } => {
+                Ok(Arc::new(SqliteMembershipRepository::new(database.clone())))
+            }
+        }
+    }
+
+    pub fn membership_community_repository(
+        &self,
+    ) -> Result<Arc<dyn MembershipCommunityRepositoryPort>, ConfigError> {
+        match self {
+            Persistence::InMemory { memberships, .. } => Ok(memberships.clone()),
+            Persistence::Snapshot { memberships, .. } => Ok(memberships.clone()),
+            Persistence::Sqlite { database, .. } => {
+                Ok(Arc::new(SqliteMembershipRepository::new(database.clone())))
+            }
+        }
+    }
+
+    pub fn snapshot_worker(&self) -> Option<SnapshotWorker> {
+        match self {
+            Persistence::Snapshot {
+                outbox,
+                accounts,
+                profiles,
+                communities,
+                memberships,
+                interval,
+            } => {
+                let sources: Vec<Arc<dyn SnapshotSource>> = vec![
+                    outbox.clone(),
+                    accounts.clone(),
+                    profiles.clone(),
+                    communities.clone(),
+                    memberships.clone(),
+                ];
+                Some(SnapshotWorker::spawn(sources, *interval))
+            }
+            _ => None,
+        }
+    }
+}
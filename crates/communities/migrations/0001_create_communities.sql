@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS communities (
+    id TEXT PRIMARY KEY,
+    owner_id TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    name TEXT NOT NULL,
+    public INTEGER NOT NULL,
+    membership_policy TEXT
+);
+CREATE UNIQUE INDEX IF NOT EXISTS communities_slug ON communities (slug);
+CREATE INDEX IF NOT EXISTS communities_owner ON communities (owner_id);
+CREATE INDEX IF NOT EXISTS communities_public_name ON communities (public, name);
@@ -0,0 +1,5 @@
+ALTER TABLE communities ADD COLUMN created_at_ms INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE communities ADD COLUMN member_count INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE communities ADD COLUMN search_text TEXT;
+CREATE INDEX IF NOT EXISTS communities_public_created ON communities (public, created_at_ms, id);
+CREATE INDEX IF NOT EXISTS communities_public_members ON communities (public, member_count, id);
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS community_slugs (
+    slug TEXT PRIMARY KEY,
+    community_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS community_slugs_community ON community_slugs (community_id);
+INSERT OR IGNORE INTO community_slugs (slug, community_id) SELECT slug, id FROM communities;
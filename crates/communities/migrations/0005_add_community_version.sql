@@ -0,0 +1 @@
+ALTER TABLE communities ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,4 @@
+pub struct ChangeCommunitySlug {
+    pub slug: String,
+    pub new_slug: String,
+}
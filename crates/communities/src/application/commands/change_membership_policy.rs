@@ -0,0 +1,4 @@
+pub struct ChangeMembershipPolicy {
+    pub slug: String,
+    pub policy: String,
+}
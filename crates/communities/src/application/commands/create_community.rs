@@ -2,4 +2,5 @@ pub struct CreateCommunity {
     pub slug: String,
     pub name: String,
     pub is_public: bool,
+    pub membership_policy: Option<String>,
 }
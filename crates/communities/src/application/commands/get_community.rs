@@ -0,0 +1,3 @@
+pub struct GetCommunity {
+    pub identifier: String,
+}
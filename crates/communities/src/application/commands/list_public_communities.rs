@@ -1,3 +1,6 @@
 pub struct ListPublicCommunities {
     pub query: Option<String>,
+    pub sort: Option<String>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
 }
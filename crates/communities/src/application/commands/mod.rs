@@ -1,2 +1,6 @@
+pub mod change_community_slug;
+pub mod change_membership_policy;
 pub mod create_community;
+pub mod get_community;
 pub mod list_public_communities;
+pub mod update_community;
@@ -0,0 +1,6 @@
+pub struct UpdateCommunity {
+    pub slug: String,
+    pub name: Option<String>,
+    pub is_public: Option<bool>,
+    pub description: Option<String>,
+}
@@ -0,0 +1,42 @@
+use super::error_codes::{COMMUNITIES_INVALID_CURSOR, COMMUNITIES_INVALID_SORT};
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CommunityListingError {
+    InvalidSort,
+    InvalidCursor,
+}
+
+impl fmt::Display for CommunityListingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommunityListingError::InvalidSort => write!(f, "Unknown community sort order"),
+            CommunityListingError::InvalidCursor => write!(f, "Malformed community page cursor"),
+        }
+    }
+}
+
+impl std::error::Error for CommunityListingError {}
+
+impl LayerError for CommunityListingError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            CommunityListingError::InvalidSort => COMMUNITIES_INVALID_SORT,
+            CommunityListingError::InvalidCursor => COMMUNITIES_INVALID_CURSOR,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            CommunityListingError::InvalidSort => {
+                "Communities can be sorted by name, created_at or member_count."
+            }
+            CommunityListingError::InvalidCursor => "This page link is invalid or has expired.",
+        }
+    }
+}
@@ -1,13 +1,28 @@
-use super::error_codes::COMMUNITIES_REPOSITORY_ERROR;
+use super::error_codes::{
+    COMMUNITIES_CONCURRENT_MODIFICATION, COMMUNITIES_REPOSITORY_ERROR,
+    COMMUNITIES_REPOSITORY_UNAVAILABLE, COMMUNITIES_SLUG_ALREADY_EXISTS,
+};
 use shared::error::{ErrorCategory, LayerError};
 use std::fmt;
 
 #[derive(Debug)]
-pub struct CommunityRepositoryError(pub String);
+pub enum CommunityRepositoryError {
+    Storage(String),
+    Unavailable(String),
+    Conflict,
+    SlugAlreadyExists,
+}
 
 impl fmt::Display for CommunityRepositoryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "We couldn't complete your request right now")
+        match self {
+            CommunityRepositoryError::Storage(message)
+            | CommunityRepositoryError::Unavailable(message) => write!(f, "{}", message),
+            CommunityRepositoryError::Conflict => {
+                write!(f, "Community was modified by another request")
+            }
+            CommunityRepositoryError::SlugAlreadyExists => write!(f, "Slug is already taken"),
+        }
     }
 }
 
@@ -19,10 +34,26 @@ impl LayerError for CommunityRepositoryError {
     }
 
     fn code(&self) -> &'static str {
-        COMMUNITIES_REPOSITORY_ERROR
+        match self {
+            CommunityRepositoryError::Storage(_) => COMMUNITIES_REPOSITORY_ERROR,
+            CommunityRepositoryError::Unavailable(_) => COMMUNITIES_REPOSITORY_UNAVAILABLE,
+            CommunityRepositoryError::Conflict => COMMUNITIES_CONCURRENT_MODIFICATION,
+            CommunityRepositoryError::SlugAlreadyExists => COMMUNITIES_SLUG_ALREADY_EXISTS,
+        }
     }
 
     fn message(&self) -> &'static str {
-        "We couldn't complete your request right now. Please try again."
+        match self {
+            CommunityRepositoryError::Storage(_) => {
+                "We couldn't complete your request right now. Please try again."
+            }
+            CommunityRepositoryError::Unavailable(_) => {
+                "Communities are temporarily unavailable. Please try again shortly."
+            }
+            CommunityRepositoryError::Conflict => {
+                "This community was changed by another request. Please try again."
+            }
+            CommunityRepositoryError::SlugAlreadyExists => "This community slug is already in use.",
+        }
     }
 }
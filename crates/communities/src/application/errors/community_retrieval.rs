@@ -0,0 +1,36 @@
+use super::error_codes::COMMUNITIES_COMMUNITY_NOT_FOUND;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CommunityRetrievalError {
+    NotFound,
+}
+
+impl fmt::Display for CommunityRetrievalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommunityRetrievalError::NotFound => write!(f, "Community not found"),
+        }
+    }
+}
+
+impl std::error::Error for CommunityRetrievalError {}
+
+impl LayerError for CommunityRetrievalError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            CommunityRetrievalError::NotFound => COMMUNITIES_COMMUNITY_NOT_FOUND,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            CommunityRetrievalError::NotFound => "This community does not exist.",
+        }
+    }
+}
@@ -1,2 +1,8 @@
 pub const COMMUNITIES_SLUG_ALREADY_EXISTS: &str = "COMMUNITIES_SLUG_ALREADY_EXISTS";
+pub const COMMUNITIES_CONCURRENT_MODIFICATION: &str = "COMMUNITIES_CONCURRENT_MODIFICATION";
+pub const COMMUNITIES_INVALID_SORT: &str = "COMMUNITIES_INVALID_SORT";
+pub const COMMUNITIES_INVALID_CURSOR: &str = "COMMUNITIES_INVALID_CURSOR";
+pub const COMMUNITIES_COMMUNITY_NOT_FOUND: &str = "COMMUNITIES_COMMUNITY_NOT_FOUND";
+pub const COMMUNITIES_MEMBERSHIP_UNAVAILABLE: &str = "COMMUNITIES_MEMBERSHIP_UNAVAILABLE";
 pub const COMMUNITIES_REPOSITORY_ERROR: &str = "COMMUNITIES_REPOSITORY_ERROR";
+pub const COMMUNITIES_REPOSITORY_UNAVAILABLE: &str = "COMMUNITIES_REPOSITORY_UNAVAILABLE";
@@ -1,3 +1,7 @@
 pub mod community_creation;
+pub mod community_listing;
 pub mod community_repository;
+pub mod community_retrieval;
 pub mod error_codes;
+pub mod membership_check;
+pub mod owner_membership;
@@ -0,0 +1,38 @@
+use super::error_codes::COMMUNITIES_MEMBERSHIP_UNAVAILABLE;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum OwnerMembershipError {
+    Unavailable(String),
+}
+
+impl fmt::Display for OwnerMembershipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OwnerMembershipError::Unavailable(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for OwnerMembershipError {}
+
+impl LayerError for OwnerMembershipError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            OwnerMembershipError::Unavailable(_) => COMMUNITIES_MEMBERSHIP_UNAVAILABLE,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            OwnerMembershipError::Unavailable(_) => {
+                "Memberships are temporarily unavailable. Please try again shortly."
+            }
+        }
+    }
+}
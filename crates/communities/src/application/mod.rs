@@ -1,5 +1,7 @@
 pub mod commands;
 pub mod errors;
+pub mod pagination;
+pub mod policies;
 pub mod ports;
 pub mod results;
 pub mod use_cases;
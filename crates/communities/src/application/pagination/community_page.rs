@@ -0,0 +1,221 @@
+use crate::domain::{
+    aggregates::community::Community, value_objects::community_search::CommunitySearch,
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use shared::domain::timestamps::to_unix_millis;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommunitySort {
+    Name,
+    CreatedAt,
+    MemberCount,
+}
+
+impl CommunitySort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "name" => Some(CommunitySort::Name),
+            "created_at" => Some(CommunitySort::CreatedAt),
+            "member_count" => Some(CommunitySort::MemberCount),
+            _ => None,
+        }
+    }
+
+    pub fn is_descending(self) -> bool {
+        match self {
+            CommunitySort::Name => false,
+            CommunitySort::CreatedAt | CommunitySort::MemberCount => true,
+        }
+    }
+
+    pub fn key_of(self, community: &Community) -> SortKey {
+        match self {
+            CommunitySort::Name => SortKey::Name(community.name().as_str().to_string()),
+            CommunitySort::CreatedAt => SortKey::CreatedAt(to_unix_millis(community.created_at())),
+            CommunitySort::MemberCount => SortKey::MemberCount(community.member_count()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Name(String),
+    CreatedAt(i64),
+    MemberCount(u64),
+}
+
+impl SortKey {
+    fn sort(&self) -> CommunitySort {
+        match self {
+            SortKey::Name(_) => CommunitySort::Name,
+            SortKey::CreatedAt(_) => CommunitySort::CreatedAt,
+            SortKey::MemberCount(_) => CommunitySort::MemberCount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageDirection {
+    After,
+    Before,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommunityCursor {
+    pub direction: PageDirection,
+    pub key: SortKey,
+    pub id: String,
+}
+
+impl CommunityCursor {
+    fn at(sort: CommunitySort, direction: PageDirection, community: &Community) -> Self {
+        Self {
+            direction,
+            key: sort.key_of(community),
+            id: community.id().as_uuid().to_string(),
+        }
+    }
+
+    pub fn sort(&self) -> CommunitySort {
+        self.key.sort()
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serializes");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(value: &str) -> Option<Self> {
+        let json = URL_SAFE_NO_PAD.decode(value).ok()?;
+        serde_json::from_slice(&json).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublicCommunitiesQuery {
+    pub search: Option<CommunitySearch>,
+    pub sort: CommunitySort,
+    pub limit: usize,
+    pub cursor: Option<CommunityCursor>,
+}
+
+impl PublicCommunitiesQuery {
+    pub fn scans_backwards(&self) -> bool {
+        matches!(&self.cursor, Some(cursor) if cursor.direction == PageDirection::Before)
+    }
+
+    pub fn scan_order(&self, a: &Community, b: &Community) -> Ordering {
+        self.compare(
+            (&self.sort.key_of(a), &a.id().as_uuid().to_string()),
+            (&self.sort.key_of(b), &b.id().as_uuid().to_string()),
+        )
+    }
+
+    pub fn is_past_cursor(&self, community: &Community) -> bool {
+        match &self.cursor {
+            Some(cursor) => {
+                self.compare(
+                    (
+                        &self.sort.key_of(community),
+                        &community.id().as_uuid().to_string(),
+                    ),
+                    (&cursor.key, &cursor.id),
+                ) == Ordering::Greater
+            }
+            None => true,
+        }
+    }
+
+    fn compare(&self, a: (&SortKey, &String), b: (&SortKey, &String)) -> Ordering {
+        let natural = a.cmp(&b);
+        let natural = if self.sort.is_descending() {
+            natural.reverse()
+        } else {
+            natural
+        };
+
+        if self.scans_backwards() {
+            natural.reverse()
+        } else {
+            natural
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommunityPage {
+    pub communities: Vec<Community>,
+    pub next: Option<CommunityCursor>,
+    pub previous: Option<CommunityCursor>,
+    pub total_estimate: u64,
+}
+
+impl CommunityPage {
+    pub fn assemble(
+        mut scanned: Vec<Community>,
+        query: &PublicCommunitiesQuery,
+        total_estimate: u64,
+    ) -> Self {
+        let has_more = scanned.len() > query.limit;
+        scanned.truncate(query.limit);
+
+        let backwards = query.scans_backwards();
+        if backwards {
+            scanned.reverse();
+        }
+
+        let more_after = if backwards {
+            query.cursor.is_some()
+        } else {
+            has_more
+        };
+        let more_before = if backwards {
+            has_more
+        } else {
+            query.cursor.is_some()
+        };
+
+        Self {
+            next: scanned
+                .last()
+                .filter(|_| more_after)
+                .map(|last| CommunityCursor::at(query.sort, PageDirection::After, last)),
+            previous: scanned
+                .first()
+                .filter(|_| more_before)
+                .map(|first| CommunityCursor::at(query.sort, PageDirection::Before, first)),
+            communities: scanned,
+            total_estimate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_cursors_through_their_opaque_encoding() {
+        let cursor = CommunityCursor {
+            direction: PageDirection::Before,
+            key: SortKey::MemberCount(42),
+            id: "community".to_string(),
+        };
+
+        let decoded = CommunityCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.sort(), CommunitySort::MemberCount);
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        assert_eq!(CommunityCursor::decode("not a cursor"), None);
+        assert_eq!(CommunityCursor::decode(&URL_SAFE_NO_PAD.encode("{}")), None);
+    }
+}
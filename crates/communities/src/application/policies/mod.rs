@@ -0,0 +1 @@
+pub mod settings_access;
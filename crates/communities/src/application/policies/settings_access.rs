@@ -0,0 +1,73 @@
+use crate::{
+    application::{
+        errors::community_retrieval::CommunityRetrievalError,
+        ports::outbound::membership_check::MembershipCheckPort,
+    },
+    domain::{aggregates::community::Community, errors::community_error::CommunityError},
+};
+use iam::domain::value_objects::AccountId;
+use shared::error::SystemError;
+use std::sync::Arc;
+
+pub struct SettingsAccessPolicy {
+    membership_check: Arc<dyn MembershipCheckPort>,
+}
+
+impl SettingsAccessPolicy {
+    pub fn new(membership_check: Arc<dyn MembershipCheckPort>) -> Self {
+        Self { membership_check }
+    }
+
+    pub async fn authorize(
+        &self,
+        community: &Community,
+        account_id: &AccountId,
+    ) -> Result<(), SystemError> {
+        if community.owner_id() == account_id
+            || self
+                .membership_check
+                .is_admin(community.id(), account_id)
+                .await?
+        {
+            return Ok(());
+        }
+
+        self.deny(
+            community,
+            account_id,
+            CommunityError::InsufficientPermissions,
+        )
+        .await
+    }
+
+    pub async fn authorize_owner(
+        &self,
+        community: &Community,
+        account_id: &AccountId,
+    ) -> Result<(), SystemError> {
+        if community.owner_id() == account_id {
+            return Ok(());
+        }
+
+        self.deny(community, account_id, CommunityError::NotOwner)
+            .await
+    }
+
+    async fn deny(
+        &self,
+        community: &Community,
+        account_id: &AccountId,
+        error: CommunityError,
+    ) -> Result<(), SystemError> {
+        if community.is_public()
+            || self
+                .membership_check
+                .is_member(community.id(), account_id)
+                .await?
+        {
+            Err(error.into())
+        } else {
+            Err(CommunityRetrievalError::NotFound.into())
+        }
+    }
+}
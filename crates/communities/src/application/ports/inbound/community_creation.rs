@@ -1,10 +1,12 @@
 use crate::application::{
     commands::create_community::CreateCommunity, results::community_created::CommunityCreated,
 };
+use async_trait::async_trait;
 use shared::{application::auth_context::AuthContext, error::SystemError};
 
-pub trait CommunityCreationPort {
-    fn execute(
+#[async_trait]
+pub trait CommunityCreationPort: Send + Sync {
+    async fn execute(
         &self,
         data: CreateCommunity,
         auth: AuthContext,
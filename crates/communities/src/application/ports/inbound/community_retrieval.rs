@@ -0,0 +1,14 @@
+use crate::application::{
+    commands::get_community::GetCommunity, results::community_retrieved::CommunityRetrieved,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait CommunityRetrievalPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: GetCommunity,
+        auth: Option<AuthContext>,
+    ) -> Result<CommunityRetrieved, SystemError>;
+}
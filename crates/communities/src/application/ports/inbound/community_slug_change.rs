@@ -0,0 +1,15 @@
+use crate::application::{
+    commands::change_community_slug::ChangeCommunitySlug,
+    results::community_updated::CommunityUpdated,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait CommunitySlugChangePort: Send + Sync {
+    async fn execute(
+        &self,
+        data: ChangeCommunitySlug,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError>;
+}
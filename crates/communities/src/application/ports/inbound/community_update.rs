@@ -0,0 +1,14 @@
+use crate::application::{
+    commands::update_community::UpdateCommunity, results::community_updated::CommunityUpdated,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait CommunityUpdatePort: Send + Sync {
+    async fn execute(
+        &self,
+        data: UpdateCommunity,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError>;
+}
@@ -0,0 +1,15 @@
+use crate::application::{
+    commands::change_membership_policy::ChangeMembershipPolicy,
+    results::community_updated::CommunityUpdated,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait MembershipPolicyChangePort: Send + Sync {
+    async fn execute(
+        &self,
+        data: ChangeMembershipPolicy,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError>;
+}
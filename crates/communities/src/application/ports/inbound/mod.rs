@@ -1,2 +1,6 @@
 pub mod community_creation;
+pub mod community_retrieval;
+pub mod community_slug_change;
+pub mod community_update;
+pub mod membership_policy_change;
 pub mod public_communities_listing;
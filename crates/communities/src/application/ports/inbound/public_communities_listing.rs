@@ -2,12 +2,14 @@ use crate::application::{
     commands::list_public_communities::ListPublicCommunities,
     results::public_communities_listed::PublicCommunitiesListed,
 };
+use async_trait::async_trait;
 use shared::{application::auth_context::AuthContext, error::SystemError};
 
-pub trait PublicCommunitiesListingPort {
-    fn execute(
+#[async_trait]
+pub trait PublicCommunitiesListingPort: Send + Sync {
+    async fn execute(
         &self,
         data: ListPublicCommunities,
-        auth: AuthContext,
+        auth: Option<AuthContext>,
     ) -> Result<PublicCommunitiesListed, SystemError>;
 }
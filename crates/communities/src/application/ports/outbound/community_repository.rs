@@ -1,14 +1,34 @@
 use crate::{
-    application::errors::community_repository::CommunityRepositoryError,
-    domain::aggregates::community::Community,
+    application::{
+        errors::community_repository::CommunityRepositoryError,
+        pagination::community_page::{CommunityPage, PublicCommunitiesQuery},
+    },
+    domain::{aggregates::community::Community, value_objects::community_id::CommunityId},
 };
+use async_trait::async_trait;
+use shared::application::ports::unit_of_work::UnitOfWork;
 
+#[async_trait]
 pub trait CommunityRepositoryPort: Send + Sync {
-    fn get_public_list(&self, query: Option<String>) -> Vec<Community>;
-    fn find_by_id(&self, id: &str) -> Option<Community>;
+    async fn get_public_list(
+        &self,
+        query: PublicCommunitiesQuery,
+    ) -> Result<CommunityPage, CommunityRepositoryError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError>;
 
-    fn find_by_slug(&self, slug: &str) -> Option<Community>;
-    fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError>;
+    async fn find_by_slug(&self, slug: &str)
+    -> Result<Option<Community>, CommunityRepositoryError>;
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError>;
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError>;
+    async fn record_member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        id: &CommunityId,
+    ) -> Result<(), CommunityRepositoryError>;
 }
 
 #[cfg(test)]
@@ -16,90 +36,246 @@ pub mod test_utils {
     use crate::{
         application::{
             errors::community_repository::CommunityRepositoryError,
+            pagination::community_page::{CommunityPage, PublicCommunitiesQuery},
             ports::outbound::community_repository::CommunityRepositoryPort,
         },
-        domain::aggregates::community::Community,
+        domain::{
+            aggregates::community::Community,
+            value_objects::{community_id::CommunityId, community_slug::CommunitySlug},
+        },
     };
+    use async_trait::async_trait;
+    use iam::domain::value_objects::AccountId;
+    use shared::application::ports::unit_of_work::UnitOfWork;
 
     pub struct FakeCommunityRepository {
         should_fail: bool,
+        unavailable: bool,
         _activated: bool,
         existing_id: Option<String>,
         existing_slug: Option<String>,
+        private: bool,
+        owner_id: Option<AccountId>,
+        other_slug: Option<String>,
+        moved_to: Option<String>,
     }
 
     impl FakeCommunityRepository {
         pub fn success() -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 _activated: false,
                 existing_id: None,
                 existing_slug: None,
+                private: false,
+                owner_id: None,
+                other_slug: None,
+                moved_to: None,
             }
         }
 
         pub fn fail() -> Self {
             Self {
+                unavailable: false,
                 should_fail: true,
                 _activated: false,
                 existing_id: None,
                 existing_slug: None,
+                private: false,
+                owner_id: None,
+                other_slug: None,
+                moved_to: None,
+            }
+        }
+
+        pub fn unavailable() -> Self {
+            Self {
+                unavailable: true,
+                ..Self::fail()
             }
         }
 
         pub fn with_existing_slug(slug: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 _activated: false,
                 existing_id: None,
                 existing_slug: Some(slug.to_string()),
+                private: false,
+                owner_id: None,
+                other_slug: None,
+                moved_to: None,
+            }
+        }
+
+        pub fn with_existing_private_slug(slug: &str) -> Self {
+            Self {
+                private: true,
+                ..Self::with_existing_slug(slug)
+            }
+        }
+
+        pub fn with_existing_slug_owned_by(slug: &str, owner_id: &AccountId) -> Self {
+            Self {
+                owner_id: Some(owner_id.clone()),
+                ..Self::with_existing_slug(slug)
+            }
+        }
+
+        pub fn with_existing_slugs(slug: &str, other_slug: &str) -> Self {
+            Self {
+                other_slug: Some(other_slug.to_string()),
+                ..Self::with_existing_slug(slug)
+            }
+        }
+
+        pub fn with_moved_slug(previous_slug: &str, slug: &str) -> Self {
+            Self {
+                moved_to: Some(slug.to_string()),
+                ..Self::with_existing_slug(previous_slug)
             }
         }
 
         pub fn with_existing_id(id: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 _activated: false,
                 existing_id: Some(id.to_string()),
                 existing_slug: None,
+                private: false,
+                owner_id: None,
+                other_slug: None,
+                moved_to: None,
             }
         }
 
         pub fn active_with_existing_username(username: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 _activated: true,
                 existing_id: Some(username.to_string()),
                 existing_slug: None,
+                private: false,
+                owner_id: None,
+                other_slug: None,
+                moved_to: None,
             }
         }
     }
 
+    impl FakeCommunityRepository {
+        fn existing(&self) -> Community {
+            let mut community = if self.private {
+                Community::dummy_private_community()
+            } else {
+                Community::dummy_community()
+            };
+            if let Some(slug) = &self.moved_to {
+                community.change_slug(
+                    CommunitySlug::new(slug.clone()).unwrap(),
+                    std::time::UNIX_EPOCH,
+                );
+            }
+            match &self.owner_id {
+                Some(owner_id) => Community::reconstitute(
+                    community.id().clone(),
+                    owner_id.clone(),
+                    community.slug().clone(),
+                    Vec::new(),
+                    community.name().clone(),
+                    None,
+                    community.is_public(),
+                    *community.membership_policy(),
+                    community.created_at(),
+                    community.member_count(),
+                    community.version(),
+                ),
+                None => community,
+            }
+        }
+
+        fn read(&self) -> Result<(), CommunityRepositoryError> {
+            if self.unavailable {
+                Err(CommunityRepositoryError::Unavailable(
+                    "FakeCommunityRepository unavailable".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[async_trait]
     impl CommunityRepositoryPort for FakeCommunityRepository {
-        fn get_public_list(&self, _query: Option<String>) -> Vec<Community> {
-            todo!()
+        async fn get_public_list(
+            &self,
+            query: PublicCommunitiesQuery,
+        ) -> Result<CommunityPage, CommunityRepositoryError> {
+            self.read()?;
+            let communities: Vec<Community> = self
+                .existing_slug
+                .iter()
+                .map(|_| Community::dummy_community())
+                .collect();
+            let total_estimate = communities.len() as u64;
+            Ok(CommunityPage::assemble(communities, &query, total_estimate))
         }
 
-        fn find_by_id(&self, id: &str) -> Option<Community> {
-            self.existing_id
+        async fn find_by_id(
+            &self,
+            id: &str,
+        ) -> Result<Option<Community>, CommunityRepositoryError> {
+            self.read()?;
+            Ok(self
+                .existing_id
                 .as_ref()
                 .filter(|c| c.as_str() == id)
-                .map(|_| Community::dummy_community())
+                .map(|_| Community::dummy_community()))
         }
 
-        fn find_by_slug(&self, slug: &str) -> Option<Community> {
-            self.existing_slug
+        async fn find_by_slug(
+            &self,
+            slug: &str,
+        ) -> Result<Option<Community>, CommunityRepositoryError> {
+            self.read()?;
+            if self.other_slug.as_deref() == Some(slug) {
+                return Ok(Some(Community::dummy_community()));
+            }
+            Ok(self
+                .existing_slug
                 .as_ref()
                 .filter(|c| c.as_str() == slug)
-                .map(|_| Community::dummy_community())
+                .map(|_| self.existing()))
         }
 
-        fn save(&self, _community: &Community) -> Result<(), CommunityRepositoryError> {
+        async fn save(&self, _community: &Community) -> Result<(), CommunityRepositoryError> {
             if self.should_fail {
-                Err(CommunityRepositoryError("Unexpected error".to_string()))
+                Err(CommunityRepositoryError::Storage(
+                    "Unexpected error".to_string(),
+                ))
             } else {
                 Ok(())
             }
         }
+
+        async fn save_in(
+            &self,
+            _unit_of_work: &mut dyn UnitOfWork,
+            community: &Community,
+        ) -> Result<(), CommunityRepositoryError> {
+            self.save(community).await
+        }
+
+        async fn record_member_joined_in(
+            &self,
+            _unit_of_work: &mut dyn UnitOfWork,
+            _id: &CommunityId,
+        ) -> Result<(), CommunityRepositoryError> {
+            self.save(&self.existing()).await
+        }
     }
 }
@@ -0,0 +1,103 @@
+use crate::{
+    application::errors::membership_check::MembershipCheckError,
+    domain::value_objects::community_id::CommunityId,
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+
+#[async_trait]
+pub trait MembershipCheckPort: Send + Sync {
+    async fn is_member(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+    ) -> Result<bool, MembershipCheckError>;
+
+    async fn is_admin(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+    ) -> Result<bool, MembershipCheckError>;
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::{
+        application::{
+            errors::membership_check::MembershipCheckError,
+            ports::outbound::membership_check::MembershipCheckPort,
+        },
+        domain::value_objects::community_id::CommunityId,
+    };
+    use async_trait::async_trait;
+    use iam::domain::value_objects::AccountId;
+
+    pub struct FakeMembershipCheck {
+        member: bool,
+        admin: bool,
+        unavailable: bool,
+    }
+
+    impl FakeMembershipCheck {
+        pub fn member() -> Self {
+            Self {
+                member: true,
+                admin: false,
+                unavailable: false,
+            }
+        }
+
+        pub fn admin() -> Self {
+            Self {
+                admin: true,
+                ..Self::member()
+            }
+        }
+
+        pub fn stranger() -> Self {
+            Self {
+                member: false,
+                admin: false,
+                unavailable: false,
+            }
+        }
+
+        pub fn unavailable() -> Self {
+            Self {
+                member: false,
+                admin: false,
+                unavailable: true,
+            }
+        }
+
+        fn check(&self) -> Result<(), MembershipCheckError> {
+            if self.unavailable {
+                return Err(MembershipCheckError::Unavailable(
+                    "FakeMembershipCheck unavailable".to_string(),
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl MembershipCheckPort for FakeMembershipCheck {
+        async fn is_member(
+            &self,
+            _community_id: &CommunityId,
+            _account_id: &AccountId,
+        ) -> Result<bool, MembershipCheckError> {
+            self.check()?;
+            Ok(self.member)
+        }
+
+        async fn is_admin(
+            &self,
+            _community_id: &CommunityId,
+            _account_id: &AccountId,
+        ) -> Result<bool, MembershipCheckError> {
+            self.check()?;
+            Ok(self.admin)
+        }
+    }
+}
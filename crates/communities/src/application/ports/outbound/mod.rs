@@ -1 +1,3 @@
 pub mod community_repository;
+pub mod membership_check;
+pub mod owner_membership;
@@ -0,0 +1,68 @@
+use crate::{
+    application::errors::owner_membership::OwnerMembershipError,
+    domain::aggregates::community::Community,
+};
+use async_trait::async_trait;
+use shared::application::ports::unit_of_work::UnitOfWork;
+
+#[async_trait]
+pub trait OwnerMembershipPort: Send + Sync {
+    async fn register_owner_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), OwnerMembershipError>;
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::{
+        application::{
+            errors::owner_membership::OwnerMembershipError,
+            ports::outbound::owner_membership::OwnerMembershipPort,
+        },
+        domain::{aggregates::community::Community, value_objects::community_id::CommunityId},
+    };
+    use async_trait::async_trait;
+    use shared::application::ports::unit_of_work::UnitOfWork;
+    use std::sync::Mutex;
+
+    pub struct FakeOwnerMembership {
+        unavailable: bool,
+        pub registered: Mutex<Vec<CommunityId>>,
+    }
+
+    impl FakeOwnerMembership {
+        pub fn available() -> Self {
+            Self {
+                unavailable: false,
+                registered: Mutex::new(Vec::new()),
+            }
+        }
+
+        pub fn unavailable() -> Self {
+            Self {
+                unavailable: true,
+                registered: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OwnerMembershipPort for FakeOwnerMembership {
+        async fn register_owner_in(
+            &self,
+            _unit_of_work: &mut dyn UnitOfWork,
+            community: &Community,
+        ) -> Result<(), OwnerMembershipError> {
+            if self.unavailable {
+                return Err(OwnerMembershipError::Unavailable(
+                    "FakeOwnerMembership unavailable".to_string(),
+                ));
+            }
+
+            self.registered.lock().unwrap().push(community.id().clone());
+            Ok(())
+        }
+    }
+}
@@ -3,4 +3,5 @@ pub struct CommunityCreated {
     pub id: String,
     pub name: String,
     pub slug: String,
+    pub membership_policy: String,
 }
@@ -0,0 +1,12 @@
+#[derive(Debug)]
+pub struct CommunityRetrieved {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub membership_policy: String,
+    pub owner_id: String,
+    pub member_count: u64,
+    pub moved_from: Option<String>,
+}
@@ -0,0 +1,31 @@
+use crate::domain::aggregates::community::Community;
+
+#[derive(Debug)]
+pub struct CommunityUpdated {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub membership_policy: String,
+    pub owner_id: String,
+    pub member_count: u64,
+}
+
+impl From<&Community> for CommunityUpdated {
+    fn from(community: &Community) -> Self {
+        Self {
+            id: community.id().as_uuid().to_string(),
+            name: community.name().as_str().to_string(),
+            slug: community.slug().as_str().to_string(),
+            description: community
+                .description()
+                .as_ref()
+                .map(|description| description.as_str().to_string()),
+            is_public: community.is_public(),
+            membership_policy: community.effective_membership_policy().as_str().to_string(),
+            owner_id: community.owner_id().as_uuid().to_string(),
+            member_count: community.member_count(),
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod community_created;
+pub mod community_retrieved;
+pub mod community_updated;
 pub mod public_communities_listed;
@@ -1,8 +1,15 @@
+use std::time::SystemTime;
+
 pub struct PublicCommunitiesListed {
     pub communities: Vec<CommunityResult>,
+    pub next_cursor: Option<String>,
+    pub previous_cursor: Option<String>,
+    pub total_estimate: u64,
 }
 
 pub struct CommunityResult {
     pub name: String,
     pub slug: String,
+    pub created_at: SystemTime,
+    pub member_count: u64,
 }
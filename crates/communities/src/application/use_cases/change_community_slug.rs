@@ -0,0 +1,217 @@
+use crate::{
+    application::{
+        commands::change_community_slug::ChangeCommunitySlug,
+        errors::{
+            community_creation::CommunityCreationError,
+            community_retrieval::CommunityRetrievalError,
+        },
+        policies::settings_access::SettingsAccessPolicy,
+        ports::{
+            inbound::community_slug_change::CommunitySlugChangePort,
+            outbound::{
+                community_repository::CommunityRepositoryPort,
+                membership_check::MembershipCheckPort,
+            },
+        },
+        results::community_updated::CommunityUpdated,
+    },
+    domain::value_objects::community_slug::CommunitySlug,
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::{
+    application::{auth_context::AuthContext, ports::clock::ClockPort},
+    error::SystemError,
+};
+use std::sync::Arc;
+
+pub struct ChangeCommunitySlugUseCase {
+    community_repository: Arc<dyn CommunityRepositoryPort>,
+    settings_access: SettingsAccessPolicy,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ChangeCommunitySlugUseCase {
+    pub fn new(
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        membership_check: Arc<dyn MembershipCheckPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            community_repository,
+            settings_access: SettingsAccessPolicy::new(membership_check),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl CommunitySlugChangePort for ChangeCommunitySlugUseCase {
+    async fn execute(
+        &self,
+        data: ChangeCommunitySlug,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+        let slug = CommunitySlug::new(data.slug).map_err(|_| CommunityRetrievalError::NotFound)?;
+        let new_slug = CommunitySlug::new(data.new_slug)?;
+
+        let mut community = self
+            .community_repository
+            .find_by_slug(slug.as_str())
+            .await?
+            .ok_or(CommunityRetrievalError::NotFound)?;
+        self.settings_access
+            .authorize(&community, &account_id)
+            .await?;
+
+        if !community.is_known_as(new_slug.as_str()) {
+            let taken = self
+                .community_repository
+                .find_by_slug(new_slug.as_str())
+                .await?;
+            if taken.is_some() {
+                return Err(CommunityCreationError::SlugAlreadyExists.into());
+            }
+        }
+
+        community.change_slug(new_slug, self.clock.now());
+        if !community.pending_events().is_empty() {
+            self.community_repository.save(&community).await?;
+        }
+
+        Ok(CommunityUpdated::from(&community))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::change_community_slug::ChangeCommunitySlug,
+            errors::error_codes::{
+                COMMUNITIES_COMMUNITY_NOT_FOUND, COMMUNITIES_SLUG_ALREADY_EXISTS,
+            },
+            ports::{
+                inbound::community_slug_change::CommunitySlugChangePort,
+                outbound::{
+                    community_repository::test_utils::FakeCommunityRepository,
+                    membership_check::test_utils::FakeMembershipCheck,
+                },
+            },
+            use_cases::change_community_slug::ChangeCommunitySlugUseCase,
+        },
+        domain::errors::error_codes::{
+            COMMUNITIES_INSUFFICIENT_PERMISSIONS, COMMUNITIES_INVALID_COMMUNITY_SLUG,
+        },
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::{application::auth_context::AuthContext, infrastructure::clock::FixedClock};
+    use std::sync::Arc;
+
+    fn use_case_with(
+        repo: FakeCommunityRepository,
+        membership_check: FakeMembershipCheck,
+    ) -> ChangeCommunitySlugUseCase {
+        ChangeCommunitySlugUseCase::new(
+            Arc::new(repo),
+            Arc::new(membership_check),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
+    fn valid_auth_context() -> AuthContext {
+        AuthContext {
+            account_id: AccountId::generate().as_uuid().to_string(),
+        }
+    }
+
+    fn rename_to(new_slug: &str) -> ChangeCommunitySlug {
+        ChangeCommunitySlug {
+            slug: "rust-community".to_string(),
+            new_slug: new_slug.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn changes_slug_and_keeps_the_previous_one() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case
+            .execute(rename_to("Rustaceans"), valid_auth_context())
+            .await;
+
+        assert_eq!(result.expect("Expected community").slug, "rustaceans");
+    }
+
+    #[tokio::test]
+    async fn fails_when_slug_is_taken_by_another_community() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slugs("rust-community", "rustaceans"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case
+            .execute(rename_to("rustaceans"), valid_auth_context())
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_SLUG_ALREADY_EXISTS
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_new_slug_is_invalid() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case
+            .execute(rename_to("rust_community!"), valid_auth_context())
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INVALID_COMMUNITY_SLUG
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_caller_is_a_regular_member() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::member(),
+        );
+
+        let result = use_case
+            .execute(rename_to("rustaceans"), valid_auth_context())
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INSUFFICIENT_PERMISSIONS
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_community_does_not_exist() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::success(),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case
+            .execute(rename_to("rustaceans"), valid_auth_context())
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+    }
+}
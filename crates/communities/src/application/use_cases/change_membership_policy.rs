@@ -0,0 +1,179 @@
+use crate::{
+    application::{
+        commands::change_membership_policy::ChangeMembershipPolicy,
+        errors::community_retrieval::CommunityRetrievalError,
+        policies::settings_access::SettingsAccessPolicy,
+        ports::{
+            inbound::membership_policy_change::MembershipPolicyChangePort,
+            outbound::{
+                community_repository::CommunityRepositoryPort,
+                membership_check::MembershipCheckPort,
+            },
+        },
+        results::community_updated::CommunityUpdated,
+    },
+    domain::{
+        errors::invalid_membership_policy::InvalidMembershipPolicy,
+        policies::membership_policy::MembershipPolicy,
+        value_objects::community_slug::CommunitySlug,
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::{
+    application::{auth_context::AuthContext, ports::clock::ClockPort},
+    error::SystemError,
+};
+use std::sync::Arc;
+
+pub struct ChangeMembershipPolicyUseCase {
+    community_repository: Arc<dyn CommunityRepositoryPort>,
+    settings_access: SettingsAccessPolicy,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl ChangeMembershipPolicyUseCase {
+    pub fn new(
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        membership_check: Arc<dyn MembershipCheckPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            community_repository,
+            settings_access: SettingsAccessPolicy::new(membership_check),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl MembershipPolicyChangePort for ChangeMembershipPolicyUseCase {
+    async fn execute(
+        &self,
+        data: ChangeMembershipPolicy,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+        let slug = CommunitySlug::new(data.slug).map_err(|_| CommunityRetrievalError::NotFound)?;
+        let policy = MembershipPolicy::parse(data.policy.trim()).ok_or(InvalidMembershipPolicy)?;
+
+        let mut community = self
+            .community_repository
+            .find_by_slug(slug.as_str())
+            .await?
+            .ok_or(CommunityRetrievalError::NotFound)?;
+        self.settings_access
+            .authorize_owner(&community, &account_id)
+            .await?;
+
+        community.change_membership_policy(policy, self.clock.now());
+        if !community.pending_events().is_empty() {
+            self.community_repository.save(&community).await?;
+        }
+
+        Ok(CommunityUpdated::from(&community))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::change_membership_policy::ChangeMembershipPolicy,
+            ports::{
+                inbound::membership_policy_change::MembershipPolicyChangePort,
+                outbound::{
+                    community_repository::test_utils::FakeCommunityRepository,
+                    membership_check::test_utils::FakeMembershipCheck,
+                },
+            },
+            use_cases::change_membership_policy::ChangeMembershipPolicyUseCase,
+        },
+        domain::errors::error_codes::{
+            COMMUNITIES_INVALID_MEMBERSHIP_POLICY, COMMUNITIES_NOT_OWNER,
+        },
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::{application::auth_context::AuthContext, infrastructure::clock::FixedClock};
+    use std::sync::Arc;
+
+    fn use_case_with(
+        repo: FakeCommunityRepository,
+        membership_check: FakeMembershipCheck,
+    ) -> ChangeMembershipPolicyUseCase {
+        ChangeMembershipPolicyUseCase::new(
+            Arc::new(repo),
+            Arc::new(membership_check),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
+    fn auth_context_for(account_id: &AccountId) -> AuthContext {
+        AuthContext {
+            account_id: account_id.as_uuid().to_string(),
+        }
+    }
+
+    fn set_policy(policy: &str) -> ChangeMembershipPolicy {
+        ChangeMembershipPolicy {
+            slug: "rust-community".to_string(),
+            policy: policy.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn owner_changes_membership_policy() {
+        let owner_id = AccountId::generate();
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug_owned_by("rust-community", &owner_id),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case
+            .execute(set_policy("by_application"), auth_context_for(&owner_id))
+            .await;
+
+        assert_eq!(
+            result.expect("Expected community").membership_policy,
+            "by_application"
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_caller_is_an_admin() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case
+            .execute(
+                set_policy("closed"),
+                auth_context_for(&AccountId::generate()),
+            )
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_NOT_OWNER
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_policy_is_unknown() {
+        let owner_id = AccountId::generate();
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug_owned_by("rust-community", &owner_id),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case
+            .execute(set_policy("everyone"), auth_context_for(&owner_id))
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INVALID_MEMBERSHIP_POLICY
+        );
+    }
+}
@@ -4,35 +4,59 @@ use crate::{
         errors::community_creation::CommunityCreationError,
         ports::{
             inbound::community_creation::CommunityCreationPort,
-            outbound::community_repository::CommunityRepositoryPort,
+            outbound::{
+                community_repository::CommunityRepositoryPort,
+                owner_membership::OwnerMembershipPort,
+            },
         },
         results::community_created::CommunityCreated,
     },
     domain::{
         aggregates::community::Community,
+        errors::invalid_membership_policy::InvalidMembershipPolicy,
+        policies::membership_policy::MembershipPolicy,
         value_objects::{
             community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
         },
     },
 };
+use async_trait::async_trait;
 use iam::domain::value_objects::AccountId;
-use shared::{application::auth_context::AuthContext, error::SystemError};
+use shared::{
+    application::{
+        auth_context::AuthContext,
+        ports::{clock::ClockPort, unit_of_work::UnitOfWorkPort},
+    },
+    error::SystemError,
+};
 use std::sync::Arc;
 
 pub struct CreateCommunityUseCase {
     community_repository: Arc<dyn CommunityRepositoryPort>,
+    owner_membership: Arc<dyn OwnerMembershipPort>,
+    unit_of_work: Arc<dyn UnitOfWorkPort>,
+    clock: Arc<dyn ClockPort>,
 }
 
 impl CreateCommunityUseCase {
-    pub fn new(community_repository: Arc<dyn CommunityRepositoryPort>) -> Self {
+    pub fn new(
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        owner_membership: Arc<dyn OwnerMembershipPort>,
+        unit_of_work: Arc<dyn UnitOfWorkPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
         Self {
             community_repository,
+            owner_membership,
+            unit_of_work,
+            clock,
         }
     }
 }
 
+#[async_trait]
 impl CommunityCreationPort for CreateCommunityUseCase {
-    fn execute(
+    async fn execute(
         &self,
         data: CreateCommunity,
         auth: AuthContext,
@@ -40,22 +64,43 @@ impl CommunityCreationPort for CreateCommunityUseCase {
         let slug = CommunitySlug::new(data.slug)?;
         let existing_slug = self
             .community_repository
-            .find_by_slug(slug.clone().as_str());
+            .find_by_slug(slug.clone().as_str())
+            .await?;
         if existing_slug.is_some() {
             return Err(CommunityCreationError::SlugAlreadyExists.into());
         }
         let account_id = AccountId::from_str(auth.account_id.as_str())?;
         let id = CommunityId::generate();
         let name = CommunityName::new(data.name.clone())?;
+        let membership_policy = match data.membership_policy.as_deref() {
+            Some(policy) => MembershipPolicy::parse(policy).ok_or(InvalidMembershipPolicy)?,
+            None => MembershipPolicy::default_for(data.is_public),
+        };
 
-        let community = Community::create(id, account_id, slug, name, data.is_public);
-
-        self.community_repository.save(&community)?;
+        let community = Community::create(
+            id,
+            account_id,
+            slug,
+            name,
+            data.is_public,
+            membership_policy,
+            self.clock.now(),
+        );
+
+        let mut work = self.unit_of_work.begin();
+        self.community_repository
+            .save_in(work.as_mut(), &community)
+            .await?;
+        self.owner_membership
+            .register_owner_in(work.as_mut(), &community)
+            .await?;
+        work.commit().await?;
 
         Ok(CommunityCreated {
             id: community.id().as_uuid().to_string(),
             name: community.name().as_str().to_string(),
             slug: community.slug().as_str().to_string(),
+            membership_policy: community.effective_membership_policy().as_str().to_string(),
         })
     }
 }
@@ -65,21 +110,47 @@ mod tests {
     use crate::{
         application::{
             commands::create_community::CreateCommunity,
-            errors::error_codes::{COMMUNITIES_REPOSITORY_ERROR, COMMUNITIES_SLUG_ALREADY_EXISTS},
+            errors::error_codes::{
+                COMMUNITIES_MEMBERSHIP_UNAVAILABLE, COMMUNITIES_REPOSITORY_ERROR,
+                COMMUNITIES_REPOSITORY_UNAVAILABLE, COMMUNITIES_SLUG_ALREADY_EXISTS,
+            },
             ports::{
                 inbound::community_creation::CommunityCreationPort,
-                outbound::community_repository::test_utils::FakeCommunityRepository,
+                outbound::{
+                    community_repository::test_utils::FakeCommunityRepository,
+                    owner_membership::test_utils::FakeOwnerMembership,
+                },
             },
             use_cases::create_community::CreateCommunityUseCase,
         },
         domain::errors::error_codes::{
             COMMUNITIES_INVALID_COMMUNITY_NAME, COMMUNITIES_INVALID_COMMUNITY_SLUG,
+            COMMUNITIES_INVALID_MEMBERSHIP_POLICY,
         },
     };
     use iam::domain::value_objects::AccountId;
-    use shared::application::auth_context::AuthContext;
+    use shared::{
+        application::auth_context::AuthContext,
+        infrastructure::{clock::FixedClock, unit_of_work::InMemoryUnitOfWorkFactory},
+    };
     use std::sync::Arc;
 
+    fn use_case_with(repo: Arc<FakeCommunityRepository>) -> CreateCommunityUseCase {
+        use_case_with_owner_membership(repo, Arc::new(FakeOwnerMembership::available()))
+    }
+
+    fn use_case_with_owner_membership(
+        repo: Arc<FakeCommunityRepository>,
+        owner_membership: Arc<FakeOwnerMembership>,
+    ) -> CreateCommunityUseCase {
+        CreateCommunityUseCase::new(
+            repo,
+            owner_membership,
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
     fn valid_auth_context() -> AuthContext {
         AuthContext {
             account_id: AccountId::generate().as_uuid().to_string(),
@@ -91,80 +162,165 @@ mod tests {
             slug: "Community-Test".to_string(),
             name: "Community Test".to_string(),
             is_public: true,
+            membership_policy: None,
         }
     }
 
-    #[test]
-    fn create_community_successfully() {
+    #[tokio::test]
+    async fn create_community_successfully() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
-        let use_case = CreateCommunityUseCase::new(repo);
+        let use_case = use_case_with(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn fails_when_name_is_invalid() {
+    #[tokio::test]
+    async fn registers_the_owner_membership_with_the_community() {
+        let owner_membership = Arc::new(FakeOwnerMembership::available());
+        let use_case = use_case_with_owner_membership(
+            Arc::new(FakeCommunityRepository::success()),
+            owner_membership.clone(),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        let registered = owner_membership.registered.lock().unwrap();
+        assert_eq!(registered.len(), 1);
+        assert_eq!(
+            registered[0].as_uuid().to_string(),
+            result.expect("Expected community").id
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_owner_membership_cannot_be_registered() {
+        let use_case = use_case_with_owner_membership(
+            Arc::new(FakeCommunityRepository::success()),
+            Arc::new(FakeOwnerMembership::unavailable()),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_MEMBERSHIP_UNAVAILABLE
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_name_is_invalid() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
-        let use_case = CreateCommunityUseCase::new(repo);
+        let use_case = use_case_with(repo);
 
         let input = CreateCommunity {
             slug: "Community-Test".to_string(),
             name: "".to_string(),
             is_public: false,
+            membership_policy: None,
         };
 
-        let result = use_case.execute(input, valid_auth_context());
+        let result = use_case.execute(input, valid_auth_context()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), COMMUNITIES_INVALID_COMMUNITY_NAME);
     }
 
-    #[test]
-    fn fails_when_slug_is_invalid() {
+    #[tokio::test]
+    async fn defaults_membership_policy_from_visibility() {
+        let use_case = use_case_with(Arc::new(FakeCommunityRepository::success()));
+
+        let public = use_case.execute(valid_input(), valid_auth_context()).await;
+        let private = use_case
+            .execute(
+                CreateCommunity {
+                    is_public: false,
+                    ..valid_input()
+                },
+                valid_auth_context(),
+            )
+            .await;
+
+        assert_eq!(public.unwrap().membership_policy, "open");
+        assert_eq!(private.unwrap().membership_policy, "by_invitation");
+    }
+
+    #[tokio::test]
+    async fn fails_when_membership_policy_is_invalid() {
+        let use_case = use_case_with(Arc::new(FakeCommunityRepository::success()));
+
+        let input = CreateCommunity {
+            membership_policy: Some("whoever".to_string()),
+            ..valid_input()
+        };
+
+        let result = use_case.execute(input, valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INVALID_MEMBERSHIP_POLICY
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_slug_is_invalid() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
-        let use_case = CreateCommunityUseCase::new(repo);
+        let use_case = use_case_with(repo);
 
         let input = CreateCommunity {
             slug: "".to_string(),
             name: "Community Test".to_string(),
             is_public: false,
+            membership_policy: None,
         };
 
-        let result = use_case.execute(input, valid_auth_context());
+        let result = use_case.execute(input, valid_auth_context()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), COMMUNITIES_INVALID_COMMUNITY_SLUG);
     }
 
-    #[test]
-    fn fails_when_repository_fails() {
+    #[tokio::test]
+    async fn fails_when_repository_fails() {
         let repo = Arc::new(FakeCommunityRepository::fail());
 
-        let use_case = CreateCommunityUseCase::new(repo);
+        let use_case = use_case_with(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), COMMUNITIES_REPOSITORY_ERROR);
     }
 
-    #[test]
-    fn fails_when_slug_already_exists() {
+    #[tokio::test]
+    async fn fails_when_repository_is_unavailable() {
+        let repo = Arc::new(FakeCommunityRepository::unavailable());
+
+        let use_case = use_case_with(repo);
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), COMMUNITIES_REPOSITORY_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn fails_when_slug_already_exists() {
         let repo = Arc::new(FakeCommunityRepository::with_existing_slug(
             "community-test",
         ));
 
-        let use_case = CreateCommunityUseCase::new(repo);
+        let use_case = use_case_with(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         let err = result.expect_err("Expected error");
 
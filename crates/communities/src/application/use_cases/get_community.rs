@@ -0,0 +1,249 @@
+use crate::{
+    application::{
+        commands::get_community::GetCommunity,
+        errors::community_retrieval::CommunityRetrievalError,
+        ports::{
+            inbound::community_retrieval::CommunityRetrievalPort,
+            outbound::{
+                community_repository::CommunityRepositoryPort,
+                membership_check::MembershipCheckPort,
+            },
+        },
+        results::community_retrieved::CommunityRetrieved,
+    },
+    domain::{
+        aggregates::community::Community,
+        value_objects::{community_id::CommunityId, community_slug::CommunitySlug},
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct GetCommunityUseCase {
+    community_repository: Arc<dyn CommunityRepositoryPort>,
+    membership_check: Arc<dyn MembershipCheckPort>,
+}
+
+impl GetCommunityUseCase {
+    pub fn new(
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        membership_check: Arc<dyn MembershipCheckPort>,
+    ) -> Self {
+        Self {
+            community_repository,
+            membership_check,
+        }
+    }
+
+    async fn find(&self, identifier: &str) -> Result<Option<Community>, SystemError> {
+        if CommunityId::from_str(identifier).is_ok() {
+            return Ok(self.community_repository.find_by_id(identifier).await?);
+        }
+
+        match CommunitySlug::new(identifier.to_string()) {
+            Ok(slug) => Ok(self
+                .community_repository
+                .find_by_slug(slug.as_str())
+                .await?),
+            Err(_) => Ok(None),
+        }
+    }
+
+    async fn can_view(
+        &self,
+        community: &Community,
+        auth: Option<AuthContext>,
+    ) -> Result<bool, SystemError> {
+        if community.is_public() {
+            return Ok(true);
+        }
+        let Some(auth) = auth else {
+            return Ok(false);
+        };
+
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+        if community.owner_id() == &account_id {
+            return Ok(true);
+        }
+
+        Ok(self
+            .membership_check
+            .is_member(community.id(), &account_id)
+            .await?)
+    }
+}
+
+#[async_trait]
+impl CommunityRetrievalPort for GetCommunityUseCase {
+    async fn execute(
+        &self,
+        data: GetCommunity,
+        auth: Option<AuthContext>,
+    ) -> Result<CommunityRetrieved, SystemError> {
+        let identifier = data.identifier.trim();
+        let community = self
+            .find(identifier)
+            .await?
+            .ok_or(CommunityRetrievalError::NotFound)?;
+        if !self.can_view(&community, auth).await? {
+            return Err(CommunityRetrievalError::NotFound.into());
+        }
+        let requested = identifier.to_ascii_lowercase();
+
+        Ok(CommunityRetrieved {
+            id: community.id().as_uuid().to_string(),
+            name: community.name().as_str().to_string(),
+            slug: community.slug().as_str().to_string(),
+            description: community
+                .description()
+                .as_ref()
+                .map(|description| description.as_str().to_string()),
+            is_public: community.is_public(),
+            membership_policy: community.effective_membership_policy().as_str().to_string(),
+            owner_id: community.owner_id().as_uuid().to_string(),
+            member_count: community.member_count(),
+            moved_from: (community.slug().as_str() != requested
+                && community.is_known_as(&requested))
+            .then_some(requested),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::{
+        commands::get_community::GetCommunity,
+        errors::error_codes::{
+            COMMUNITIES_COMMUNITY_NOT_FOUND, COMMUNITIES_MEMBERSHIP_UNAVAILABLE,
+        },
+        ports::{
+            inbound::community_retrieval::CommunityRetrievalPort,
+            outbound::{
+                community_repository::test_utils::FakeCommunityRepository,
+                membership_check::test_utils::FakeMembershipCheck,
+            },
+        },
+        use_cases::get_community::GetCommunityUseCase,
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::application::auth_context::AuthContext;
+    use std::sync::Arc;
+
+    fn use_case_with(
+        repo: FakeCommunityRepository,
+        membership_check: FakeMembershipCheck,
+    ) -> GetCommunityUseCase {
+        GetCommunityUseCase::new(Arc::new(repo), Arc::new(membership_check))
+    }
+
+    fn valid_auth_context() -> AuthContext {
+        AuthContext {
+            account_id: AccountId::generate().as_uuid().to_string(),
+        }
+    }
+
+    fn by_slug(slug: &str) -> GetCommunity {
+        GetCommunity {
+            identifier: slug.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_public_community_to_anonymous_visitors() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case.execute(by_slug("Rust-Community"), None).await;
+
+        let community = result.expect("Expected community");
+        assert_eq!(community.slug, "rust-community");
+        assert!(community.is_public);
+        assert_eq!(community.member_count, 1);
+    }
+
+    #[tokio::test]
+    async fn returns_private_community_to_members() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_private_slug("rust-community"),
+            FakeMembershipCheck::member(),
+        );
+
+        let result = use_case
+            .execute(by_slug("rust-community"), Some(valid_auth_context()))
+            .await;
+
+        assert!(!result.expect("Expected community").is_public);
+    }
+
+    #[tokio::test]
+    async fn hides_private_community_from_non_members() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_private_slug("rust-community"),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let anonymous = use_case.execute(by_slug("rust-community"), None).await;
+        let stranger = use_case
+            .execute(by_slug("rust-community"), Some(valid_auth_context()))
+            .await;
+
+        assert_eq!(
+            anonymous.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+        assert_eq!(
+            stranger.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_when_a_previous_slug_was_requested() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_moved_slug("rust-community", "rustaceans"),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case.execute(by_slug("Rust-Community"), None).await;
+
+        let community = result.expect("Expected community");
+        assert_eq!(community.slug, "rustaceans");
+        assert_eq!(community.moved_from.as_deref(), Some("rust-community"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_community_does_not_exist() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::success(),
+            FakeMembershipCheck::member(),
+        );
+
+        let result = use_case.execute(by_slug("ghost-town"), None).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_memberships_are_unavailable() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_private_slug("rust-community"),
+            FakeMembershipCheck::unavailable(),
+        );
+
+        let result = use_case
+            .execute(by_slug("rust-community"), Some(valid_auth_context()))
+            .await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_MEMBERSHIP_UNAVAILABLE
+        );
+    }
+}
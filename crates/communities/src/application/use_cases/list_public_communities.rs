@@ -1,11 +1,17 @@
-use crate::application::{
-    commands::list_public_communities::ListPublicCommunities,
-    ports::{
-        inbound::public_communities_listing::PublicCommunitiesListingPort,
-        outbound::community_repository::CommunityRepositoryPort,
+use crate::{
+    application::{
+        commands::list_public_communities::ListPublicCommunities,
+        errors::community_listing::CommunityListingError,
+        pagination::community_page::{CommunityCursor, CommunitySort, PublicCommunitiesQuery},
+        ports::{
+            inbound::public_communities_listing::PublicCommunitiesListingPort,
+            outbound::community_repository::CommunityRepositoryPort,
+        },
+        results::public_communities_listed::{CommunityResult, PublicCommunitiesListed},
     },
-    results::public_communities_listed::{CommunityResult, PublicCommunitiesListed},
+    domain::value_objects::community_search::CommunitySearch,
 };
+use async_trait::async_trait;
 use shared::{application::auth_context::AuthContext, error::SystemError};
 use std::sync::Arc;
 
@@ -14,6 +20,9 @@ pub struct ListPublicCommunitiesUseCase {
 }
 
 impl ListPublicCommunitiesUseCase {
+    const DEFAULT_LIMIT: usize = 20;
+    const MAX_LIMIT: usize = 100;
+
     pub fn new(community_repository: Arc<dyn CommunityRepositoryPort>) -> Self {
         Self {
             community_repository,
@@ -21,121 +30,143 @@ impl ListPublicCommunitiesUseCase {
     }
 }
 
+#[async_trait]
 impl PublicCommunitiesListingPort for ListPublicCommunitiesUseCase {
-    fn execute(
+    async fn execute(
         &self,
         data: ListPublicCommunities,
-        _: AuthContext,
+        _: Option<AuthContext>,
     ) -> Result<PublicCommunitiesListed, SystemError> {
-        let communities: Vec<CommunityResult> = self
+        let sort = match data.sort.as_deref() {
+            Some(sort) => CommunitySort::parse(sort).ok_or(CommunityListingError::InvalidSort)?,
+            None => CommunitySort::Name,
+        };
+        let cursor = data
+            .cursor
+            .as_deref()
+            .map(|cursor| {
+                CommunityCursor::decode(cursor)
+                    .filter(|cursor| cursor.sort() == sort)
+                    .ok_or(CommunityListingError::InvalidCursor)
+            })
+            .transpose()?;
+        let limit = data
+            .limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT);
+
+        let page = self
             .community_repository
-            .get_public_list(data.query)
-            .iter()
-            .map(|community| CommunityResult {
-                name: community.name().as_str().to_string(),
-                slug: community.slug().as_str().to_string(),
+            .get_public_list(PublicCommunitiesQuery {
+                search: data.query.as_deref().and_then(CommunitySearch::new),
+                sort,
+                limit,
+                cursor,
             })
-            .collect();
+            .await?;
 
-        Ok(PublicCommunitiesListed { communities })
+        Ok(PublicCommunitiesListed {
+            communities: page
+                .communities
+                .iter()
+                .map(|community| CommunityResult {
+                    name: community.name().as_str().to_string(),
+                    slug: community.slug().as_str().to_string(),
+                    created_at: community.created_at(),
+                    member_count: community.member_count(),
+                })
+                .collect(),
+            next_cursor: page.next.as_ref().map(CommunityCursor::encode),
+            previous_cursor: page.previous.as_ref().map(CommunityCursor::encode),
+            total_estimate: page.total_estimate,
+        })
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use std::sync::Arc;
-//     use iam::domain::value_objects::AccountId;
-//     use shared::application::auth_context::AuthContext;
-//     use shared::application::common_application_error::CommonApplicationError;
-//     use crate::application::commands::create_community::CreateCommunity;
-//     use crate::application::errors::application_error::ApplicationError;
-//     use crate::application::ports::inbound::community_creation::CommunityCreationPort;
-//     use crate::application::ports::outbound::community_repository::test_utils::FakeCommunityRepository;
-//     use crate::application::use_cases::create_community::CreateCommunityUseCase;
-//
-//     fn valid_auth_context() -> AuthContext {
-//         AuthContext {
-//             account_id: AccountId::generate().as_uuid().to_string(),
-//         }
-//     }
-//
-//     fn valid_input() -> CreateCommunity {
-//         CreateCommunity {
-//             slug: "Community-Test".to_string(),
-//             name: "Community Test".to_string(),
-//             is_public: true,
-//         }
-//     }
-//
-//     #[test]
-//     fn create_community_successfully() {
-//         let repo = Arc::new(FakeCommunityRepository::success());
-//
-//         let use_case = CreateCommunityUseCase::new(repo);
-//
-//         let result = use_case.execute(valid_input(), valid_auth_context());
-//
-//         assert!(result.is_ok());
-//     }
-//
-//     #[test]
-//     fn fails_when_name_is_invalid() {
-//         let repo = Arc::new(FakeCommunityRepository::success());
-//
-//         let use_case = CreateCommunityUseCase::new(repo);
-//
-//         let input = CreateCommunity {
-//             slug: "Community-Test".to_string(),
-//             name: "".to_string(),
-//             is_public: false,
-//         };
-//
-//         let result = use_case.execute(input, valid_auth_context());
-//
-//         assert!(matches!(result, Err(ApplicationError::InvalidName)));
-//     }
-//
-//     #[test]
-//     fn fails_when_slug_is_invalid() {
-//         let repo = Arc::new(FakeCommunityRepository::success());
-//
-//         let use_case = CreateCommunityUseCase::new(repo);
-//
-//         let input = CreateCommunity {
-//             slug: "".to_string(),
-//             name: "Community Test".to_string(),
-//             is_public: false,
-//         };
-//
-//         let result = use_case.execute(input, valid_auth_context());
-//
-//         assert!(matches!(result, Err(ApplicationError::InvalidSlug)));
-//     }
-//
-//     #[test]
-//     fn fails_when_repository_fails() {
-//         let repo = Arc::new(FakeCommunityRepository::fail());
-//
-//         let use_case = CreateCommunityUseCase::new(repo);
-//
-//         let result = use_case.execute(valid_input(),valid_auth_context());
-//
-//         assert!(matches!(
-//             result,
-//             Err(ApplicationError::Common(
-//                 CommonApplicationError::Infrastructure
-//             ))
-//         ));
-//     }
-//
-//     #[test]
-//     fn fails_when_slug_already_exists() {
-//         let repo = Arc::new(FakeCommunityRepository::with_existing_slug("community-test"));
-//
-//         let use_case = CreateCommunityUseCase::new(repo);
-//
-//         let result = use_case.execute(valid_input(),valid_auth_context());
-//
-//         assert!(matches!(result, Err(ApplicationError::SlugAlreadyExists)));
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use crate::application::{
+        commands::list_public_communities::ListPublicCommunities,
+        errors::error_codes::{
+            COMMUNITIES_INVALID_CURSOR, COMMUNITIES_INVALID_SORT,
+            COMMUNITIES_REPOSITORY_UNAVAILABLE,
+        },
+        ports::{
+            inbound::public_communities_listing::PublicCommunitiesListingPort,
+            outbound::community_repository::test_utils::FakeCommunityRepository,
+        },
+        use_cases::list_public_communities::ListPublicCommunitiesUseCase,
+    };
+    use std::sync::Arc;
+
+    fn list() -> ListPublicCommunities {
+        ListPublicCommunities {
+            query: None,
+            sort: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_public_communities_for_anonymous_visitors() {
+        let repo = Arc::new(FakeCommunityRepository::with_existing_slug(
+            "rust-community",
+        ));
+
+        let use_case = ListPublicCommunitiesUseCase::new(repo);
+
+        let result = use_case.execute(list(), None).await.unwrap();
+
+        assert_eq!(result.communities.len(), 1);
+        assert_eq!(result.communities[0].slug, "rust-community");
+        assert_eq!(result.communities[0].name, "Rust Community");
+    }
+
+    #[tokio::test]
+    async fn fails_when_repository_is_unavailable() {
+        let repo = Arc::new(FakeCommunityRepository::unavailable());
+
+        let use_case = ListPublicCommunitiesUseCase::new(repo);
+
+        let result = use_case.execute(list(), None).await;
+
+        let err = result.err().expect("Expected error");
+
+        assert_eq!(err.code(), COMMUNITIES_REPOSITORY_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn fails_when_sort_is_unknown() {
+        let repo = Arc::new(FakeCommunityRepository::success());
+
+        let use_case = ListPublicCommunitiesUseCase::new(repo);
+
+        let input = ListPublicCommunities {
+            sort: Some("popularity".to_string()),
+            ..list()
+        };
+        let result = use_case.execute(input, None).await;
+
+        let err = result.err().expect("Expected error");
+
+        assert_eq!(err.code(), COMMUNITIES_INVALID_SORT);
+    }
+
+    #[tokio::test]
+    async fn fails_when_cursor_is_malformed() {
+        let repo = Arc::new(FakeCommunityRepository::success());
+
+        let use_case = ListPublicCommunitiesUseCase::new(repo);
+
+        let input = ListPublicCommunities {
+            cursor: Some("garbage".to_string()),
+            ..list()
+        };
+        let result = use_case.execute(input, None).await;
+
+        let err = result.err().expect("Expected error");
+
+        assert_eq!(err.code(), COMMUNITIES_INVALID_CURSOR);
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{
+    application::{
+        commands::update_community::UpdateCommunity,
+        errors::community_retrieval::CommunityRetrievalError,
+        policies::settings_access::SettingsAccessPolicy,
+        ports::{
+            inbound::community_update::CommunityUpdatePort,
+            outbound::{
+                community_repository::CommunityRepositoryPort,
+                membership_check::MembershipCheckPort,
+            },
+        },
+        results::community_updated::CommunityUpdated,
+    },
+    domain::value_objects::{
+        community_description::CommunityDescription, community_name::CommunityName,
+        community_slug::CommunitySlug,
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::{
+    application::{auth_context::AuthContext, ports::clock::ClockPort},
+    error::SystemError,
+};
+use std::sync::Arc;
+
+pub struct UpdateCommunityUseCase {
+    community_repository: Arc<dyn CommunityRepositoryPort>,
+    settings_access: SettingsAccessPolicy,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl UpdateCommunityUseCase {
+    pub fn new(
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        membership_check: Arc<dyn MembershipCheckPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            community_repository,
+            settings_access: SettingsAccessPolicy::new(membership_check),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl CommunityUpdatePort for UpdateCommunityUseCase {
+    async fn execute(
+        &self,
+        data: UpdateCommunity,
+        auth: AuthContext,
+    ) -> Result<CommunityUpdated, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+        let slug = CommunitySlug::new(data.slug).map_err(|_| CommunityRetrievalError::NotFound)?;
+        let name = data.name.map(CommunityName::new).transpose()?;
+        let description = data
+            .description
+            .map(CommunityDescription::new)
+            .transpose()?;
+
+        let mut community = self
+            .community_repository
+            .find_by_slug(slug.as_str())
+            .await?
+            .ok_or(CommunityRetrievalError::NotFound)?;
+        self.settings_access
+            .authorize(&community, &account_id)
+            .await?;
+
+        let now = self.clock.now();
+        if let Some(name) = name {
+            community.rename(name, now);
+        }
+        if let Some(public) = data.is_public {
+            community.change_visibility(public, now);
+        }
+        if let Some(description) = description {
+            community.change_description(description, now);
+        }
+        if !community.pending_events().is_empty() {
+            self.community_repository.save(&community).await?;
+        }
+
+        Ok(CommunityUpdated::from(&community))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::update_community::UpdateCommunity,
+            errors::error_codes::{
+                COMMUNITIES_COMMUNITY_NOT_FOUND, COMMUNITIES_REPOSITORY_UNAVAILABLE,
+            },
+            ports::{
+                inbound::community_update::CommunityUpdatePort,
+                outbound::{
+                    community_repository::test_utils::FakeCommunityRepository,
+                    membership_check::test_utils::FakeMembershipCheck,
+                },
+            },
+            use_cases::update_community::UpdateCommunityUseCase,
+        },
+        domain::errors::error_codes::{
+            COMMUNITIES_INSUFFICIENT_PERMISSIONS, COMMUNITIES_INVALID_COMMUNITY_NAME,
+        },
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::{application::auth_context::AuthContext, infrastructure::clock::FixedClock};
+    use std::sync::Arc;
+
+    fn use_case_with(
+        repo: FakeCommunityRepository,
+        membership_check: FakeMembershipCheck,
+    ) -> UpdateCommunityUseCase {
+        UpdateCommunityUseCase::new(
+            Arc::new(repo),
+            Arc::new(membership_check),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
+    fn auth_context_for(account_id: &AccountId) -> AuthContext {
+        AuthContext {
+            account_id: account_id.as_uuid().to_string(),
+        }
+    }
+
+    fn valid_auth_context() -> AuthContext {
+        auth_context_for(&AccountId::generate())
+    }
+
+    fn valid_input() -> UpdateCommunity {
+        UpdateCommunity {
+            slug: "rust-community".to_string(),
+            name: Some("Rustaceans United".to_string()),
+            is_public: Some(false),
+            description: Some("All things Rust".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn owner_updates_community_settings() {
+        let owner_id = AccountId::generate();
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug_owned_by("rust-community", &owner_id),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case
+            .execute(valid_input(), auth_context_for(&owner_id))
+            .await;
+
+        let community = result.expect("Expected updated community");
+        assert_eq!(community.name, "Rustaceans United");
+        assert!(!community.is_public);
+        assert_eq!(community.description.as_deref(), Some("All things Rust"));
+    }
+
+    #[tokio::test]
+    async fn admin_updates_community_settings() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let input = UpdateCommunity {
+            name: None,
+            is_public: None,
+            ..valid_input()
+        };
+        let result = use_case.execute(input, valid_auth_context()).await;
+
+        let community = result.expect("Expected updated community");
+        assert_eq!(community.name, "Rust Community");
+        assert_eq!(community.description.as_deref(), Some("All things Rust"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_caller_is_a_regular_member() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::member(),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INSUFFICIENT_PERMISSIONS
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_private_community_from_strangers() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_private_slug("rust-community"),
+            FakeMembershipCheck::stranger(),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_name_is_invalid() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::with_existing_slug("rust-community"),
+            FakeMembershipCheck::admin(),
+        );
+
+        let input = UpdateCommunity {
+            name: Some("abc".to_string()),
+            ..valid_input()
+        };
+        let result = use_case.execute(input, valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_INVALID_COMMUNITY_NAME
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_community_does_not_exist() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::success(),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_COMMUNITY_NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_repository_is_unavailable() {
+        let use_case = use_case_with(
+            FakeCommunityRepository::unavailable(),
+            FakeMembershipCheck::admin(),
+        );
+
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
+
+        assert_eq!(
+            result.expect_err("Expected error").code(),
+            COMMUNITIES_REPOSITORY_UNAVAILABLE
+        );
+    }
+}
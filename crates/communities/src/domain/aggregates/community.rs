@@ -1,36 +1,100 @@
 use crate::domain::{
+    events::{
+        community_created::CommunityCreated,
+        community_description_changed::CommunityDescriptionChanged,
+        community_renamed::CommunityRenamed, community_slug_changed::CommunitySlugChanged,
+        community_visibility_changed::CommunityVisibilityChanged,
+        membership_policy_changed::MembershipPolicyChanged,
+    },
     policies::membership_policy::MembershipPolicy,
     value_objects::{
-        community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+        community_description::CommunityDescription, community_id::CommunityId,
+        community_name::CommunityName, community_slug::CommunitySlug,
     },
 };
 use iam::domain::value_objects::AccountId;
+use shared::domain::events::{DomainEvent, DomainEvents};
+use std::{sync::Arc, time::SystemTime};
 
 #[derive(Debug, Clone)]
 pub struct Community {
     id: CommunityId,
-    _owner_id: AccountId,
+    owner_id: AccountId,
     slug: CommunitySlug,
+    previous_slugs: Vec<CommunitySlug>,
     name: CommunityName,
+    description: Option<CommunityDescription>,
     public: bool,
     membership_policy: Option<MembershipPolicy>,
+    created_at: SystemTime,
+    member_count: u64,
+    version: u64,
+    events: DomainEvents,
 }
 
 impl Community {
     pub fn create(
         id: CommunityId,
-        _owner_id: AccountId,
+        owner_id: AccountId,
+        slug: CommunitySlug,
+        name: CommunityName,
+        public: bool,
+        membership_policy: MembershipPolicy,
+        now: SystemTime,
+    ) -> Self {
+        let mut events = DomainEvents::new();
+        events.record(CommunityCreated {
+            community_id: id.clone(),
+            owner_id: owner_id.clone(),
+            slug: slug.as_str().to_string(),
+            public,
+            membership_policy,
+            occurred_at: now,
+        });
+
+        Self {
+            id,
+            owner_id,
+            slug,
+            previous_slugs: Vec::new(),
+            name,
+            description: None,
+            public,
+            membership_policy: Some(membership_policy),
+            created_at: now,
+            member_count: 1,
+            version: 0,
+            events,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: CommunityId,
+        owner_id: AccountId,
         slug: CommunitySlug,
+        previous_slugs: Vec<CommunitySlug>,
         name: CommunityName,
+        description: Option<CommunityDescription>,
         public: bool,
+        membership_policy: Option<MembershipPolicy>,
+        created_at: SystemTime,
+        member_count: u64,
+        version: u64,
     ) -> Self {
         Self {
             id,
-            _owner_id,
+            owner_id,
             slug,
+            previous_slugs,
             name,
+            description,
             public,
-            membership_policy: None,
+            membership_policy,
+            created_at,
+            member_count,
+            version,
+            events: DomainEvents::new(),
         }
     }
 
@@ -38,14 +102,34 @@ impl Community {
         &self.id
     }
 
+    pub fn owner_id(&self) -> &AccountId {
+        &self.owner_id
+    }
+
     pub fn slug(&self) -> &CommunitySlug {
         &self.slug
     }
 
+    pub fn previous_slugs(&self) -> &[CommunitySlug] {
+        &self.previous_slugs
+    }
+
+    pub fn is_known_as(&self, slug: &str) -> bool {
+        self.slug.as_str() == slug
+            || self
+                .previous_slugs
+                .iter()
+                .any(|previous| previous.as_str() == slug)
+    }
+
     pub fn name(&self) -> &CommunityName {
         &self.name
     }
 
+    pub fn description(&self) -> &Option<CommunityDescription> {
+        &self.description
+    }
+
     pub fn is_public(&self) -> bool {
         self.public
     }
@@ -53,11 +137,129 @@ impl Community {
     pub fn membership_policy(&self) -> &Option<MembershipPolicy> {
         &self.membership_policy
     }
+
+    pub fn effective_membership_policy(&self) -> MembershipPolicy {
+        self.membership_policy
+            .unwrap_or_else(|| MembershipPolicy::default_for(self.public))
+    }
+
+    pub fn created_at(&self) -> SystemTime {
+        self.created_at
+    }
+
+    pub fn member_count(&self) -> u64 {
+        self.member_count
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub(crate) fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
+    pub fn member_joined(&mut self) {
+        self.member_count += 1;
+    }
+
+    pub fn member_left(&mut self) {
+        self.member_count = self.member_count.saturating_sub(1);
+    }
+
+    pub fn change_slug(&mut self, slug: CommunitySlug, now: SystemTime) {
+        if self.slug == slug {
+            return;
+        }
+
+        self.events.record(CommunitySlugChanged {
+            community_id: self.id.clone(),
+            previous_slug: self.slug.as_str().to_string(),
+            new_slug: slug.as_str().to_string(),
+            occurred_at: now,
+        });
+        self.previous_slugs.retain(|previous| previous != &slug);
+        let previous = std::mem::replace(&mut self.slug, slug);
+        self.previous_slugs.push(previous);
+    }
+
+    pub fn rename(&mut self, name: CommunityName, now: SystemTime) {
+        if self.name == name {
+            return;
+        }
+
+        self.events.record(CommunityRenamed {
+            community_id: self.id.clone(),
+            previous_name: self.name.as_str().to_string(),
+            new_name: name.as_str().to_string(),
+            occurred_at: now,
+        });
+        self.name = name;
+    }
+
+    pub fn change_visibility(&mut self, public: bool, now: SystemTime) {
+        if self.public == public {
+            return;
+        }
+
+        self.events.record(CommunityVisibilityChanged {
+            community_id: self.id.clone(),
+            public,
+            occurred_at: now,
+        });
+        self.public = public;
+    }
+
+    pub fn change_description(
+        &mut self,
+        description: Option<CommunityDescription>,
+        now: SystemTime,
+    ) {
+        if self.description == description {
+            return;
+        }
+
+        self.events.record(CommunityDescriptionChanged {
+            community_id: self.id.clone(),
+            description: description
+                .as_ref()
+                .map(|description| description.as_str().to_string()),
+            occurred_at: now,
+        });
+        self.description = description;
+    }
+
+    pub fn change_membership_policy(&mut self, policy: MembershipPolicy, now: SystemTime) {
+        if self.membership_policy == Some(policy) {
+            return;
+        }
+
+        self.events.record(MembershipPolicyChanged {
+            community_id: self.id.clone(),
+            previous_policy: self.membership_policy,
+            new_policy: policy,
+            occurred_at: now,
+        });
+        self.membership_policy = Some(policy);
+    }
+
+    pub fn pending_events(&self) -> &[Arc<dyn DomainEvent>] {
+        self.events.pending()
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn DomainEvent>> {
+        self.events.take()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn slug(value: &str) -> CommunitySlug {
+        CommunitySlug::new(value.to_string()).unwrap()
+    }
 
     impl Community {
         pub fn dummy_community() -> Community {
@@ -67,6 +269,8 @@ mod tests {
                 CommunitySlug::new("rust-community".to_string()).unwrap(),
                 CommunityName::new("Rust Community".to_string()).unwrap(),
                 true,
+                MembershipPolicy::Open,
+                UNIX_EPOCH,
             )
         }
 
@@ -77,7 +281,112 @@ mod tests {
                 CommunitySlug::new("rust-community".to_string()).unwrap(),
                 CommunityName::new("Rust Community".to_string()).unwrap(),
                 false,
+                MembershipPolicy::ByInvitation,
+                UNIX_EPOCH,
             )
         }
     }
+
+    #[test]
+    fn creating_records_community_created_event() {
+        let mut community = Community::dummy_community();
+
+        let events = community.pull_events();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), CommunityCreated::EVENT_TYPE);
+    }
+
+    #[test]
+    fn counts_the_owner_as_the_first_member() {
+        let mut community = Community::dummy_community();
+
+        community.member_joined();
+        community.member_joined();
+        community.member_left();
+
+        assert_eq!(community.member_count(), 2);
+    }
+
+    #[test]
+    fn changing_membership_policy_records_event_once() {
+        let mut community = Community::dummy_community();
+        community.pull_events();
+
+        community.change_membership_policy(MembershipPolicy::Closed, UNIX_EPOCH);
+        community.change_membership_policy(MembershipPolicy::Closed, UNIX_EPOCH);
+
+        let events = community.pull_events();
+        let event = events[0]
+            .as_any()
+            .downcast_ref::<MembershipPolicyChanged>()
+            .expect("Expected MembershipPolicyChanged");
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(event.new_policy, MembershipPolicy::Closed);
+        assert_eq!(
+            community.membership_policy(),
+            &Some(MembershipPolicy::Closed)
+        );
+    }
+
+    #[test]
+    fn changing_slug_keeps_previous_slugs() {
+        let mut community = Community::dummy_community();
+        community.pull_events();
+
+        community.change_slug(slug("rustaceans"), UNIX_EPOCH);
+        community.change_slug(slug("ferris-club"), UNIX_EPOCH);
+        community.change_slug(slug("rustaceans"), UNIX_EPOCH);
+
+        let events = community.pull_events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].event_type(), CommunitySlugChanged::EVENT_TYPE);
+        assert_eq!(community.slug().as_str(), "rustaceans");
+        assert_eq!(
+            community.previous_slugs(),
+            &[slug("rust-community"), slug("ferris-club")]
+        );
+        assert!(community.is_known_as("rust-community"));
+        assert!(!community.is_known_as("go-community"));
+    }
+
+    #[test]
+    fn updating_settings_records_only_actual_changes() {
+        let mut community = Community::dummy_community();
+        community.pull_events();
+
+        community.rename(
+            CommunityName::new("Rust Community".to_string()).unwrap(),
+            UNIX_EPOCH,
+        );
+        community.rename(
+            CommunityName::new("Rustaceans United".to_string()).unwrap(),
+            UNIX_EPOCH,
+        );
+        community.change_visibility(true, UNIX_EPOCH);
+        community.change_visibility(false, UNIX_EPOCH);
+        community.change_description(
+            CommunityDescription::new("All things Rust".to_string()).unwrap(),
+            UNIX_EPOCH,
+        );
+
+        let events = community.pull_events();
+        let event_types: Vec<&str> = events.iter().map(|event| event.event_type()).collect();
+
+        assert_eq!(
+            event_types,
+            [
+                CommunityRenamed::EVENT_TYPE,
+                CommunityVisibilityChanged::EVENT_TYPE,
+                CommunityDescriptionChanged::EVENT_TYPE,
+            ]
+        );
+        assert_eq!(community.name().as_str(), "Rustaceans United");
+        assert!(!community.is_public());
+        assert_eq!(
+            community.description().as_ref().map(|d| d.as_str()),
+            Some("All things Rust")
+        );
+    }
 }
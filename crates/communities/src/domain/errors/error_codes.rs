@@ -1,6 +1,9 @@
 pub const COMMUNITIES_INVALID_COMMUNITY_ID: &str = "COMMUNITIES_INVALID_COMMUNITY_ID";
+pub const COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION: &str =
+    "COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION";
 pub const COMMUNITIES_INVALID_COMMUNITY_NAME: &str = "COMMUNITIES_INVALID_COMMUNITY_NAME";
 pub const COMMUNITIES_INVALID_COMMUNITY_SLUG: &str = "COMMUNITIES_INVALID_COMMUNITY_SLUG";
+pub const COMMUNITIES_INVALID_MEMBERSHIP_POLICY: &str = "COMMUNITIES_INVALID_MEMBERSHIP_POLICY";
 pub const COMMUNITIES_NOT_OWNER: &str = "COMMUNITIES_NOT_OWNER";
 pub const COMMUNITIES_NOT_MEMBER: &str = "COMMUNITIES_NOT_MEMBER";
 pub const COMMUNITIES_ALREADY_MEMBER: &str = "COMMUNITIES_ALREADY_MEMBER";
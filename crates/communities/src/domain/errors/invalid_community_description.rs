@@ -0,0 +1,28 @@
+use super::error_codes::COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidCommunityDescription;
+
+impl fmt::Display for InvalidCommunityDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid community description")
+    }
+}
+
+impl std::error::Error for InvalidCommunityDescription {}
+
+impl LayerError for InvalidCommunityDescription {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        COMMUNITIES_INVALID_COMMUNITY_DESCRIPTION
+    }
+
+    fn message(&self) -> &'static str {
+        "Please enter a valid community description."
+    }
+}
@@ -0,0 +1,28 @@
+use super::error_codes::COMMUNITIES_INVALID_MEMBERSHIP_POLICY;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidMembershipPolicy;
+
+impl fmt::Display for InvalidMembershipPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid membership policy")
+    }
+}
+
+impl std::error::Error for InvalidMembershipPolicy {}
+
+impl LayerError for InvalidMembershipPolicy {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        COMMUNITIES_INVALID_MEMBERSHIP_POLICY
+    }
+
+    fn message(&self) -> &'static str {
+        "Please choose open, by_invitation, by_application or closed."
+    }
+}
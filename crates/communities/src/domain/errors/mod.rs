@@ -1,5 +1,7 @@
 pub mod community_error;
 pub mod error_codes;
+pub mod invalid_community_description;
 pub mod invalid_community_id;
 pub mod invalid_community_name;
 pub mod invalid_community_slug;
+pub mod invalid_membership_policy;
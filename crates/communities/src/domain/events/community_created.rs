@@ -0,0 +1,48 @@
+use crate::domain::{
+    policies::membership_policy::MembershipPolicy, value_objects::community_id::CommunityId,
+};
+use iam::domain::value_objects::AccountId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct CommunityCreated {
+    pub community_id: CommunityId,
+    pub owner_id: AccountId,
+    pub slug: String,
+    pub public: bool,
+    pub membership_policy: MembershipPolicy,
+    pub occurred_at: SystemTime,
+}
+
+impl CommunityCreated {
+    pub const EVENT_TYPE: &'static str = "communities.community_created";
+}
+
+impl DomainEvent for CommunityCreated {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.community_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "community_id": self.aggregate_id(),
+            "owner_id": self.owner_id.as_uuid().to_string(),
+            "slug": self.slug,
+            "public": self.public,
+            "membership_policy": self.membership_policy.as_str(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
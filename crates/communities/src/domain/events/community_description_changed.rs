@@ -0,0 +1,39 @@
+use crate::domain::value_objects::community_id::CommunityId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct CommunityDescriptionChanged {
+    pub community_id: CommunityId,
+    pub description: Option<String>,
+    pub occurred_at: SystemTime,
+}
+
+impl CommunityDescriptionChanged {
+    pub const EVENT_TYPE: &'static str = "communities.community_description_changed";
+}
+
+impl DomainEvent for CommunityDescriptionChanged {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.community_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "community_id": self.aggregate_id(),
+            "description": self.description,
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,41 @@
+use crate::domain::value_objects::community_id::CommunityId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct CommunityRenamed {
+    pub community_id: CommunityId,
+    pub previous_name: String,
+    pub new_name: String,
+    pub occurred_at: SystemTime,
+}
+
+impl CommunityRenamed {
+    pub const EVENT_TYPE: &'static str = "communities.community_renamed";
+}
+
+impl DomainEvent for CommunityRenamed {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.community_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "community_id": self.aggregate_id(),
+            "previous_name": self.previous_name,
+            "new_name": self.new_name,
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
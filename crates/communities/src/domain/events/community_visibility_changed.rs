@@ -0,0 +1,39 @@
+use crate::domain::value_objects::community_id::CommunityId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct CommunityVisibilityChanged {
+    pub community_id: CommunityId,
+    pub public: bool,
+    pub occurred_at: SystemTime,
+}
+
+impl CommunityVisibilityChanged {
+    pub const EVENT_TYPE: &'static str = "communities.community_visibility_changed";
+}
+
+impl DomainEvent for CommunityVisibilityChanged {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.community_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "community_id": self.aggregate_id(),
+            "public": self.public,
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
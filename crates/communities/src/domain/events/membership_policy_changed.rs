@@ -1,6 +1,43 @@
-use crate::domain::policies::membership_policy::MembershipPolicy;
+use crate::domain::{
+    policies::membership_policy::MembershipPolicy, value_objects::community_id::CommunityId,
+};
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
 
 #[derive(Debug, Clone)]
 pub struct MembershipPolicyChanged {
+    pub community_id: CommunityId,
+    pub previous_policy: Option<MembershipPolicy>,
     pub new_policy: MembershipPolicy,
+    pub occurred_at: SystemTime,
+}
+
+impl MembershipPolicyChanged {
+    pub const EVENT_TYPE: &'static str = "communities.membership_policy_changed";
+}
+
+impl DomainEvent for MembershipPolicyChanged {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.community_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "community_id": self.aggregate_id(),
+            "previous_policy": self.previous_policy.map(|policy| policy.as_str()),
+            "new_policy": self.new_policy.as_str(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
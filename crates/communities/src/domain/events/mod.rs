@@ -1 +1,6 @@
+pub mod community_created;
+pub mod community_description_changed;
+pub mod community_renamed;
+pub mod community_slug_changed;
+pub mod community_visibility_changed;
 pub mod membership_policy_changed;
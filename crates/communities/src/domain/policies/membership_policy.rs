@@ -1,7 +1,36 @@
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MembershipPolicy {
     Open,
     ByInvitation,
     ByApplication,
     Closed,
 }
+
+impl MembershipPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "open" => Some(MembershipPolicy::Open),
+            "by_invitation" => Some(MembershipPolicy::ByInvitation),
+            "by_application" => Some(MembershipPolicy::ByApplication),
+            "closed" => Some(MembershipPolicy::Closed),
+            _ => None,
+        }
+    }
+
+    pub fn default_for(public: bool) -> Self {
+        if public {
+            MembershipPolicy::Open
+        } else {
+            MembershipPolicy::ByInvitation
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembershipPolicy::Open => "open",
+            MembershipPolicy::ByInvitation => "by_invitation",
+            MembershipPolicy::ByApplication => "by_application",
+            MembershipPolicy::Closed => "closed",
+        }
+    }
+}
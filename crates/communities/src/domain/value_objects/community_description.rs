@@ -0,0 +1,52 @@
+use crate::domain::errors::invalid_community_description::InvalidCommunityDescription;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommunityDescription(String);
+
+impl CommunityDescription {
+    const MAX_LENGTH: usize = 500;
+
+    pub fn new(value: String) -> Result<Option<Self>, InvalidCommunityDescription> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+
+        if trimmed.chars().count() > Self::MAX_LENGTH {
+            return Err(InvalidCommunityDescription);
+        }
+
+        Ok(Some(Self(trimmed.to_string())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_whitespace() {
+        let description = CommunityDescription::new("  A place for Rustaceans  ".to_string())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(description.as_str(), "A place for Rustaceans");
+    }
+
+    #[test]
+    fn treats_blank_description_as_absent() {
+        assert_eq!(CommunityDescription::new("   ".to_string()), Ok(None));
+    }
+
+    #[test]
+    fn rejects_too_long_description() {
+        let result = CommunityDescription::new("é".repeat(501));
+
+        assert_eq!(result, Err(InvalidCommunityDescription));
+    }
+}
@@ -0,0 +1,60 @@
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommunitySearch(String);
+
+impl CommunitySearch {
+    pub fn new(query: &str) -> Option<Self> {
+        let folded = Self::fold(query);
+
+        if folded.is_empty() {
+            return None;
+        }
+
+        Some(Self(folded))
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        Self::fold(text).contains(&self.0)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn fold(value: &str) -> String {
+        value
+            .trim()
+            .nfkd()
+            .filter(|c| !is_combining_mark(*c))
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_blank_queries() {
+        assert_eq!(CommunitySearch::new("   "), None);
+    }
+
+    #[test]
+    fn matches_regardless_of_case() {
+        let search = CommunitySearch::new("RUST").unwrap();
+
+        assert!(search.matches("Rustaceans"));
+        assert!(!search.matches("Gophers"));
+    }
+
+    #[test]
+    fn matches_regardless_of_accents() {
+        let search = CommunitySearch::new("creme").unwrap();
+        let accented = CommunitySearch::new("Crème").unwrap();
+
+        assert!(search.matches("Café Crème"));
+        assert!(accented.matches("creme brulee"));
+    }
+}
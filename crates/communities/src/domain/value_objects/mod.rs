@@ -1,3 +1,5 @@
+pub mod community_description;
 pub mod community_id;
 pub mod community_name;
+pub mod community_search;
 pub mod community_slug;
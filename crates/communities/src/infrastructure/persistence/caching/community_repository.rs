@@ -0,0 +1,268 @@
+use crate::{
+    application::{
+        errors::community_repository::CommunityRepositoryError,
+        pagination::community_page::{CommunityPage, PublicCommunitiesQuery},
+        ports::outbound::community_repository::CommunityRepositoryPort,
+    },
+    domain::{aggregates::community::Community, value_objects::community_id::CommunityId},
+};
+use async_trait::async_trait;
+use shared::{
+    application::ports::{clock::ClockPort, unit_of_work::UnitOfWork},
+    infrastructure::cache::{CachePolicy, CacheStats, LruCache},
+};
+use std::sync::Arc;
+
+struct Caches {
+    communities: LruCache<CommunityId, Option<Community>>,
+    slugs: LruCache<String, Option<CommunityId>>,
+    lists: LruCache<PublicCommunitiesQuery, CommunityPage>,
+}
+
+impl Caches {
+    fn invalidate(&self, community: &Community) {
+        let mut slugs = vec![community.slug().as_str().to_string()];
+        if let Some(Some(cached)) = self.communities.peek(community.id()) {
+            slugs.push(cached.slug().as_str().to_string());
+        }
+
+        self.slugs.invalidate(&slugs);
+        self.communities.invalidate(&[community.id().clone()]);
+        self.lists.clear();
+    }
+
+    fn invalidate_counts(&self, id: &CommunityId) {
+        self.communities.invalidate(std::slice::from_ref(id));
+        self.lists.clear();
+    }
+}
+
+pub struct CachingCommunityRepository {
+    inner: Arc<dyn CommunityRepositoryPort>,
+    caches: Arc<Caches>,
+    policy: CachePolicy,
+}
+
+impl CachingCommunityRepository {
+    pub fn new(
+        inner: Arc<dyn CommunityRepositoryPort>,
+        policy: CachePolicy,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            inner,
+            caches: Arc::new(Caches {
+                communities: LruCache::new(policy.capacity, policy.ttl, clock.clone()),
+                slugs: LruCache::new(policy.capacity, policy.ttl, clock.clone()),
+                lists: LruCache::new(policy.capacity, policy.ttl, clock),
+            }),
+            policy,
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.caches.communities.stats() + self.caches.slugs.stats() + self.caches.lists.stats()
+    }
+
+    fn ttl_for<T>(&self, found: &Option<T>) -> std::time::Duration {
+        if found.is_some() {
+            self.policy.ttl
+        } else {
+            self.policy.negative_ttl
+        }
+    }
+
+    async fn find_cached_by_id(
+        &self,
+        id: CommunityId,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        if let Some(cached) = self.caches.communities.get(&id) {
+            return Ok(cached);
+        }
+
+        let generation = self.caches.communities.generation();
+        let found = self.inner.find_by_id(&id.as_uuid().to_string()).await?;
+        let ttl = self.ttl_for(&found);
+        self.caches
+            .communities
+            .fill(generation, id, found.clone(), ttl);
+
+        Ok(found)
+    }
+}
+
+#[async_trait]
+impl CommunityRepositoryPort for CachingCommunityRepository {
+    async fn get_public_list(
+        &self,
+        query: PublicCommunitiesQuery,
+    ) -> Result<CommunityPage, CommunityRepositoryError> {
+        if let Some(cached) = self.caches.lists.get(&query) {
+            return Ok(cached);
+        }
+
+        let generation = self.caches.lists.generation();
+        let found = self.inner.get_public_list(query.clone()).await?;
+        self.caches
+            .lists
+            .fill(generation, query, found.clone(), self.policy.ttl);
+
+        Ok(found)
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError> {
+        match CommunityId::from_str(id) {
+            Ok(id) => self.find_cached_by_id(id).await,
+            Err(_) => self.inner.find_by_id(id).await,
+        }
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let key = slug.to_string();
+        match self.caches.slugs.get(&key) {
+            Some(None) => return Ok(None),
+            Some(Some(id)) => {
+                if let Some(community) = self.find_cached_by_id(id).await?
+                    && community.is_known_as(slug)
+                {
+                    return Ok(Some(community));
+                }
+            }
+            None => {}
+        }
+
+        let generation = (
+            self.caches.communities.generation(),
+            self.caches.slugs.generation(),
+        );
+        let found = self.inner.find_by_slug(slug).await?;
+        let id = found.as_ref().map(|community| community.id().clone());
+        let ttl = self.ttl_for(&id);
+        if let Some(community) = &found {
+            self.caches.communities.fill(
+                generation.0,
+                community.id().clone(),
+                Some(community.clone()),
+                ttl,
+            );
+        }
+        self.caches.slugs.fill(generation.1, key, id, ttl);
+
+        Ok(found)
+    }
+
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
+        let result = self.inner.save(community).await;
+        self.caches.invalidate(community);
+        result
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError> {
+        self.inner.save_in(unit_of_work, community).await?;
+
+        let caches = self.caches.clone();
+        let saved = community.clone();
+        unit_of_work.on_commit(Box::new(move || caches.invalidate(&saved)));
+
+        Ok(())
+    }
+
+    async fn record_member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        id: &CommunityId,
+    ) -> Result<(), CommunityRepositoryError> {
+        self.inner.record_member_joined_in(unit_of_work, id).await?;
+
+        let caches = self.caches.clone();
+        let id = id.clone();
+        unit_of_work.on_commit(Box::new(move || caches.invalidate_counts(&id)));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::{
+        community_repository_contract::{community, community_repository_contract, public_query},
+        in_memory::community_repository::InMemoryCommunityRepository,
+    };
+    use shared::infrastructure::{
+        clock::FixedClock, outbox::InMemoryOutboxStore, unit_of_work::InMemoryUnitOfWorkFactory,
+    };
+    use std::time::Duration;
+
+    const POLICY: CachePolicy = CachePolicy {
+        capacity: 16,
+        ttl: Duration::from_secs(60),
+        negative_ttl: Duration::from_secs(5),
+    };
+
+    fn repository() -> CachingCommunityRepository {
+        CachingCommunityRepository::new(
+            Arc::new(InMemoryCommunityRepository::new(Arc::new(
+                InMemoryOutboxStore::new(),
+            ))),
+            POLICY,
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
+    community_repository_contract!(
+        repository(),
+        (repository(), InMemoryUnitOfWorkFactory::new())
+    );
+
+    #[tokio::test]
+    async fn serves_repeated_reads_from_the_cache() {
+        let repository = repository();
+        let saved = community("rust-lang", "Rust Lang", true);
+        repository.save(&saved).await.unwrap();
+
+        repository.find_by_slug("rust-lang").await.unwrap();
+        repository.find_by_slug("rust-lang").await.unwrap();
+        repository
+            .find_by_id(&saved.id().as_uuid().to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(repository.stats(), CacheStats { hits: 3, misses: 1 });
+    }
+
+    #[tokio::test]
+    async fn saving_refreshes_cached_public_lists() {
+        let repository = repository();
+        repository
+            .save(&community("rust-lang", "Rust Lang", true))
+            .await
+            .unwrap();
+        repository
+            .get_public_list(public_query(None))
+            .await
+            .unwrap();
+
+        repository
+            .save(&community("go-lang", "Go Lang", true))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repository
+                .get_public_list(public_query(None))
+                .await
+                .unwrap()
+                .communities
+                .len(),
+            2
+        );
+    }
+}
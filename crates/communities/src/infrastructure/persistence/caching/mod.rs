@@ -0,0 +1 @@
+pub mod community_repository;
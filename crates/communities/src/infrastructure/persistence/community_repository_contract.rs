@@ -0,0 +1,551 @@
+use crate::{
+    application::pagination::community_page::{CommunitySort, PublicCommunitiesQuery},
+    domain::{
+        aggregates::community::Community,
+        policies::membership_policy::MembershipPolicy,
+        value_objects::{
+            community_id::CommunityId, community_name::CommunityName,
+            community_search::CommunitySearch, community_slug::CommunitySlug,
+        },
+    },
+};
+use iam::domain::value_objects::AccountId;
+use std::time::{Duration, UNIX_EPOCH};
+
+pub fn community(slug: &str, name: &str, public: bool) -> Community {
+    community_created_at(slug, name, public, 0)
+}
+
+pub fn community_created_at(slug: &str, name: &str, public: bool, seconds: u64) -> Community {
+    Community::create(
+        CommunityId::generate(),
+        AccountId::generate(),
+        CommunitySlug::new(slug.to_string()).unwrap(),
+        CommunityName::new(name.to_string()).unwrap(),
+        public,
+        MembershipPolicy::default_for(public),
+        UNIX_EPOCH + Duration::from_secs(seconds),
+    )
+}
+
+pub fn public_query(search: Option<&str>) -> PublicCommunitiesQuery {
+    PublicCommunitiesQuery {
+        search: search.and_then(CommunitySearch::new),
+        sort: CommunitySort::Name,
+        limit: 100,
+        cursor: None,
+    }
+}
+
+macro_rules! community_repository_contract {
+    ($repository:expr, $with_unit_of_work:expr) => {
+        mod contract {
+            use super::*;
+            use crate::{
+                application::{
+                    errors::community_repository::CommunityRepositoryError,
+                    pagination::community_page::{
+                        CommunityPage, CommunitySort, PublicCommunitiesQuery,
+                    },
+                    ports::outbound::community_repository::CommunityRepositoryPort,
+                },
+                domain::{
+                    policies::membership_policy::MembershipPolicy,
+                    value_objects::{
+                        community_description::CommunityDescription, community_name::CommunityName,
+                        community_slug::CommunitySlug,
+                    },
+                },
+                infrastructure::persistence::community_repository_contract::{
+                    community, community_created_at, public_query,
+                },
+            };
+            use shared::application::ports::unit_of_work::UnitOfWorkPort;
+            use std::time::UNIX_EPOCH;
+
+            fn slugs(page: CommunityPage) -> Vec<String> {
+                page.communities
+                    .iter()
+                    .map(|community| community.slug().as_str().to_string())
+                    .collect()
+            }
+
+            #[tokio::test]
+            async fn finds_saved_community_by_id_and_slug() {
+                let repository = $repository;
+                let saved = community("rust-lang", "Rust Lang", true);
+
+                repository.save(&saved).await.unwrap();
+
+                let by_id = repository
+                    .find_by_id(&saved.id().as_uuid().to_string())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                let by_slug = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                assert_eq!(by_id.id(), saved.id());
+                assert_eq!(by_slug.owner_id(), saved.owner_id());
+                assert!(by_slug.is_public());
+            }
+
+            #[tokio::test]
+            async fn returns_none_for_unknown_communities() {
+                let repository = $repository;
+
+                assert!(repository.find_by_slug("ghost").await.unwrap().is_none());
+                assert!(repository.find_by_id("not-an-id").await.unwrap().is_none());
+            }
+
+            #[tokio::test]
+            async fn persists_membership_policy_changes() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", false))
+                    .await
+                    .unwrap();
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                saved.change_membership_policy(MembershipPolicy::ByApplication, UNIX_EPOCH);
+                repository.save(&saved).await.unwrap();
+
+                let found = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                assert_eq!(
+                    found.membership_policy(),
+                    &Some(MembershipPolicy::ByApplication)
+                );
+                assert!(!found.is_public());
+            }
+
+            #[tokio::test]
+            async fn persists_community_settings_changes() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                saved.rename(
+                    CommunityName::new("Ferris Fan Club".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                saved.change_visibility(false, UNIX_EPOCH);
+                saved.change_description(
+                    CommunityDescription::new("Crabs welcome".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                repository.save(&saved).await.unwrap();
+
+                let found = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                assert_eq!(found.name().as_str(), "Ferris Fan Club");
+                assert!(!found.is_public());
+                assert_eq!(
+                    found.description().as_ref().map(|d| d.as_str()),
+                    Some("Crabs welcome")
+                );
+
+                let mut saved = found;
+                saved.change_visibility(true, UNIX_EPOCH);
+                repository.save(&saved).await.unwrap();
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(Some("ferris")))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["rust-lang"]
+                );
+            }
+
+            #[tokio::test]
+            async fn resolves_previous_slugs_to_the_renamed_community() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                saved.change_slug(
+                    CommunitySlug::new("ferris".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                repository.save(&saved).await.unwrap();
+                let mut saved = repository.find_by_slug("ferris").await.unwrap().unwrap();
+                saved.change_slug(CommunitySlug::new("crabs".to_string()).unwrap(), UNIX_EPOCH);
+                repository.save(&saved).await.unwrap();
+
+                for slug in ["rust-lang", "ferris", "crabs"] {
+                    let found = repository.find_by_slug(slug).await.unwrap().unwrap();
+                    assert_eq!(found.id(), saved.id());
+                    assert_eq!(found.slug().as_str(), "crabs");
+                    assert_eq!(found.previous_slugs().len(), 2);
+                }
+            }
+
+            #[tokio::test]
+            async fn keeps_previous_slugs_reserved() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                saved.change_slug(
+                    CommunitySlug::new("ferris".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                repository.save(&saved).await.unwrap();
+
+                let result = repository
+                    .save(&community("rust-lang", "Other Rust", true))
+                    .await;
+
+                assert!(matches!(
+                    result,
+                    Err(CommunityRepositoryError::SlugAlreadyExists)
+                ));
+                assert_eq!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .id(),
+                    saved.id()
+                );
+            }
+
+            #[tokio::test]
+            async fn rejects_another_community_with_the_same_slug() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+
+                let result = repository
+                    .save(&community("rust-lang", "Other Rust", true))
+                    .await;
+
+                assert!(matches!(
+                    result,
+                    Err(CommunityRepositoryError::SlugAlreadyExists)
+                ));
+            }
+
+            #[tokio::test]
+            async fn rejects_stale_writes() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+                let mut first = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                let mut second = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                first.rename(
+                    CommunityName::new("Ferris Fan Club".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                repository.save(&first).await.unwrap();
+                second.change_slug(
+                    CommunitySlug::new("ferris".to_string()).unwrap(),
+                    UNIX_EPOCH,
+                );
+                let result = repository.save(&second).await;
+
+                assert!(matches!(result, Err(CommunityRepositoryError::Conflict)));
+                let found = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                assert_eq!(found.name().as_str(), "Ferris Fan Club");
+                assert!(repository.find_by_slug("ferris").await.unwrap().is_none());
+            }
+
+            #[tokio::test]
+            async fn rejects_saving_a_new_community_twice() {
+                let repository = $repository;
+                let saved = community("rust-lang", "Rust Lang", true);
+                repository.save(&saved).await.unwrap();
+
+                let result = repository.save(&saved).await;
+
+                assert!(matches!(result, Err(CommunityRepositoryError::Conflict)));
+            }
+
+            #[tokio::test]
+            async fn public_list_excludes_private_communities() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+                repository
+                    .save(&community("secret", "Secret Club", false))
+                    .await
+                    .unwrap();
+                repository
+                    .save(&community("go-lang", "Go Lang", true))
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(None))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["go-lang", "rust-lang"]
+                );
+            }
+
+            #[tokio::test]
+            async fn public_list_filters_by_name_or_slug() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rustaceans", true))
+                    .await
+                    .unwrap();
+                repository
+                    .save(&community("go-lang", "Gophers", true))
+                    .await
+                    .unwrap();
+                repository
+                    .save(&community("rusty-secret", "Rusty Club", false))
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(Some("RUST")))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["rust-lang"]
+                );
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(Some("lang")))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["go-lang", "rust-lang"]
+                );
+                assert!(
+                    repository
+                        .get_public_list(public_query(Some("%")))
+                        .await
+                        .unwrap()
+                        .communities
+                        .is_empty()
+                );
+            }
+
+            #[tokio::test]
+            async fn public_list_search_ignores_accents() {
+                let repository = $repository;
+                repository
+                    .save(&community("cafe-creme", "Café Crème", true))
+                    .await
+                    .unwrap();
+                repository
+                    .save(&community("tea-house", "Tea House", true))
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(Some("CREME")))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["cafe-creme"]
+                );
+                assert_eq!(
+                    slugs(
+                        repository
+                            .get_public_list(public_query(Some("café")))
+                            .await
+                            .unwrap()
+                    ),
+                    vec!["cafe-creme"]
+                );
+            }
+
+            #[tokio::test]
+            async fn pages_forwards_and_backwards_through_public_communities() {
+                let repository = $repository;
+                for (slug, name) in [
+                    ("alpha", "Alpha Club"),
+                    ("bravo", "Bravo Club"),
+                    ("charlie", "Charlie Club"),
+                    ("delta", "Delta Club"),
+                    ("echo", "Echo Club"),
+                ] {
+                    repository.save(&community(slug, name, true)).await.unwrap();
+                }
+                let query = |cursor| PublicCommunitiesQuery {
+                    limit: 2,
+                    cursor,
+                    ..public_query(None)
+                };
+
+                let first = repository.get_public_list(query(None)).await.unwrap();
+                let second = repository
+                    .get_public_list(query(first.next.clone()))
+                    .await
+                    .unwrap();
+                let last = repository
+                    .get_public_list(query(second.next.clone()))
+                    .await
+                    .unwrap();
+                let back = repository
+                    .get_public_list(query(last.previous.clone()))
+                    .await
+                    .unwrap();
+
+                assert_eq!(first.total_estimate, 5);
+                assert!(first.previous.is_none());
+                assert!(last.next.is_none());
+                assert_eq!(slugs(first), vec!["alpha", "bravo"]);
+                assert_eq!(slugs(last), vec!["echo"]);
+                assert!(back.next.is_some());
+                assert_eq!(slugs(second), vec!["charlie", "delta"]);
+                assert_eq!(slugs(back), vec!["charlie", "delta"]);
+            }
+
+            #[tokio::test]
+            async fn sorts_public_communities_by_creation_date_and_member_count() {
+                let repository = $repository;
+                let mut crowded = community_created_at("crowded", "Crowded Club", true, 10);
+                crowded.member_joined();
+                crowded.member_joined();
+                let mut busy = community_created_at("busy", "Busy Club", true, 30);
+                busy.member_joined();
+                for saved in [
+                    crowded,
+                    busy,
+                    community_created_at("quiet", "Quiet Club", true, 20),
+                ] {
+                    repository.save(&saved).await.unwrap();
+                }
+
+                let newest = repository
+                    .get_public_list(PublicCommunitiesQuery {
+                        sort: CommunitySort::CreatedAt,
+                        ..public_query(None)
+                    })
+                    .await
+                    .unwrap();
+                let largest = repository
+                    .get_public_list(PublicCommunitiesQuery {
+                        sort: CommunitySort::MemberCount,
+                        ..public_query(None)
+                    })
+                    .await
+                    .unwrap();
+
+                assert_eq!(slugs(newest), vec!["busy", "quiet", "crowded"]);
+                assert_eq!(slugs(largest), vec!["crowded", "busy", "quiet"]);
+            }
+
+            #[tokio::test]
+            async fn save_in_writes_only_when_the_unit_of_work_commits() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let saved = community("rust-lang", "Rust Lang", true);
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &saved).await.unwrap();
+                assert!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+                work.commit().await.unwrap();
+
+                assert!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .is_some()
+                );
+            }
+
+            #[tokio::test]
+            async fn stale_saves_keep_members_counted_in_between() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let saved = community("rust-lang", "Rust Lang", true);
+                repository.save(&saved).await.unwrap();
+                let stale = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                for _ in 0..2 {
+                    let mut work = unit_of_work.begin();
+                    repository
+                        .record_member_joined_in(work.as_mut(), saved.id())
+                        .await
+                        .unwrap();
+                    work.commit().await.unwrap();
+                }
+                repository.save(&stale).await.unwrap();
+
+                assert_eq!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .member_count(),
+                    saved.member_count() + 2
+                );
+            }
+
+            #[tokio::test]
+            async fn failing_step_undoes_earlier_steps() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                repository
+                    .save(&community("go-lang", "Go Lang", true))
+                    .await
+                    .unwrap();
+                let mut work = unit_of_work.begin();
+
+                repository
+                    .save_in(work.as_mut(), &community("rust-lang", "Rust Lang", true))
+                    .await
+                    .unwrap();
+                repository
+                    .save_in(work.as_mut(), &community("go-lang", "Other Go", true))
+                    .await
+                    .unwrap();
+
+                assert!(work.commit().await.is_err());
+                assert!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+                assert_eq!(
+                    repository
+                        .find_by_slug("go-lang")
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .name()
+                        .as_str(),
+                    "Go Lang"
+                );
+            }
+        }
+    };
+}
+
+pub(crate) use community_repository_contract;
@@ -0,0 +1,117 @@
+use crate::{
+    application::errors::community_repository::CommunityRepositoryError,
+    domain::{
+        aggregates::community::Community,
+        policies::membership_policy::MembershipPolicy,
+        value_objects::{
+            community_description::CommunityDescription, community_id::CommunityId,
+            community_name::CommunityName, community_slug::CommunitySlug,
+        },
+    },
+};
+use iam::domain::value_objects::AccountId;
+use serde::{Deserialize, Serialize};
+use shared::domain::timestamps::{from_unix_millis, to_unix_millis};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct CommunityRecord {
+    id: String,
+    owner_id: String,
+    slug: String,
+    #[serde(default)]
+    previous_slugs: Vec<String>,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    public: bool,
+    membership_policy: Option<String>,
+    #[serde(default)]
+    created_at_ms: i64,
+    #[serde(default = "CommunityRecord::owner_only")]
+    member_count: u64,
+    #[serde(default = "CommunityRecord::first_version")]
+    version: u64,
+}
+
+impl CommunityRecord {
+    fn owner_only() -> u64 {
+        1
+    }
+
+    fn first_version() -> u64 {
+        1
+    }
+
+    pub(super) fn with_member_count(self, member_count: u64) -> Self {
+        Self {
+            member_count,
+            ..self
+        }
+    }
+}
+
+impl From<&Community> for CommunityRecord {
+    fn from(community: &Community) -> Self {
+        Self {
+            id: community.id().as_uuid().to_string(),
+            owner_id: community.owner_id().as_uuid().to_string(),
+            slug: community.slug().as_str().to_string(),
+            previous_slugs: community
+                .previous_slugs()
+                .iter()
+                .map(|slug| slug.as_str().to_string())
+                .collect(),
+            name: community.name().as_str().to_string(),
+            description: community
+                .description()
+                .as_ref()
+                .map(|description| description.as_str().to_string()),
+            public: community.is_public(),
+            membership_policy: community
+                .membership_policy()
+                .map(|policy| policy.as_str().to_string()),
+            created_at_ms: to_unix_millis(community.created_at()),
+            member_count: community.member_count(),
+            version: community.version(),
+        }
+    }
+}
+
+impl TryFrom<CommunityRecord> for Community {
+    type Error = CommunityRepositoryError;
+
+    fn try_from(record: CommunityRecord) -> Result<Self, Self::Error> {
+        let corrupted = || {
+            CommunityRepositoryError::Storage(format!("Corrupted community record {}", record.id))
+        };
+
+        let description = match record.description.clone() {
+            Some(description) => CommunityDescription::new(description).map_err(|_| corrupted())?,
+            None => None,
+        };
+        let previous_slugs = record
+            .previous_slugs
+            .iter()
+            .map(|slug| CommunitySlug::new(slug.clone()).map_err(|_| corrupted()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let membership_policy = record
+            .membership_policy
+            .as_deref()
+            .map(|policy| MembershipPolicy::parse(policy).ok_or_else(corrupted))
+            .transpose()?;
+
+        Ok(Community::reconstitute(
+            CommunityId::from_str(&record.id).map_err(|_| corrupted())?,
+            AccountId::from_str(&record.owner_id).map_err(|_| corrupted())?,
+            CommunitySlug::new(record.slug.clone()).map_err(|_| corrupted())?,
+            previous_slugs,
+            CommunityName::new(record.name.clone()).map_err(|_| corrupted())?,
+            description,
+            record.public,
+            membership_policy,
+            from_unix_millis(record.created_at_ms),
+            record.member_count,
+            record.version,
+        ))
+    }
+}
@@ -1,53 +1,554 @@
+use super::community_record::CommunityRecord;
 use crate::{
     application::{
         errors::community_repository::CommunityRepositoryError,
+        pagination::community_page::{CommunityPage, PublicCommunitiesQuery},
         ports::outbound::community_repository::CommunityRepositoryPort,
     },
     domain::{aggregates::community::Community, value_objects::community_id::CommunityId},
 };
+use async_trait::async_trait;
+use shared::{
+    application::{
+        errors::unit_of_work::UnitOfWorkError,
+        outbox::OutboxMessage,
+        ports::{outbox_store::OutboxStorePort, unit_of_work::UnitOfWork},
+    },
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::json_snapshot::{
+            JsonSnapshotStore, LogEntry, Recovered, SnapshotError, SnapshotSource,
+        },
+        unit_of_work::{InMemoryUnitOfWork, Undo},
+    },
+};
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{Arc, RwLock},
 };
 
+#[derive(Default)]
+struct Communities {
+    by_id: HashMap<CommunityId, Community>,
+    by_slug: HashMap<String, CommunityId>,
+    log: Option<Arc<JsonSnapshotStore>>,
+}
+
+impl Communities {
+    fn insert(&mut self, community: Community) -> Option<Community> {
+        let previous = self.remove(community.id());
+
+        for slug in Self::slugs_of(&community) {
+            self.by_slug.insert(slug, community.id().clone());
+        }
+        self.by_id.insert(community.id().clone(), community);
+
+        previous
+    }
+
+    fn remove(&mut self, id: &CommunityId) -> Option<Community> {
+        let removed = self.by_id.remove(id)?;
+        for slug in Self::slugs_of(&removed) {
+            self.by_slug.remove(&slug);
+        }
+
+        Some(removed)
+    }
+
+    fn slugs_of(community: &Community) -> Vec<String> {
+        community
+            .previous_slugs()
+            .iter()
+            .chain([community.slug()])
+            .map(|slug| slug.as_str().to_string())
+            .collect()
+    }
+
+    fn restore(
+        &mut self,
+        id: &CommunityId,
+        written_version: u64,
+        previous: Option<Community>,
+    ) -> Result<(), CommunityRepositoryError> {
+        let Some(current) = self.by_id.get(id) else {
+            return Ok(());
+        };
+        if current.version() != written_version {
+            return Ok(());
+        }
+
+        match previous {
+            Some(previous) => {
+                let record =
+                    CommunityRecord::from(&previous).with_member_count(current.member_count());
+                let restored = Community::try_from(record)?;
+                self.log(LogEntry::Put(CommunityRecord::from(&restored)))
+                    .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+                self.insert(restored);
+            }
+            None => {
+                self.log(LogEntry::Remove(id.as_uuid().to_string()))
+                    .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+                self.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    fn store(
+        &mut self,
+        community: &Community,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let taken = Self::slugs_of(community).iter().any(|slug| {
+            self.by_slug
+                .get(slug)
+                .is_some_and(|id| id != community.id())
+        });
+        if taken {
+            return Err(CommunityRepositoryError::SlugAlreadyExists);
+        }
+
+        let existing = self.by_id.get(community.id());
+        if existing.map_or(0, Community::version) != community.version() {
+            return Err(CommunityRepositoryError::Conflict);
+        }
+
+        let mut record = CommunityRecord::from(community);
+        if let Some(existing) = existing {
+            record = record.with_member_count(existing.member_count());
+        }
+        let mut stored = Community::try_from(record)?;
+        stored.increment_version();
+        self.log(LogEntry::Put(CommunityRecord::from(&stored)))
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        Ok(self.insert(stored))
+    }
+
+    fn recount(
+        &mut self,
+        id: &CommunityId,
+        count: impl FnOnce(&mut Community),
+    ) -> Result<(), CommunityRepositoryError> {
+        let mut counted = self.by_id.get(id).cloned().ok_or_else(|| {
+            CommunityRepositoryError::Storage(format!("Community {} not found", id.as_uuid()))
+        })?;
+        count(&mut counted);
+        self.log(LogEntry::Put(CommunityRecord::from(&counted)))
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        self.insert(counted);
+        Ok(())
+    }
+
+    fn log(&self, entry: LogEntry<CommunityRecord>) -> Result<(), SnapshotError> {
+        match &self.log {
+            Some(log) => log.append(&entry),
+            None => Ok(()),
+        }
+    }
+}
+
 pub struct InMemoryCommunityRepository {
-    communities: Arc<Mutex<HashMap<CommunityId, Community>>>,
+    communities: Arc<RwLock<Communities>>,
+    outbox: Arc<dyn OutboxStorePort>,
 }
 
 impl InMemoryCommunityRepository {
-    pub fn new() -> Self {
+    pub fn new(outbox: Arc<dyn OutboxStorePort>) -> Self {
         Self {
-            communities: Arc::new(Mutex::new(HashMap::new())),
+            communities: Arc::new(RwLock::new(Communities::default())),
+            outbox,
+        }
+    }
+
+    pub fn with_snapshots(
+        outbox: Arc<dyn OutboxStorePort>,
+        snapshots: Arc<JsonSnapshotStore>,
+    ) -> Result<Self, SnapshotError> {
+        let recovered: Recovered<Vec<CommunityRecord>, LogEntry<CommunityRecord>> =
+            snapshots.load()?;
+        let corrupted = |e: CommunityRepositoryError| SnapshotError::Corrupted(e.to_string());
+
+        let mut communities = Communities::default();
+        for record in recovered.snapshot.into_iter().flatten() {
+            communities.insert(Community::try_from(record).map_err(corrupted)?);
+        }
+        for entry in recovered.log {
+            match entry {
+                LogEntry::Put(record) => {
+                    communities.insert(Community::try_from(record).map_err(corrupted)?);
+                }
+                LogEntry::Remove(id) => {
+                    let id = CommunityId::from_str(&id).map_err(|_| {
+                        SnapshotError::Corrupted(format!("Corrupted community record {}", id))
+                    })?;
+                    communities.remove(&id);
+                }
+            }
         }
+        communities.log = Some(snapshots);
+
+        Ok(Self {
+            communities: Arc::new(RwLock::new(communities)),
+            outbox,
+        })
     }
 }
 
-impl CommunityRepositoryPort for InMemoryCommunityRepository {
-    fn get_public_list(&self, _query: Option<String>) -> Vec<Community> {
-        todo!()
+impl InMemoryCommunityRepository {
+    fn outbox_messages(community: &Community) -> Vec<OutboxMessage> {
+        community
+            .pending_events()
+            .iter()
+            .map(|event| OutboxMessage::from_event(event.as_ref()))
+            .collect()
     }
+}
 
-    fn find_by_id(&self, id: &str) -> Option<Community> {
-        let communities = self.communities.lock().expect("mutex poisoned");
-        communities
+#[async_trait]
+impl CommunityRepositoryPort for InMemoryCommunityRepository {
+    async fn get_public_list(
+        &self,
+        query: PublicCommunitiesQuery,
+    ) -> Result<CommunityPage, CommunityRepositoryError> {
+        let communities = self.communities.read().expect("lock poisoned");
+
+        let mut public: Vec<&Community> = communities
+            .by_id
             .values()
-            .find(|community| community.id().as_uuid().to_string() == id)
-            .cloned()
+            .filter(|community| community.is_public())
+            .filter(|community| match &query.search {
+                Some(search) => {
+                    search.matches(community.name().as_str())
+                        || search.matches(community.slug().as_str())
+                }
+                None => true,
+            })
+            .collect();
+        public.sort_by(|a, b| query.scan_order(a, b));
+
+        let scanned = public
+            .iter()
+            .filter(|community| query.is_past_cursor(community))
+            .take(query.limit + 1)
+            .map(|community| (*community).clone())
+            .collect();
+
+        Ok(CommunityPage::assemble(
+            scanned,
+            &query,
+            public.len() as u64,
+        ))
     }
 
-    fn find_by_slug(&self, slug: &str) -> Option<Community> {
-        let communities = self.communities.lock().expect("mutex poisoned");
-        communities
-            .values()
-            .find(|community| community.slug().as_str() == slug)
-            .cloned()
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError> {
+        let Ok(id) = CommunityId::from_str(id) else {
+            return Ok(None);
+        };
+        let communities = self.communities.read().expect("lock poisoned");
+        Ok(communities.by_id.get(&id).cloned())
     }
 
-    fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
-        let mut communities = self.communities.lock().expect("mutex poisoned");
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let communities = self.communities.read().expect("lock poisoned");
+        Ok(communities
+            .by_slug
+            .get(slug)
+            .and_then(|id| communities.by_id.get(id))
+            .cloned())
+    }
 
-        communities.insert(community.id().clone(), community.clone());
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
+        let communities = self.communities.clone();
+        let outbox = self.outbox.clone();
+        let community = community.clone();
+
+        run_blocking(move || {
+            let mut communities = communities.write().expect("lock poisoned");
+            let previous = communities.store(&community)?;
+            if let Err(e) = outbox.append(Self::outbox_messages(&community)) {
+                communities.restore(community.id(), community.version() + 1, previous)?;
+                return Err(CommunityRepositoryError::Storage(e.0));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = InMemoryUnitOfWork::of(unit_of_work)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let communities = self.communities.clone();
+        let outbox = self.outbox.clone();
+        let messages = Self::outbox_messages(community);
+        let community = community.clone();
+
+        unit_of_work.register(move || {
+            let previous = communities
+                .write()
+                .expect("lock poisoned")
+                .store(&community)
+                .map_err(|e| match e {
+                    CommunityRepositoryError::Conflict
+                    | CommunityRepositoryError::SlugAlreadyExists => {
+                        UnitOfWorkError::Conflict(e.to_string())
+                    }
+                    _ => UnitOfWorkError::Failed(e.to_string()),
+                })?;
+            let id = community.id().clone();
+            let written_version = community.version() + 1;
+            Ok(Box::new(move || {
+                communities
+                    .write()
+                    .expect("lock poisoned")
+                    .restore(&id, written_version, previous)
+                    .map_err(|e| UnitOfWorkError::Failed(e.to_string()))
+            }) as Undo)
+        });
+        unit_of_work.append_to_outbox(outbox, messages);
 
         Ok(())
     }
+
+    async fn record_member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        id: &CommunityId,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = InMemoryUnitOfWork::of(unit_of_work)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let communities = self.communities.clone();
+        let id = id.clone();
+
+        unit_of_work.register(move || {
+            communities
+                .write()
+                .expect("lock poisoned")
+                .recount(&id, Community::member_joined)
+                .map_err(|e| UnitOfWorkError::Failed(e.to_string()))?;
+            Ok(Box::new(move || {
+                communities
+                    .write()
+                    .expect("lock poisoned")
+                    .recount(&id, Community::member_left)
+                    .map_err(|e| UnitOfWorkError::Failed(e.to_string()))
+            }) as Undo)
+        });
+
+        Ok(())
+    }
+}
+
+impl SnapshotSource for InMemoryCommunityRepository {
+    fn write_snapshot(&self) -> Result<(), SnapshotError> {
+        let communities = self.communities.read().expect("lock poisoned");
+        let Some(log) = &communities.log else {
+            return Ok(());
+        };
+
+        log.write_snapshot(|| {
+            communities
+                .by_id
+                .values()
+                .map(CommunityRecord::from)
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            events::community_created::CommunityCreated,
+            policies::membership_policy::MembershipPolicy,
+        },
+        infrastructure::persistence::community_repository_contract::community_repository_contract,
+    };
+    use shared::{
+        application::{
+            errors::outbox_store::OutboxStoreError, ports::unit_of_work::UnitOfWorkPort,
+        },
+        infrastructure::{outbox::InMemoryOutboxStore, unit_of_work::InMemoryUnitOfWorkFactory},
+    };
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use uuid::Uuid;
+
+    struct FailingOutbox;
+
+    impl OutboxStorePort for FailingOutbox {
+        fn append(&self, _messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError> {
+            Err(OutboxStoreError("outbox is full".to_string()))
+        }
+
+        fn due(
+            &self,
+            _now: SystemTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboxMessage>, OutboxStoreError> {
+            Ok(Vec::new())
+        }
+
+        fn mark_delivered(&self, _id: &Uuid, _at: SystemTime) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_retry(
+            &self,
+            _id: &Uuid,
+            _error: String,
+            _next_attempt_at: SystemTime,
+        ) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_failed(&self, _id: &Uuid, _error: String) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+    }
+
+    fn repository() -> InMemoryCommunityRepository {
+        InMemoryCommunityRepository::new(Arc::new(InMemoryOutboxStore::new()))
+    }
+
+    community_repository_contract!(
+        repository(),
+        (repository(), InMemoryUnitOfWorkFactory::new())
+    );
+
+    #[tokio::test]
+    async fn save_writes_pending_events_to_the_outbox() {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        let repository = InMemoryCommunityRepository::new(outbox.clone());
+        let community = Community::dummy_community();
+
+        repository.save(&community).await.unwrap();
+        let reloaded = repository
+            .find_by_slug("rust-community")
+            .await
+            .unwrap()
+            .unwrap();
+        repository.save(&reloaded).await.unwrap();
+
+        let messages = outbox.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].event_type, CommunityCreated::EVENT_TYPE);
+    }
+
+    #[tokio::test]
+    async fn save_keeps_nothing_when_the_outbox_rejects_the_events() {
+        let repository = InMemoryCommunityRepository::new(Arc::new(FailingOutbox));
+
+        let result = repository.save(&Community::dummy_community()).await;
+
+        assert!(matches!(result, Err(CommunityRepositoryError::Storage(_))));
+        assert!(
+            repository
+                .find_by_slug("rust-community")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_saves_with_the_same_slug_admit_only_one() {
+        let repository = Arc::new(repository());
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    let community = Community::dummy_community();
+                    repository.save(&community).await
+                })
+            })
+            .collect();
+        let mut saved = 0;
+        for handle in handles {
+            if handle.await.unwrap().is_ok() {
+                saved += 1;
+            }
+        }
+
+        assert_eq!(saved, 1);
+    }
+
+    #[tokio::test]
+    async fn undone_joins_keep_members_counted_in_between() {
+        let repository = repository();
+        let community = Community::dummy_community();
+        repository.save(&community).await.unwrap();
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+        repository
+            .record_member_joined_in(work.as_mut(), community.id())
+            .await
+            .unwrap();
+        let communities = repository.communities.clone();
+        let id = community.id().clone();
+        InMemoryUnitOfWork::of(work.as_mut())
+            .unwrap()
+            .register(move || {
+                communities
+                    .write()
+                    .unwrap()
+                    .recount(&id, Community::member_joined)
+                    .unwrap();
+                Err(UnitOfWorkError::Conflict("stale".to_string()))
+            });
+
+        assert!(work.commit().await.is_err());
+
+        let found = repository
+            .find_by_slug("rust-community")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.member_count(), community.member_count() + 1);
+    }
+
+    fn snapshotting_repository(dir: &tempfile::TempDir) -> InMemoryCommunityRepository {
+        InMemoryCommunityRepository::with_snapshots(
+            Arc::new(InMemoryOutboxStore::new()),
+            Arc::new(JsonSnapshotStore::open(dir.path(), "communities").unwrap()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn restores_communities_from_the_snapshot_and_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = snapshotting_repository(&dir);
+        let community = Community::dummy_community();
+        repository.save(&community).await.unwrap();
+        repository.write_snapshot().unwrap();
+        let mut community = repository
+            .find_by_slug("rust-community")
+            .await
+            .unwrap()
+            .unwrap();
+        community.change_membership_policy(MembershipPolicy::ByApplication, UNIX_EPOCH);
+        repository.save(&community).await.unwrap();
+        drop(repository);
+
+        let restored = snapshotting_repository(&dir);
+
+        let found = restored
+            .find_by_slug("rust-community")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id(), community.id());
+        assert_eq!(
+            found.membership_policy(),
+            &Some(MembershipPolicy::ByApplication)
+        );
+    }
 }
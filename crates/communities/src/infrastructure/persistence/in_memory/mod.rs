@@ -1 +1,2 @@
+mod community_record;
 pub mod community_repository;
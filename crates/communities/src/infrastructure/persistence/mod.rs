@@ -1 +1,5 @@
+pub mod caching;
+#[cfg(test)]
+pub mod community_repository_contract;
 pub mod in_memory;
+pub mod sqlite;
@@ -0,0 +1,474 @@
+use crate::{
+    application::{
+        errors::community_repository::CommunityRepositoryError,
+        pagination::community_page::{
+            CommunityPage, CommunitySort, PublicCommunitiesQuery, SortKey,
+        },
+        ports::outbound::community_repository::CommunityRepositoryPort,
+    },
+    domain::{
+        aggregates::community::Community,
+        policies::membership_policy::MembershipPolicy,
+        value_objects::{
+            community_description::CommunityDescription, community_id::CommunityId,
+            community_name::CommunityName, community_search::CommunitySearch,
+            community_slug::CommunitySlug,
+        },
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Row, params, types::Value};
+use shared::{
+    application::{errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWork},
+    domain::timestamps::{from_unix_millis, to_unix_millis},
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::sqlite::{SqliteDatabase, SqliteOutboxStore, SqliteUnitOfWork},
+    },
+};
+use std::sync::Arc;
+
+const COLUMNS: &str = "communities.*,
+    (SELECT group_concat(slug, ',') FROM community_slugs
+     WHERE community_id = communities.id AND slug <> communities.slug) AS previous_slugs";
+
+struct CommunityRow {
+    id: String,
+    owner_id: String,
+    slug: String,
+    previous_slugs: Option<String>,
+    name: String,
+    description: Option<String>,
+    public: bool,
+    membership_policy: Option<String>,
+    created_at_ms: i64,
+    member_count: i64,
+    version: i64,
+}
+
+impl CommunityRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get("id")?,
+            owner_id: row.get("owner_id")?,
+            slug: row.get("slug")?,
+            previous_slugs: row.get("previous_slugs")?,
+            name: row.get("name")?,
+            description: row.get("description")?,
+            public: row.get("public")?,
+            membership_policy: row.get("membership_policy")?,
+            created_at_ms: row.get("created_at_ms")?,
+            member_count: row.get("member_count")?,
+            version: row.get("version")?,
+        })
+    }
+
+    fn into_community(self) -> Result<Community, CommunityRepositoryError> {
+        let corrupted =
+            || CommunityRepositoryError::Storage(format!("Corrupted community record {}", self.id));
+
+        let description = match self.description.clone() {
+            Some(description) => CommunityDescription::new(description).map_err(|_| corrupted())?,
+            None => None,
+        };
+        let previous_slugs = self
+            .previous_slugs
+            .as_deref()
+            .map(|slugs| slugs.split(',').collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|slug| CommunitySlug::new(slug.to_string()).map_err(|_| corrupted()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let membership_policy = self
+            .membership_policy
+            .as_deref()
+            .map(|policy| MembershipPolicy::parse(policy).ok_or_else(corrupted))
+            .transpose()?;
+
+        Ok(Community::reconstitute(
+            CommunityId::from_str(&self.id).map_err(|_| corrupted())?,
+            AccountId::from_str(&self.owner_id).map_err(|_| corrupted())?,
+            CommunitySlug::new(self.slug.clone()).map_err(|_| corrupted())?,
+            previous_slugs,
+            CommunityName::new(self.name.clone()).map_err(|_| corrupted())?,
+            description,
+            self.public,
+            membership_policy,
+            from_unix_millis(self.created_at_ms),
+            u64::try_from(self.member_count).map_err(|_| corrupted())?,
+            u64::try_from(self.version).map_err(|_| corrupted())?,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct SqliteCommunityRepository {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteCommunityRepository {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+
+    pub(super) fn search_text(name: &str, slug: &str) -> String {
+        format!(
+            "{}\n{}",
+            CommunitySearch::fold(name),
+            CommunitySearch::fold(slug)
+        )
+    }
+
+    fn find_one(
+        &self,
+        condition: &str,
+        value: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let row = self
+            .database
+            .connection()
+            .query_row(
+                &format!("SELECT {COLUMNS} FROM communities WHERE {condition}"),
+                params![value],
+                CommunityRow::read,
+            )
+            .optional()
+            .map_err(Self::storage_error)?;
+
+        row.map(CommunityRow::into_community).transpose()
+    }
+
+    fn storage_error(e: rusqlite::Error) -> CommunityRepositoryError {
+        if SqliteDatabase::is_unavailable(&e) {
+            CommunityRepositoryError::Unavailable(e.to_string())
+        } else {
+            CommunityRepositoryError::Storage(e.to_string())
+        }
+    }
+
+    fn write(connection: &Connection, community: &Community) -> rusqlite::Result<bool> {
+        let params = params![
+            community.id().as_uuid().to_string(),
+            community.owner_id().as_uuid().to_string(),
+            community.slug().as_str(),
+            community.name().as_str(),
+            community
+                .description()
+                .as_ref()
+                .map(|description| description.as_str()),
+            community.is_public(),
+            community.membership_policy().map(|policy| policy.as_str()),
+            to_unix_millis(community.created_at()),
+            community.member_count() as i64,
+            Self::search_text(community.name().as_str(), community.slug().as_str()),
+            community.version() as i64,
+        ];
+        let written = if community.version() == 0 {
+            connection.execute(
+                "INSERT INTO communities
+                    (id, owner_id, slug, name, description, public, membership_policy,
+                     created_at_ms, member_count, search_text, version)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11 + 1)
+                 ON CONFLICT (id) DO NOTHING",
+                params,
+            )?
+        } else {
+            connection.execute(
+                "UPDATE communities SET
+                    owner_id = ?2,
+                    slug = ?3,
+                    name = ?4,
+                    description = ?5,
+                    public = ?6,
+                    membership_policy = ?7,
+                    created_at_ms = ?8,
+                    search_text = ?10,
+                    version = ?11 + 1
+                 WHERE id = ?1 AND version = ?11",
+                params,
+            )?
+        };
+        if written == 1 {
+            Self::write_slugs(connection, community)?;
+            SqliteOutboxStore::record_events(connection, community.pending_events())?;
+        }
+        Ok(written == 1)
+    }
+
+    fn write_slugs(connection: &Connection, community: &Community) -> rusqlite::Result<()> {
+        let id = community.id().as_uuid().to_string();
+        connection.execute(
+            "DELETE FROM community_slugs WHERE community_id = ?1",
+            params![id],
+        )?;
+        for slug in community.previous_slugs().iter().chain([community.slug()]) {
+            connection.execute(
+                "INSERT INTO community_slugs (slug, community_id) VALUES (?1, ?2)",
+                params![slug.as_str(), id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_error(e: rusqlite::Error) -> CommunityRepositoryError {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::ConstraintViolation)
+                if e.to_string().contains("communities.slug")
+                    || e.to_string().contains("community_slugs.slug") =>
+            {
+                CommunityRepositoryError::SlugAlreadyExists
+            }
+            _ => Self::storage_error(e),
+        }
+    }
+
+    fn public_page(
+        &self,
+        query: &PublicCommunitiesQuery,
+    ) -> Result<CommunityPage, CommunityRepositoryError> {
+        let column = match query.sort {
+            CommunitySort::Name => "name",
+            CommunitySort::CreatedAt => "created_at_ms",
+            CommunitySort::MemberCount => "member_count",
+        };
+        let (order, comparison) = if query.sort.is_descending() == query.scans_backwards() {
+            ("ASC", ">")
+        } else {
+            ("DESC", "<")
+        };
+        let pattern = query
+            .search
+            .as_ref()
+            .map(|search| Self::like_pattern(search.as_str()));
+        let (key, id) = match &query.cursor {
+            Some(cursor) => (Some(Self::key_value(&cursor.key)), Some(cursor.id.clone())),
+            None => (None, None),
+        };
+
+        let connection = self.database.connection();
+        let rows = connection
+            .prepare(&format!(
+                "SELECT {COLUMNS} FROM communities
+                 WHERE public = 1
+                   AND (?1 IS NULL OR search_text LIKE ?1 ESCAPE '\\')
+                   AND (?2 IS NULL OR ({column}, id) {comparison} (?2, ?3))
+                 ORDER BY {column} {order}, id {order}
+                 LIMIT ?4"
+            ))
+            .and_then(|mut statement| {
+                statement
+                    .query_map(
+                        params![pattern, key, id, (query.limit + 1) as i64],
+                        CommunityRow::read,
+                    )?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            })
+            .map_err(Self::storage_error)?;
+        let total_estimate: i64 = connection
+            .query_row(
+                "SELECT COUNT(*) FROM communities
+                 WHERE public = 1 AND (?1 IS NULL OR search_text LIKE ?1 ESCAPE '\\')",
+                params![pattern],
+                |row| row.get(0),
+            )
+            .map_err(Self::storage_error)?;
+
+        let scanned = rows
+            .into_iter()
+            .map(CommunityRow::into_community)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(CommunityPage::assemble(
+            scanned,
+            query,
+            total_estimate as u64,
+        ))
+    }
+
+    fn key_value(key: &SortKey) -> Value {
+        match key {
+            SortKey::Name(name) => Value::Text(name.clone()),
+            SortKey::CreatedAt(millis) => Value::Integer(*millis),
+            SortKey::MemberCount(count) => Value::Integer(*count as i64),
+        }
+    }
+
+    fn like_pattern(query: &str) -> String {
+        let escaped = query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("%{}%", escaped)
+    }
+}
+
+#[async_trait]
+impl CommunityRepositoryPort for SqliteCommunityRepository {
+    async fn get_public_list(
+        &self,
+        query: PublicCommunitiesQuery,
+    ) -> Result<CommunityPage, CommunityRepositoryError> {
+        let repository = self.clone();
+
+        run_blocking(move || repository.public_page(&query)).await
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError> {
+        let repository = self.clone();
+        let id = id.to_owned();
+        run_blocking(move || repository.find_one("id = ?1", &id)).await
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let repository = self.clone();
+        let slug = slug.to_owned();
+        run_blocking(move || {
+            repository.find_one(
+                "id = (SELECT community_id FROM community_slugs WHERE slug = ?1)",
+                &slug,
+            )
+        })
+        .await
+    }
+
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
+        let database = self.database.clone();
+        let community = community.clone();
+
+        let written = run_blocking(move || database.transaction(|tx| Self::write(tx, &community)))
+            .await
+            .map_err(Self::write_error)?;
+        if !written {
+            return Err(CommunityRepositoryError::Conflict);
+        }
+        Ok(())
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = SqliteUnitOfWork::of(unit_of_work, &self.database)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let community = community.clone();
+
+        unit_of_work.register(move |tx| {
+            let written = Self::write(tx, &community).map_err(|e| match Self::write_error(e) {
+                CommunityRepositoryError::SlugAlreadyExists => UnitOfWorkError::Conflict(
+                    CommunityRepositoryError::SlugAlreadyExists.to_string(),
+                ),
+                other => UnitOfWorkError::Failed(other.to_string()),
+            })?;
+            if !written {
+                return Err(UnitOfWorkError::Conflict(
+                    CommunityRepositoryError::Conflict.to_string(),
+                ));
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    async fn record_member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        id: &CommunityId,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = SqliteUnitOfWork::of(unit_of_work, &self.database)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let id = id.as_uuid().to_string();
+
+        unit_of_work.register(move |tx| {
+            let updated = tx
+                .execute(
+                    "UPDATE communities SET member_count = member_count + 1 WHERE id = ?1",
+                    params![id],
+                )
+                .map_err(|e| UnitOfWorkError::Failed(Self::storage_error(e).to_string()))?;
+            if updated == 0 {
+                return Err(UnitOfWorkError::Failed(format!("Community {id} not found")));
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::{
+        community_repository_contract::{community_repository_contract, public_query},
+        sqlite::migrations::{MIGRATIONS, index_search_text},
+    };
+    use shared::infrastructure::persistence::sqlite::SqliteUnitOfWorkFactory;
+
+    fn database() -> Arc<SqliteDatabase> {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&SqliteOutboxStore::MIGRATIONS).unwrap();
+        database.migrate(&MIGRATIONS).unwrap();
+        Arc::new(database)
+    }
+
+    fn repository() -> SqliteCommunityRepository {
+        SqliteCommunityRepository::new(database())
+    }
+
+    fn repository_with_unit_of_work() -> (SqliteCommunityRepository, SqliteUnitOfWorkFactory) {
+        let database = database();
+        (
+            SqliteCommunityRepository::new(database.clone()),
+            SqliteUnitOfWorkFactory::new(database),
+        )
+    }
+
+    community_repository_contract!(repository(), repository_with_unit_of_work());
+
+    #[test]
+    fn reports_other_constraint_violations_as_storage_errors() {
+        let database = database();
+        let violation = database
+            .connection()
+            .execute(
+                "INSERT INTO communities (id, slug, name, public) VALUES ('id', 'slug', 'name', 1)",
+                [],
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            SqliteCommunityRepository::write_error(violation),
+            CommunityRepositoryError::Storage(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn indexes_communities_stored_before_search_text_existed() {
+        let database = database();
+        database
+            .connection()
+            .execute(
+                "INSERT INTO communities (id, owner_id, slug, name, public)
+                 VALUES (?1, ?2, 'cafe-creme', 'Café Crème', 1)",
+                params![
+                    CommunityId::generate().as_uuid().to_string(),
+                    AccountId::generate().as_uuid().to_string()
+                ],
+            )
+            .unwrap();
+
+        index_search_text(&database).unwrap();
+        let repository = SqliteCommunityRepository::new(database);
+        let found = repository
+            .get_public_list(public_query(Some("creme")))
+            .await
+            .unwrap();
+
+        assert_eq!(found.communities.len(), 1);
+    }
+}
@@ -0,0 +1,60 @@
+use super::community_repository::SqliteCommunityRepository;
+use rusqlite::params;
+use shared::infrastructure::persistence::sqlite::{
+    Migration, MigrationError, MigrationSet, SqliteDatabase,
+};
+
+pub const MIGRATIONS: MigrationSet = MigrationSet {
+    context: "communities",
+    migrations: &[
+        Migration {
+            version: 1,
+            name: "create_communities",
+            sql: include_str!("../../../../migrations/0001_create_communities.sql"),
+        },
+        Migration {
+            version: 2,
+            name: "add_community_listing_columns",
+            sql: include_str!("../../../../migrations/0002_add_community_listing_columns.sql"),
+        },
+        Migration {
+            version: 3,
+            name: "add_community_description",
+            sql: include_str!("../../../../migrations/0003_add_community_description.sql"),
+        },
+        Migration {
+            version: 4,
+            name: "create_community_slugs",
+            sql: include_str!("../../../../migrations/0004_create_community_slugs.sql"),
+        },
+        Migration {
+            version: 5,
+            name: "add_community_version",
+            sql: include_str!("../../../../migrations/0005_add_community_version.sql"),
+        },
+    ],
+};
+
+pub fn index_search_text(database: &SqliteDatabase) -> Result<(), MigrationError> {
+    database.transaction(|tx| {
+        let unindexed = tx
+            .prepare("SELECT id, name, slug FROM communities WHERE search_text IS NULL")?
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        for (id, name, slug) in unindexed {
+            tx.execute(
+                "UPDATE communities SET search_text = ?1 WHERE id = ?2",
+                params![SqliteCommunityRepository::search_text(&name, &slug), id],
+            )?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS accounts (
+    id TEXT PRIMARY KEY,
+    username TEXT NOT NULL,
+    username_key TEXT NOT NULL,
+    email TEXT NOT NULL,
+    password_hash TEXT NOT NULL,
+    status TEXT NOT NULL,
+    verification_code INTEGER,
+    roles TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    verified_at INTEGER,
+    last_login_at INTEGER,
+    status_changed_at INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS accounts_username_key ON accounts (username_key);
+CREATE UNIQUE INDEX IF NOT EXISTS accounts_email ON accounts (email);
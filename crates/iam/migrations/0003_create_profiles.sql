@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS profiles (
+    account_id TEXT PRIMARY KEY,
+    display_name TEXT,
+    bio TEXT,
+    avatar TEXT,
+    locale TEXT NOT NULL,
+    timezone TEXT NOT NULL,
+    visibility TEXT NOT NULL
+);
@@ -0,0 +1,3 @@
+pub struct ListAuditEvents {
+    pub limit: Option<usize>,
+}
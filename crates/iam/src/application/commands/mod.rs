@@ -1,4 +1,7 @@
 pub mod authenticate_account;
 pub mod identify_account;
+pub mod list_audit_events;
 pub mod register_account;
+pub mod update_profile;
 pub mod verify_account;
+pub mod view_public_profile;
@@ -0,0 +1,3 @@
+pub struct ViewPublicProfile {
+    pub username: String,
+}
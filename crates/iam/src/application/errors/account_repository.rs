@@ -1,13 +1,35 @@
-use super::error_codes::IAM_ACCOUNT_REPOSITORY_ERROR;
+use super::error_codes::{
+    IAM_ACCOUNT_CONCURRENT_MODIFICATION, IAM_ACCOUNT_REPOSITORY_ERROR,
+    IAM_ACCOUNT_REPOSITORY_UNAVAILABLE,
+};
+use crate::domain::errors::error_codes::{
+    IAM_ACCOUNT_EMAIL_ALREADY_EXISTS, IAM_ACCOUNT_USERNAME_ALREADY_EXISTS,
+};
 use shared::error::{ErrorCategory, LayerError};
 use std::fmt;
 
 #[derive(Debug)]
-pub struct AccountRepositoryError(pub String);
+pub enum AccountRepositoryError {
+    Storage(String),
+    Unavailable(String),
+    Conflict,
+    UsernameAlreadyExists,
+    EmailAlreadyExists,
+}
 
 impl fmt::Display for AccountRepositoryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            AccountRepositoryError::Storage(message)
+            | AccountRepositoryError::Unavailable(message) => write!(f, "{}", message),
+            AccountRepositoryError::Conflict => {
+                write!(f, "Account was modified by another request")
+            }
+            AccountRepositoryError::UsernameAlreadyExists => {
+                write!(f, "Username is already taken")
+            }
+            AccountRepositoryError::EmailAlreadyExists => write!(f, "Email is already taken"),
+        }
     }
 }
 
@@ -19,10 +41,28 @@ impl LayerError for AccountRepositoryError {
     }
 
     fn code(&self) -> &'static str {
-        IAM_ACCOUNT_REPOSITORY_ERROR
+        match self {
+            AccountRepositoryError::Storage(_) => IAM_ACCOUNT_REPOSITORY_ERROR,
+            AccountRepositoryError::Unavailable(_) => IAM_ACCOUNT_REPOSITORY_UNAVAILABLE,
+            AccountRepositoryError::Conflict => IAM_ACCOUNT_CONCURRENT_MODIFICATION,
+            AccountRepositoryError::UsernameAlreadyExists => IAM_ACCOUNT_USERNAME_ALREADY_EXISTS,
+            AccountRepositoryError::EmailAlreadyExists => IAM_ACCOUNT_EMAIL_ALREADY_EXISTS,
+        }
     }
 
     fn message(&self) -> &'static str {
-        "We couldn't complete your request right now. Please try again."
+        match self {
+            AccountRepositoryError::Storage(_) => {
+                "We couldn't complete your request right now. Please try again."
+            }
+            AccountRepositoryError::Unavailable(_) => {
+                "Accounts are temporarily unavailable. Please try again shortly."
+            }
+            AccountRepositoryError::Conflict => {
+                "Your account was changed by another request. Please try again."
+            }
+            AccountRepositoryError::UsernameAlreadyExists => "This username is already in use.",
+            AccountRepositoryError::EmailAlreadyExists => "This email is already in use.",
+        }
     }
 }
@@ -0,0 +1,28 @@
+use super::error_codes::IAM_AUDIT_LOG_ERROR;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct AuditLogError(pub String);
+
+impl fmt::Display for AuditLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for AuditLogError {}
+
+impl LayerError for AuditLogError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        IAM_AUDIT_LOG_ERROR
+    }
+
+    fn message(&self) -> &'static str {
+        "We couldn't complete your request right now. Please try again."
+    }
+}
@@ -2,4 +2,11 @@ pub const IAM_LOGIN_FAILED: &str = "IAM_LOGIN_FAILED";
 pub const IAM_CANNOT_AUTHENTICATE: &str = "IAM_CANNOT_AUTHENTICATE";
 pub const IAM_PASSWORD_TOO_SHORT: &str = "IAM_PASSWORD_TOO_SHORT";
 pub const IAM_ACCOUNT_REPOSITORY_ERROR: &str = "IAM_ACCOUNT_REPOSITORY_ERROR";
+pub const IAM_ACCOUNT_REPOSITORY_UNAVAILABLE: &str = "IAM_ACCOUNT_REPOSITORY_UNAVAILABLE";
+pub const IAM_ACCOUNT_CONCURRENT_MODIFICATION: &str = "IAM_ACCOUNT_CONCURRENT_MODIFICATION";
 pub const IAM_TOKEN_GENERATOR_ERROR: &str = "IAM_TOKEN_GENERATOR_ERROR";
+pub const IAM_USERNAME_INVALID_CHARACTERS: &str = "IAM_USERNAME_INVALID_CHARACTERS";
+pub const IAM_USERNAME_MIXED_SCRIPT: &str = "IAM_USERNAME_MIXED_SCRIPT";
+pub const IAM_USERNAME_RESERVED: &str = "IAM_USERNAME_RESERVED";
+pub const IAM_PROFILE_REPOSITORY_ERROR: &str = "IAM_PROFILE_REPOSITORY_ERROR";
+pub const IAM_AUDIT_LOG_ERROR: &str = "IAM_AUDIT_LOG_ERROR";
@@ -1,5 +1,8 @@
 pub mod account_repository;
+pub mod audit_log;
 pub mod authenticate_account;
 pub mod error_codes;
 pub mod password_policy;
+pub mod profile_repository;
 pub mod token_generator;
+pub mod username_policy;
@@ -0,0 +1,28 @@
+use super::error_codes::IAM_PROFILE_REPOSITORY_ERROR;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ProfileRepositoryError(pub String);
+
+impl fmt::Display for ProfileRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ProfileRepositoryError {}
+
+impl LayerError for ProfileRepositoryError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        IAM_PROFILE_REPOSITORY_ERROR
+    }
+
+    fn message(&self) -> &'static str {
+        "We couldn't complete your request right now. Please try again."
+    }
+}
@@ -0,0 +1,54 @@
+use super::error_codes::{
+    IAM_USERNAME_INVALID_CHARACTERS, IAM_USERNAME_MIXED_SCRIPT, IAM_USERNAME_RESERVED,
+};
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum UsernamePolicyError {
+    InvalidCharacters,
+    MixedScript,
+    Reserved,
+}
+
+impl fmt::Display for UsernamePolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UsernamePolicyError::InvalidCharacters => {
+                write!(f, "Username contains characters that are not allowed")
+            }
+            UsernamePolicyError::MixedScript => {
+                write!(f, "Username mixes characters from different alphabets")
+            }
+            UsernamePolicyError::Reserved => write!(f, "This username is reserved"),
+        }
+    }
+}
+
+impl std::error::Error for UsernamePolicyError {}
+
+impl LayerError for UsernamePolicyError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            UsernamePolicyError::InvalidCharacters => IAM_USERNAME_INVALID_CHARACTERS,
+            UsernamePolicyError::MixedScript => IAM_USERNAME_MIXED_SCRIPT,
+            UsernamePolicyError::Reserved => IAM_USERNAME_RESERVED,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            UsernamePolicyError::InvalidCharacters => {
+                "Username contains characters that are not allowed."
+            }
+            UsernamePolicyError::MixedScript => {
+                "Username mixes characters from different alphabets."
+            }
+            UsernamePolicyError::Reserved => "This username is reserved.",
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod password_policy;
+pub mod username_policy;
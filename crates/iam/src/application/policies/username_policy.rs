@@ -0,0 +1,198 @@
+use crate::{
+    application::errors::username_policy::UsernamePolicyError, domain::value_objects::Username,
+};
+use shared::error::SystemError;
+use std::collections::HashSet;
+use unicode_security::MixedScript;
+
+pub const DEFAULT_RESERVED_USERNAMES: &[&str] = &[
+    "abuse",
+    "admin",
+    "administrator",
+    "api",
+    "auth",
+    "communities",
+    "help",
+    "info",
+    "kumenity",
+    "me",
+    "mod",
+    "moderator",
+    "noreply",
+    "null",
+    "official",
+    "root",
+    "security",
+    "staff",
+    "support",
+    "system",
+    "undefined",
+    "users",
+    "www",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsernameCharset {
+    Ascii,
+    Unicode,
+}
+
+impl UsernameCharset {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ascii" => Some(UsernameCharset::Ascii),
+            "unicode" => Some(UsernameCharset::Unicode),
+            _ => None,
+        }
+    }
+
+    fn allows(&self, c: char) -> bool {
+        if matches!(c, '_' | '-' | '.') {
+            return true;
+        }
+
+        match self {
+            UsernameCharset::Ascii => c.is_ascii_alphanumeric(),
+            UsernameCharset::Unicode => c.is_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UsernamePolicy {
+    charset: UsernameCharset,
+    reserved: HashSet<String>,
+}
+
+impl UsernamePolicy {
+    pub fn new<I, S>(charset: UsernameCharset, reserved: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            charset,
+            reserved: reserved
+                .into_iter()
+                .map(|name| Username::lookup_key_of(name.as_ref()))
+                .collect(),
+        }
+    }
+
+    pub fn validate(&self, username: &Username) -> Result<(), SystemError> {
+        let value = username.as_str();
+
+        if !value.chars().all(|c| self.charset.allows(c)) {
+            return Err(UsernamePolicyError::InvalidCharacters.into());
+        }
+
+        if !value.is_single_script() {
+            return Err(UsernamePolicyError::MixedScript.into());
+        }
+
+        if self.reserved.contains(&username.lookup_key()) {
+            return Err(UsernamePolicyError::Reserved.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for UsernamePolicy {
+    fn default() -> Self {
+        Self::new(UsernameCharset::Ascii, DEFAULT_RESERVED_USERNAMES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::errors::error_codes::{
+        IAM_USERNAME_INVALID_CHARACTERS, IAM_USERNAME_MIXED_SCRIPT, IAM_USERNAME_RESERVED,
+    };
+
+    fn username(value: &str) -> Username {
+        Username::new(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn accepts_ascii_username() {
+        let policy = UsernamePolicy::default();
+
+        let result = policy.validate(&username("john.doe-42_"));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn rejects_emoji() {
+        let policy = UsernamePolicy::default();
+
+        let result = policy.validate(&username("john🦀doe"));
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_INVALID_CHARACTERS);
+    }
+
+    #[test]
+    fn ascii_charset_rejects_accented_letters() {
+        let policy = UsernamePolicy::default();
+
+        let result = policy.validate(&username("josé"));
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_INVALID_CHARACTERS);
+    }
+
+    #[test]
+    fn unicode_charset_accepts_accented_letters() {
+        let policy = UsernamePolicy::new(UsernameCharset::Unicode, DEFAULT_RESERVED_USERNAMES);
+
+        let result = policy.validate(&username("josé"));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn unicode_charset_rejects_mixed_scripts() {
+        let policy = UsernamePolicy::new(UsernameCharset::Unicode, DEFAULT_RESERVED_USERNAMES);
+
+        let result = policy.validate(&username("\u{440}\u{430}ypal"));
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_MIXED_SCRIPT);
+    }
+
+    #[test]
+    fn rejects_reserved_username_regardless_of_case() {
+        let policy = UsernamePolicy::default();
+
+        let result = policy.validate(&username("ADMIN"));
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_RESERVED);
+    }
+
+    #[test]
+    fn rejects_confusable_of_reserved_username() {
+        let policy = UsernamePolicy::default();
+
+        let result = policy.validate(&username("adrnin"));
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_RESERVED);
+    }
+
+    #[test]
+    fn uses_custom_reserved_names() {
+        let policy = UsernamePolicy::new(UsernameCharset::Ascii, ["kume"]);
+
+        assert!(policy.validate(&username("admin")).is_ok());
+        assert!(policy.validate(&username("Kume")).is_err());
+    }
+}
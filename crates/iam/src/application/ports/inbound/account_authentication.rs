@@ -2,8 +2,14 @@ use crate::application::{
     commands::authenticate_account::AuthenticateAccount,
     results::account_authenticated::AccountAuthenticated,
 };
-use shared::error::SystemError;
+use async_trait::async_trait;
+use shared::{application::request_context::RequestContext, error::SystemError};
 
-pub trait AccountAuthenticationPort {
-    fn execute(&self, data: AuthenticateAccount) -> Result<AccountAuthenticated, SystemError>;
+#[async_trait]
+pub trait AccountAuthenticationPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: AuthenticateAccount,
+        context: RequestContext,
+    ) -> Result<AccountAuthenticated, SystemError>;
 }
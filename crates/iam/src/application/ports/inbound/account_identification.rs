@@ -1,8 +1,10 @@
 use crate::application::{
     commands::identify_account::IdentifyAccount, results::account_identified::AccountIdentified,
 };
+use async_trait::async_trait;
 use shared::error::SystemError;
 
-pub trait AccountIdentificationPort {
-    fn execute(&self, data: IdentifyAccount) -> Result<AccountIdentified, SystemError>;
+#[async_trait]
+pub trait AccountIdentificationPort: Send + Sync {
+    async fn execute(&self, data: IdentifyAccount) -> Result<AccountIdentified, SystemError>;
 }
@@ -1,8 +1,10 @@
 use crate::application::{
     commands::register_account::RegisterAccount, results::account_registered::AccountRegistered,
 };
+use async_trait::async_trait;
 use shared::error::SystemError;
 
-pub trait AccountRegistrationPort {
-    fn execute(&self, data: RegisterAccount) -> Result<AccountRegistered, SystemError>;
+#[async_trait]
+pub trait AccountRegistrationPort: Send + Sync {
+    async fn execute(&self, data: RegisterAccount) -> Result<AccountRegistered, SystemError>;
 }
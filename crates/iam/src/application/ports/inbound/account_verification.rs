@@ -1,6 +1,12 @@
 use crate::application::commands::verify_account::VerifyAccount;
-use shared::error::SystemError;
+use async_trait::async_trait;
+use shared::{application::request_context::RequestContext, error::SystemError};
 
-pub trait AccountVerificationPort {
-    fn execute(&self, data: VerifyAccount) -> Result<bool, SystemError>;
+#[async_trait]
+pub trait AccountVerificationPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: VerifyAccount,
+        context: RequestContext,
+    ) -> Result<bool, SystemError>;
 }
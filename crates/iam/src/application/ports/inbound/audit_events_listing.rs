@@ -0,0 +1,14 @@
+use crate::application::{
+    commands::list_audit_events::ListAuditEvents, results::audit_events_listed::AuditEventsListed,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait AuditEventsListingPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: ListAuditEvents,
+        auth: AuthContext,
+    ) -> Result<AuditEventsListed, SystemError>;
+}
@@ -0,0 +1,8 @@
+use crate::application::results::current_account_retrieved::CurrentAccountRetrieved;
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait CurrentAccountRetrievalPort: Send + Sync {
+    async fn execute(&self, auth: AuthContext) -> Result<CurrentAccountRetrieved, SystemError>;
+}
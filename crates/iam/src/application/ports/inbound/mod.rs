@@ -2,3 +2,8 @@ pub mod account_authentication;
 pub mod account_identification;
 pub mod account_registration;
 pub mod account_verification;
+pub mod audit_events_listing;
+pub mod current_account_retrieval;
+pub mod own_profile_retrieval;
+pub mod profile_update;
+pub mod public_profile_retrieval;
@@ -0,0 +1,8 @@
+use crate::application::results::profile_retrieved::ProfileRetrieved;
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait OwnProfileRetrievalPort: Send + Sync {
+    async fn execute(&self, auth: AuthContext) -> Result<ProfileRetrieved, SystemError>;
+}
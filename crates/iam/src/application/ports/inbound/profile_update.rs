@@ -0,0 +1,14 @@
+use crate::application::{
+    commands::update_profile::UpdateProfile, results::profile_retrieved::ProfileRetrieved,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait ProfileUpdatePort: Send + Sync {
+    async fn execute(
+        &self,
+        data: UpdateProfile,
+        auth: AuthContext,
+    ) -> Result<ProfileRetrieved, SystemError>;
+}
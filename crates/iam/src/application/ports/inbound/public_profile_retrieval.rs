@@ -0,0 +1,15 @@
+use crate::application::{
+    commands::view_public_profile::ViewPublicProfile,
+    results::public_profile_retrieved::PublicProfileRetrieved,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+
+#[async_trait]
+pub trait PublicProfileRetrievalPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: ViewPublicProfile,
+        auth: Option<AuthContext>,
+    ) -> Result<PublicProfileRetrieved, SystemError>;
+}
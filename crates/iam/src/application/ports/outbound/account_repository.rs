@@ -1,12 +1,26 @@
 use crate::{
-    application::errors::account_repository::AccountRepositoryError, domain::aggregates::Account,
+    application::errors::account_repository::AccountRepositoryError,
+    domain::{aggregates::Account, value_objects::AccountId},
 };
+use async_trait::async_trait;
+use shared::application::ports::unit_of_work::UnitOfWork;
 
+#[async_trait]
 pub trait AccountRepositoryPort: Send + Sync {
-    fn find_by_username(&self, username: &str) -> Option<Account>;
+    async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, AccountRepositoryError>;
 
-    fn find_by_email(&self, email: &str) -> Option<Account>;
-    fn save(&self, user: &Account) -> Result<(), AccountRepositoryError>;
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<Account>, AccountRepositoryError>;
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<Account>, AccountRepositoryError>;
+    async fn save(&self, user: &Account) -> Result<(), AccountRepositoryError>;
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        user: &Account,
+    ) -> Result<(), AccountRepositoryError>;
 }
 
 #[cfg(test)]
@@ -16,92 +30,170 @@ pub mod test_utils {
             errors::account_repository::AccountRepositoryError,
             ports::outbound::account_repository::AccountRepositoryPort,
         },
-        domain::{aggregates::Account, value_objects::AccountStatus},
+        domain::{
+            aggregates::Account,
+            value_objects::{AccountId, AccountStatus, Username},
+        },
     };
+    use async_trait::async_trait;
+    use shared::application::ports::unit_of_work::UnitOfWork;
 
     pub struct FakeAccountRepository {
         should_fail: bool,
+        unavailable: bool,
         activated: bool,
         existing_username: Option<String>,
         existing_email: Option<String>,
+        existing_account: Option<Account>,
     }
 
     impl FakeAccountRepository {
         pub fn success() -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 activated: false,
                 existing_username: None,
                 existing_email: None,
+                existing_account: None,
             }
         }
 
         pub fn fail() -> Self {
             Self {
+                unavailable: false,
                 should_fail: true,
                 activated: false,
                 existing_username: None,
                 existing_email: None,
+                existing_account: None,
+            }
+        }
+
+        pub fn unavailable() -> Self {
+            Self {
+                unavailable: true,
+                ..Self::fail()
             }
         }
 
         pub fn with_existing_email(email: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 activated: false,
                 existing_username: None,
                 existing_email: Some(email.to_string()),
+                existing_account: None,
             }
         }
 
         pub fn with_existing_username(username: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 activated: false,
                 existing_username: Some(username.to_string()),
                 existing_email: None,
+                existing_account: None,
             }
         }
 
         pub fn active_with_existing_username(username: &str) -> Self {
             Self {
+                unavailable: false,
                 should_fail: false,
                 activated: true,
                 existing_username: Some(username.to_string()),
                 existing_email: None,
+                existing_account: None,
+            }
+        }
+
+        pub fn with_existing_account(account: Account) -> Self {
+            Self {
+                unavailable: false,
+                should_fail: false,
+                activated: false,
+                existing_username: None,
+                existing_email: None,
+                existing_account: Some(account),
             }
         }
     }
 
+    impl FakeAccountRepository {
+        fn read(&self) -> Result<(), AccountRepositoryError> {
+            if self.unavailable {
+                Err(AccountRepositoryError::Unavailable(
+                    "FakeAccountRepository unavailable".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[async_trait]
     impl AccountRepositoryPort for FakeAccountRepository {
-        fn find_by_username(&self, username: &str) -> Option<Account> {
-            self.existing_username
+        async fn find_by_id(
+            &self,
+            id: &AccountId,
+        ) -> Result<Option<Account>, AccountRepositoryError> {
+            self.read()?;
+            Ok(self
+                .existing_account
+                .as_ref()
+                .filter(|account| account.id() == id)
+                .cloned())
+        }
+
+        async fn find_by_username(
+            &self,
+            username: &str,
+        ) -> Result<Option<Account>, AccountRepositoryError> {
+            self.read()?;
+            Ok(self
+                .existing_username
                 .as_ref()
-                .filter(|u| u.as_str() == username)
+                .filter(|u| Username::lookup_key_of(u) == Username::lookup_key_of(username))
                 .map(|_| {
                     if !self.activated {
                         Account::dummy_account()
                     } else {
                         Account::dummy_account_with_status(AccountStatus::Active)
                     }
-                })
+                }))
         }
 
-        fn find_by_email(&self, email: &str) -> Option<Account> {
-            self.existing_email
+        async fn find_by_email(
+            &self,
+            email: &str,
+        ) -> Result<Option<Account>, AccountRepositoryError> {
+            self.read()?;
+            Ok(self
+                .existing_email
                 .as_ref()
                 .filter(|e| e.as_str() == email)
-                .map(|_| Account::dummy_account())
+                .map(|_| Account::dummy_account()))
         }
 
-        fn save(&self, _user: &Account) -> Result<(), AccountRepositoryError> {
+        async fn save(&self, _user: &Account) -> Result<(), AccountRepositoryError> {
             if self.should_fail {
-                Err(AccountRepositoryError(
+                Err(AccountRepositoryError::Storage(
                     "FakeAccountRepository error".to_string(),
                 ))
             } else {
                 Ok(())
             }
         }
+
+        async fn save_in(
+            &self,
+            _unit_of_work: &mut dyn UnitOfWork,
+            user: &Account,
+        ) -> Result<(), AccountRepositoryError> {
+            self.save(user).await
+        }
     }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    application::errors::audit_log::AuditLogError,
+    domain::{aggregates::AuditEvent, value_objects::AccountId},
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditLogPort: Send + Sync {
+    async fn append(&self, event: &AuditEvent) -> Result<(), AuditLogError>;
+
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+        limit: usize,
+    ) -> Result<Vec<AuditEvent>, AuditLogError>;
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::{
+        application::{errors::audit_log::AuditLogError, ports::outbound::audit_log::AuditLogPort},
+        domain::{aggregates::AuditEvent, value_objects::AccountId},
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    pub struct FakeAuditLog {
+        should_fail: bool,
+        events: Mutex<Vec<AuditEvent>>,
+    }
+
+    impl FakeAuditLog {
+        pub fn success() -> Self {
+            Self {
+                should_fail: false,
+                events: Mutex::new(Vec::new()),
+            }
+        }
+
+        pub fn fail() -> Self {
+            Self {
+                should_fail: true,
+                events: Mutex::new(Vec::new()),
+            }
+        }
+
+        pub fn recorded(&self) -> Vec<AuditEvent> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AuditLogPort for FakeAuditLog {
+        async fn append(&self, event: &AuditEvent) -> Result<(), AuditLogError> {
+            if self.should_fail {
+                return Err(AuditLogError("FakeAuditLog error".to_string()));
+            }
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+
+        async fn find_by_account_id(
+            &self,
+            account_id: &AccountId,
+            limit: usize,
+        ) -> Result<Vec<AuditEvent>, AuditLogError> {
+            if self.should_fail {
+                return Err(AuditLogError("FakeAuditLog error".to_string()));
+            }
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|event| event.account_id() == Some(account_id))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+}
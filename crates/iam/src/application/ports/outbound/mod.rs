@@ -1,3 +1,5 @@
 pub mod account_repository;
+pub mod audit_log;
 pub mod password_hasher;
+pub mod profile_repository;
 pub mod token_generator;
@@ -1,8 +1,10 @@
 use crate::domain::value_objects::HashedPassword;
+use async_trait::async_trait;
 
+#[async_trait]
 pub trait PasswordHasherPort: Send + Sync {
-    fn hash(&self, password: &str) -> HashedPassword;
-    fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool;
+    async fn hash(&self, password: &str) -> HashedPassword;
+    async fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool;
 }
 
 #[cfg(test)]
@@ -11,15 +13,17 @@ pub mod test_utils {
         application::ports::outbound::password_hasher::PasswordHasherPort,
         domain::value_objects::HashedPassword,
     };
+    use async_trait::async_trait;
 
     pub struct FakePasswordHasher;
 
+    #[async_trait]
     impl PasswordHasherPort for FakePasswordHasher {
-        fn hash(&self, _raw: &str) -> HashedPassword {
+        async fn hash(&self, _raw: &str) -> HashedPassword {
             HashedPassword::dummy()
         }
 
-        fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool {
+        async fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool {
             password == hashed_password.as_str()
         }
     }
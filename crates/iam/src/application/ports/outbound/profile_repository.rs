@@ -0,0 +1,104 @@
+use crate::{
+    application::errors::profile_repository::ProfileRepositoryError,
+    domain::{aggregates::Profile, value_objects::AccountId},
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ProfileRepositoryPort: Send + Sync {
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<Profile>, ProfileRepositoryError>;
+
+    async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::{
+        application::{
+            errors::profile_repository::ProfileRepositoryError,
+            ports::outbound::profile_repository::ProfileRepositoryPort,
+        },
+        domain::{aggregates::Profile, value_objects::AccountId},
+    };
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    pub struct FakeProfileRepository {
+        should_fail: bool,
+        unreadable: bool,
+        existing: Option<Profile>,
+        saved: AtomicBool,
+    }
+
+    impl FakeProfileRepository {
+        pub fn success() -> Self {
+            Self {
+                should_fail: false,
+                unreadable: false,
+                existing: None,
+                saved: AtomicBool::new(false),
+            }
+        }
+
+        pub fn fail() -> Self {
+            Self {
+                should_fail: true,
+                unreadable: false,
+                existing: None,
+                saved: AtomicBool::new(false),
+            }
+        }
+
+        pub fn with_existing(profile: Profile) -> Self {
+            Self {
+                should_fail: false,
+                unreadable: false,
+                existing: Some(profile),
+                saved: AtomicBool::new(false),
+            }
+        }
+
+        pub fn unreadable() -> Self {
+            Self {
+                should_fail: false,
+                unreadable: true,
+                existing: None,
+                saved: AtomicBool::new(false),
+            }
+        }
+
+        pub fn saved(&self) -> bool {
+            self.saved.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl ProfileRepositoryPort for FakeProfileRepository {
+        async fn find_by_account_id(
+            &self,
+            _account_id: &AccountId,
+        ) -> Result<Option<Profile>, ProfileRepositoryError> {
+            if self.unreadable {
+                Err(ProfileRepositoryError(
+                    "FakeProfileRepository error".to_string(),
+                ))
+            } else {
+                Ok(self.existing.clone())
+            }
+        }
+
+        async fn save(&self, _profile: &Profile) -> Result<(), ProfileRepositoryError> {
+            if self.should_fail {
+                Err(ProfileRepositoryError(
+                    "FakeProfileRepository error".to_string(),
+                ))
+            } else {
+                self.saved.store(true, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::time::SystemTime;
+
+#[derive(Debug)]
+pub struct AuditEventListed {
+    pub id: String,
+    pub event_type: String,
+    pub actor_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub occurred_at: SystemTime,
+}
+
+#[derive(Debug)]
+pub struct AuditEventsListed {
+    pub events: Vec<AuditEventListed>,
+}
@@ -0,0 +1,14 @@
+use std::time::SystemTime;
+
+#[derive(Debug)]
+pub struct CurrentAccountRetrieved {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub status: String,
+    pub verified: bool,
+    pub roles: Vec<String>,
+    pub created_at: SystemTime,
+    pub verified_at: Option<SystemTime>,
+    pub last_login_at: Option<SystemTime>,
+}
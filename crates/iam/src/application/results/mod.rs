@@ -1,3 +1,7 @@
 pub mod account_authenticated;
 pub mod account_identified;
 pub mod account_registered;
+pub mod audit_events_listed;
+pub mod current_account_retrieved;
+pub mod profile_retrieved;
+pub mod public_profile_retrieved;
@@ -0,0 +1,26 @@
+use crate::domain::aggregates::Profile;
+
+#[derive(Debug)]
+pub struct ProfileRetrieved {
+    pub account_id: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub locale: String,
+    pub timezone: String,
+    pub visibility: String,
+}
+
+impl From<&Profile> for ProfileRetrieved {
+    fn from(profile: &Profile) -> Self {
+        Self {
+            account_id: profile.account_id().as_uuid().to_string(),
+            display_name: profile.display_name().map(|v| v.as_str().to_owned()),
+            bio: profile.bio().map(|v| v.as_str().to_owned()),
+            avatar: profile.avatar().map(|v| v.as_str().to_owned()),
+            locale: profile.locale().as_str().to_owned(),
+            timezone: profile.timezone().as_str().to_owned(),
+            visibility: profile.visibility().as_str().to_owned(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use std::time::SystemTime;
+
+#[derive(Debug)]
+pub struct PublicProfileRetrieved {
+    pub username: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    pub member_since: SystemTime,
+}
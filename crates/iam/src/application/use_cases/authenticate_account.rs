@@ -5,21 +5,31 @@ use crate::{
         ports::{
             inbound::account_authentication::AccountAuthenticationPort,
             outbound::{
-                account_repository::AccountRepositoryPort, password_hasher::PasswordHasherPort,
-                token_generator::TokenGeneratorPort,
+                account_repository::AccountRepositoryPort, audit_log::AuditLogPort,
+                password_hasher::PasswordHasherPort, token_generator::TokenGeneratorPort,
             },
         },
         results::account_authenticated::AccountAuthenticated,
     },
-    domain::{aggregates::Account, errors::AccountError},
+    domain::{
+        aggregates::AuditEvent,
+        errors::AccountError,
+        value_objects::{AccountId, AuditEventType, AuditOrigin},
+    },
+};
+use async_trait::async_trait;
+use shared::{
+    application::{ports::clock::ClockPort, request_context::RequestContext},
+    error::SystemError,
 };
-use shared::error::SystemError;
 use std::sync::Arc;
 
 pub struct AuthenticateAccountUseCase {
     account_repository: Arc<dyn AccountRepositoryPort>,
     password_hasher: Arc<dyn PasswordHasherPort>,
     token_generator: Arc<dyn TokenGeneratorPort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    clock: Arc<dyn ClockPort>,
 }
 
 impl AuthenticateAccountUseCase {
@@ -27,37 +37,72 @@ impl AuthenticateAccountUseCase {
         account_repository: Arc<dyn AccountRepositoryPort>,
         password_hasher: Arc<dyn PasswordHasherPort>,
         token_generator: Arc<dyn TokenGeneratorPort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        clock: Arc<dyn ClockPort>,
     ) -> Self {
         Self {
             account_repository,
             password_hasher,
             token_generator,
+            audit_log,
+            clock,
+        }
+    }
+
+    async fn audit(
+        &self,
+        event_type: AuditEventType,
+        account_id: Option<&AccountId>,
+        context: &RequestContext,
+    ) {
+        let event = AuditEvent::record(
+            event_type,
+            account_id.cloned(),
+            account_id.cloned(),
+            AuditOrigin::new(context.ip_address.clone(), context.user_agent.clone()),
+            self.clock.now(),
+        );
+
+        if let Err(err) = self.audit_log.append(&event).await {
+            tracing::error!(
+                error = %err,
+                event_type = event_type.as_str(),
+                "Failed to record audit event"
+            );
         }
     }
 }
 
+#[async_trait]
 impl AccountAuthenticationPort for AuthenticateAccountUseCase {
-    fn execute(&self, cmd: AuthenticateAccount) -> Result<AccountAuthenticated, SystemError> {
-        let account: Option<Account>;
-
-        let existing_username = self
+    async fn execute(
+        &self,
+        cmd: AuthenticateAccount,
+        context: RequestContext,
+    ) -> Result<AccountAuthenticated, SystemError> {
+        let Some(mut account) = self
             .account_repository
-            .find_by_username(cmd.username.as_str());
-        if existing_username.is_some() {
-            account = existing_username;
-        } else {
+            .find_by_username(cmd.username.as_str())
+            .await?
+        else {
+            self.audit(AuditEventType::SignInFailed, None, &context)
+                .await;
             return Err(AccountError::AccountNotFound.into());
-        }
-        let account = account.unwrap();
+        };
 
         if !self
             .password_hasher
-            .verify(cmd.password.as_str(), &account.password())
+            .verify(cmd.password.as_str(), account.password())
+            .await
         {
+            self.audit(AuditEventType::SignInFailed, Some(account.id()), &context)
+                .await;
             return Err(AuthenticateAccountError::LoginFailed.into());
         }
 
         if !account.can_authenticate() {
+            self.audit(AuditEventType::SignInFailed, Some(account.id()), &context)
+                .await;
             return Err(AuthenticateAccountError::CannotAuthenticate.into());
         }
 
@@ -65,6 +110,15 @@ impl AccountAuthenticationPort for AuthenticateAccountUseCase {
             .token_generator
             .generate(&account.id().as_uuid().to_string())?;
 
+        account.record_login(self.clock.now());
+        self.account_repository.save(&account).await?;
+        self.audit(
+            AuditEventType::SignInSucceeded,
+            Some(account.id()),
+            &context,
+        )
+        .await;
+
         Ok(AccountAuthenticated { token })
     }
 }
@@ -79,86 +133,205 @@ mod tests {
                 inbound::account_authentication::AccountAuthenticationPort,
                 outbound::{
                     account_repository::test_utils::FakeAccountRepository,
+                    audit_log::test_utils::FakeAuditLog,
                     password_hasher::test_utils::FakePasswordHasher,
                     token_generator::test_utils::FakeTokenGenerator,
                 },
             },
             use_cases::authenticate_account::AuthenticateAccountUseCase,
         },
-        domain::{errors::error_codes::IAM_ACCOUNT_NOT_FOUND, value_objects::HashedPassword},
+        domain::{
+            errors::error_codes::IAM_ACCOUNT_NOT_FOUND,
+            value_objects::{AuditEventType, HashedPassword},
+        },
     };
+    use shared::{application::request_context::RequestContext, infrastructure::clock::FixedClock};
     use std::sync::Arc;
 
-    #[test]
-    fn fails_when_account_not_found() {
+    #[tokio::test]
+    async fn fails_when_account_not_found() {
         let repo = Arc::new(FakeAccountRepository::success());
         let hasher = Arc::new(FakePasswordHasher);
         let token_generator = Arc::new(FakeTokenGenerator);
+        let audit_log = Arc::new(FakeAuditLog::success());
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
 
-        let use_case = AuthenticateAccountUseCase::new(repo, hasher, token_generator);
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            hasher,
+            token_generator,
+            audit_log.clone(),
+            clock,
+        );
 
-        let result = use_case.execute(AuthenticateAccount {
-            username: "dummy".to_string(),
-            password: HashedPassword::dummy().as_str().to_string(),
-        });
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: HashedPassword::dummy().as_str().to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
     }
 
-    #[test]
-    fn fails_with_incorrect_password() {
+    #[tokio::test]
+    async fn fails_with_incorrect_password() {
         let repo = Arc::new(FakeAccountRepository::with_existing_username("dummy"));
         let hasher = Arc::new(FakePasswordHasher);
         let token_generator = Arc::new(FakeTokenGenerator);
+        let audit_log = Arc::new(FakeAuditLog::success());
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
 
-        let use_case = AuthenticateAccountUseCase::new(repo, hasher, token_generator);
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            hasher,
+            token_generator,
+            audit_log.clone(),
+            clock,
+        );
 
-        let result = use_case.execute(AuthenticateAccount {
-            username: "dummy".to_string(),
-            password: "wrong_password".to_string(),
-        });
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: "wrong_password".to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_LOGIN_FAILED);
+        assert_eq!(
+            audit_log.recorded()[0].event_type(),
+            AuditEventType::SignInFailed
+        );
     }
 
-    #[test]
-    fn fails_when_account_cannot_authenticate() {
+    #[tokio::test]
+    async fn fails_when_account_cannot_authenticate() {
         let repo = Arc::new(FakeAccountRepository::with_existing_username("dummy"));
         let hasher = Arc::new(FakePasswordHasher);
         let token_generator = Arc::new(FakeTokenGenerator);
+        let audit_log = Arc::new(FakeAuditLog::success());
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
 
-        let use_case = AuthenticateAccountUseCase::new(repo, hasher, token_generator);
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            hasher,
+            token_generator,
+            audit_log.clone(),
+            clock,
+        );
 
-        let result = use_case.execute(AuthenticateAccount {
-            username: "dummy".to_string(),
-            password: HashedPassword::dummy().as_str().to_string(),
-        });
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: HashedPassword::dummy().as_str().to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_CANNOT_AUTHENTICATE);
     }
 
-    #[test]
-    fn authenticate_account_successfully_returns_token() {
+    #[tokio::test]
+    async fn authenticate_account_successfully_returns_token() {
         let repo = Arc::new(FakeAccountRepository::active_with_existing_username(
             "dummy",
         ));
         let hasher = Arc::new(FakePasswordHasher);
         let token_generator = Arc::new(FakeTokenGenerator);
+        let audit_log = Arc::new(FakeAuditLog::success());
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
 
-        let use_case = AuthenticateAccountUseCase::new(repo, hasher, token_generator);
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            hasher,
+            token_generator,
+            audit_log.clone(),
+            clock,
+        );
 
-        let result = use_case.execute(AuthenticateAccount {
-            username: "dummy".to_string(),
-            password: HashedPassword::dummy().as_str().to_string(),
-        });
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: HashedPassword::dummy().as_str().to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap().token, "valid_token");
+        assert_eq!(
+            audit_log.recorded()[0].event_type(),
+            AuditEventType::SignInSucceeded
+        );
+    }
+
+    #[tokio::test]
+    async fn signs_in_even_when_the_audit_log_fails() {
+        let repo = Arc::new(FakeAccountRepository::active_with_existing_username(
+            "dummy",
+        ));
+
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            Arc::new(FakePasswordHasher),
+            Arc::new(FakeTokenGenerator),
+            Arc::new(FakeAuditLog::fail()),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        );
+
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: HashedPassword::dummy().as_str().to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
+
+        assert_eq!(result.unwrap().token, "valid_token");
+    }
+
+    #[tokio::test]
+    async fn keeps_the_sign_in_error_when_the_audit_log_fails() {
+        let repo = Arc::new(FakeAccountRepository::success());
+
+        let use_case = AuthenticateAccountUseCase::new(
+            repo,
+            Arc::new(FakePasswordHasher),
+            Arc::new(FakeTokenGenerator),
+            Arc::new(FakeAuditLog::fail()),
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        );
+
+        let result = use_case
+            .execute(
+                AuthenticateAccount {
+                    username: "dummy".to_string(),
+                    password: HashedPassword::dummy().as_str().to_string(),
+                },
+                RequestContext::default(),
+            )
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
     }
 }
@@ -0,0 +1,125 @@
+use crate::{
+    application::{
+        errors::authenticate_account::AuthenticateAccountError,
+        ports::{
+            inbound::current_account_retrieval::CurrentAccountRetrievalPort,
+            outbound::account_repository::AccountRepositoryPort,
+        },
+        results::current_account_retrieved::CurrentAccountRetrieved,
+    },
+    domain::value_objects::AccountId,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct GetCurrentAccountUseCase {
+    account_repository: Arc<dyn AccountRepositoryPort>,
+}
+
+impl GetCurrentAccountUseCase {
+    pub fn new(account_repository: Arc<dyn AccountRepositoryPort>) -> Self {
+        Self { account_repository }
+    }
+}
+
+#[async_trait]
+impl CurrentAccountRetrievalPort for GetCurrentAccountUseCase {
+    async fn execute(&self, auth: AuthContext) -> Result<CurrentAccountRetrieved, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+
+        let account = self
+            .account_repository
+            .find_by_id(&account_id)
+            .await?
+            .filter(|account| account.can_authenticate())
+            .ok_or(AuthenticateAccountError::CannotAuthenticate)?;
+
+        Ok(CurrentAccountRetrieved {
+            id: account.id().as_uuid().to_string(),
+            username: account.username().as_str().to_owned(),
+            email: account.email().as_str().to_owned(),
+            status: account.status().as_str().to_owned(),
+            verified: account.is_verified(),
+            roles: account
+                .roles()
+                .iter()
+                .map(|role| role.as_str().to_owned())
+                .collect(),
+            created_at: account.created_at(),
+            verified_at: account.verified_at(),
+            last_login_at: account.last_login_at(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            errors::error_codes::IAM_CANNOT_AUTHENTICATE,
+            ports::{
+                inbound::current_account_retrieval::CurrentAccountRetrievalPort,
+                outbound::account_repository::test_utils::FakeAccountRepository,
+            },
+            use_cases::get_current_account::GetCurrentAccountUseCase,
+        },
+        domain::{
+            aggregates::Account,
+            value_objects::{AccountId, AccountStatus, PlatformRole},
+        },
+    };
+    use shared::application::auth_context::AuthContext;
+    use std::sync::Arc;
+
+    fn auth_for(account_id: &AccountId) -> AuthContext {
+        AuthContext {
+            account_id: account_id.as_uuid().to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_when_account_no_longer_exists() {
+        let repo = Arc::new(FakeAccountRepository::success());
+
+        let use_case = GetCurrentAccountUseCase::new(repo);
+
+        let result = use_case.execute(auth_for(&AccountId::generate())).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_CANNOT_AUTHENTICATE);
+    }
+
+    #[tokio::test]
+    async fn fails_when_account_was_suspended() {
+        let account = Account::dummy_account_with_status(AccountStatus::Suspended);
+        let auth = auth_for(account.id());
+        let repo = Arc::new(FakeAccountRepository::with_existing_account(account));
+
+        let use_case = GetCurrentAccountUseCase::new(repo);
+
+        let result = use_case.execute(auth).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_CANNOT_AUTHENTICATE);
+    }
+
+    #[tokio::test]
+    async fn returns_current_account() {
+        let mut account = Account::dummy_account_with_status(AccountStatus::Active);
+        account.grant_role(PlatformRole::Moderator);
+        let auth = auth_for(account.id());
+        let repo = Arc::new(FakeAccountRepository::with_existing_account(account));
+
+        let use_case = GetCurrentAccountUseCase::new(repo);
+
+        let result = use_case.execute(auth).await.unwrap();
+
+        assert_eq!(result.username, "dummy");
+        assert_eq!(result.status, "active");
+        assert!(result.verified);
+        assert_eq!(result.roles, vec!["moderator".to_string()]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    application::{
+        ports::{
+            inbound::own_profile_retrieval::OwnProfileRetrievalPort,
+            outbound::profile_repository::ProfileRepositoryPort,
+        },
+        results::profile_retrieved::ProfileRetrieved,
+    },
+    domain::{aggregates::Profile, value_objects::AccountId},
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct GetOwnProfileUseCase {
+    profile_repository: Arc<dyn ProfileRepositoryPort>,
+}
+
+impl GetOwnProfileUseCase {
+    pub fn new(profile_repository: Arc<dyn ProfileRepositoryPort>) -> Self {
+        Self { profile_repository }
+    }
+}
+
+#[async_trait]
+impl OwnProfileRetrievalPort for GetOwnProfileUseCase {
+    async fn execute(&self, auth: AuthContext) -> Result<ProfileRetrieved, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+
+        let profile = self
+            .profile_repository
+            .find_by_account_id(&account_id)
+            .await?
+            .unwrap_or_else(|| Profile::create_default(account_id));
+
+        Ok(ProfileRetrieved::from(&profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            errors::error_codes::IAM_PROFILE_REPOSITORY_ERROR,
+            ports::{
+                inbound::own_profile_retrieval::OwnProfileRetrievalPort,
+                outbound::profile_repository::test_utils::FakeProfileRepository,
+            },
+            use_cases::get_own_profile::GetOwnProfileUseCase,
+        },
+        domain::{
+            aggregates::Profile,
+            errors::error_codes::IAM_INVALID_ACCOUNT_ID_FORMAT,
+            value_objects::{AccountId, DisplayName},
+        },
+    };
+    use shared::application::auth_context::AuthContext;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn fails_when_account_id_is_malformed() {
+        let repo = Arc::new(FakeProfileRepository::success());
+
+        let use_case = GetOwnProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(AuthContext {
+                account_id: "not-a-uuid".to_string(),
+            })
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_INVALID_ACCOUNT_ID_FORMAT);
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_stored_profile_cannot_be_read() {
+        let repo = Arc::new(FakeProfileRepository::unreadable());
+
+        let use_case = GetOwnProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(AuthContext {
+                account_id: AccountId::generate().as_uuid().to_string(),
+            })
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_PROFILE_REPOSITORY_ERROR);
+    }
+
+    #[tokio::test]
+    async fn returns_default_profile_when_none_was_saved() {
+        let repo = Arc::new(FakeProfileRepository::success());
+
+        let use_case = GetOwnProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(AuthContext {
+                account_id: AccountId::generate().as_uuid().to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.display_name, None);
+        assert_eq!(result.locale, "en");
+        assert_eq!(result.visibility, "public");
+    }
+
+    #[tokio::test]
+    async fn returns_saved_profile() {
+        let account_id = AccountId::generate();
+        let mut profile = Profile::create_default(account_id.clone());
+        profile.change_display_name(Some(DisplayName::new("Dummy").unwrap()));
+        let repo = Arc::new(FakeProfileRepository::with_existing(profile));
+
+        let use_case = GetOwnProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(AuthContext {
+                account_id: account_id.as_uuid().to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.display_name.as_deref(), Some("Dummy"));
+    }
+}
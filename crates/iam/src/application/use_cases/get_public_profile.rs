@@ -0,0 +1,203 @@
+use crate::{
+    application::{
+        commands::view_public_profile::ViewPublicProfile,
+        ports::{
+            inbound::public_profile_retrieval::PublicProfileRetrievalPort,
+            outbound::{
+                account_repository::AccountRepositoryPort,
+                profile_repository::ProfileRepositoryPort,
+            },
+        },
+        results::public_profile_retrieved::PublicProfileRetrieved,
+    },
+    domain::{aggregates::Profile, errors::AccountError, value_objects::AccountId},
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct GetPublicProfileUseCase {
+    account_repository: Arc<dyn AccountRepositoryPort>,
+    profile_repository: Arc<dyn ProfileRepositoryPort>,
+}
+
+impl GetPublicProfileUseCase {
+    pub fn new(
+        account_repository: Arc<dyn AccountRepositoryPort>,
+        profile_repository: Arc<dyn ProfileRepositoryPort>,
+    ) -> Self {
+        Self {
+            account_repository,
+            profile_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl PublicProfileRetrievalPort for GetPublicProfileUseCase {
+    async fn execute(
+        &self,
+        data: ViewPublicProfile,
+        auth: Option<AuthContext>,
+    ) -> Result<PublicProfileRetrieved, SystemError> {
+        let viewer = auth
+            .map(|auth| AccountId::from_str(auth.account_id.as_str()))
+            .transpose()?;
+
+        let account = self
+            .account_repository
+            .find_by_username(data.username.as_str())
+            .await?
+            .filter(|account| account.can_authenticate())
+            .ok_or(AccountError::AccountNotFound)?;
+
+        let profile = self
+            .profile_repository
+            .find_by_account_id(account.id())
+            .await?
+            .unwrap_or_else(|| Profile::create_default(account.id().clone()));
+
+        if !profile.is_visible_to(viewer.as_ref()) {
+            return Err(AccountError::AccountNotFound.into());
+        }
+
+        Ok(PublicProfileRetrieved {
+            username: account.username().as_str().to_owned(),
+            display_name: profile.display_name().map(|v| v.as_str().to_owned()),
+            bio: profile.bio().map(|v| v.as_str().to_owned()),
+            avatar: profile.avatar().map(|v| v.as_str().to_owned()),
+            member_since: account.created_at(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::view_public_profile::ViewPublicProfile,
+            ports::{
+                inbound::public_profile_retrieval::PublicProfileRetrievalPort,
+                outbound::{
+                    account_repository::test_utils::FakeAccountRepository,
+                    profile_repository::test_utils::FakeProfileRepository,
+                },
+            },
+            use_cases::get_public_profile::GetPublicProfileUseCase,
+        },
+        domain::{
+            aggregates::Profile,
+            errors::error_codes::IAM_ACCOUNT_NOT_FOUND,
+            value_objects::{AccountId, ProfileVisibility},
+        },
+    };
+    use shared::application::auth_context::AuthContext;
+    use std::sync::Arc;
+
+    fn view(username: &str) -> ViewPublicProfile {
+        ViewPublicProfile {
+            username: username.to_string(),
+        }
+    }
+
+    fn profile_with(visibility: ProfileVisibility) -> Profile {
+        let mut profile = Profile::create_default(AccountId::generate());
+        profile.change_visibility(visibility);
+        profile
+    }
+
+    fn viewer() -> Option<AuthContext> {
+        Some(AuthContext {
+            account_id: AccountId::generate().as_uuid().to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn fails_when_account_not_found() {
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(FakeAccountRepository::success()),
+            Arc::new(FakeProfileRepository::success()),
+        );
+
+        let result = use_case.execute(view("ghost"), None).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn fails_when_account_is_not_active() {
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(FakeAccountRepository::with_existing_username("dummy")),
+            Arc::new(FakeProfileRepository::success()),
+        );
+
+        let result = use_case.execute(view("dummy"), None).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_default_public_profile() {
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(FakeAccountRepository::active_with_existing_username(
+                "dummy",
+            )),
+            Arc::new(FakeProfileRepository::success()),
+        );
+
+        let result = use_case.execute(view("Dummy"), None).await.unwrap();
+
+        assert_eq!(result.username, "dummy");
+        assert_eq!(result.display_name, None);
+    }
+
+    #[tokio::test]
+    async fn hides_members_profile_from_anonymous_visitors() {
+        let profile = profile_with(ProfileVisibility::Members);
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(FakeAccountRepository::active_with_existing_username(
+                "dummy",
+            )),
+            Arc::new(FakeProfileRepository::with_existing(profile)),
+        );
+
+        let anonymous = use_case.execute(view("dummy"), None).await;
+        let member = use_case.execute(view("dummy"), viewer()).await;
+
+        let err = anonymous.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
+        assert!(member.is_ok());
+    }
+
+    #[tokio::test]
+    async fn shows_private_profile_only_to_owner() {
+        let profile = profile_with(ProfileVisibility::Private);
+        let owner_id = profile.account_id().clone();
+        let use_case = GetPublicProfileUseCase::new(
+            Arc::new(FakeAccountRepository::active_with_existing_username(
+                "dummy",
+            )),
+            Arc::new(FakeProfileRepository::with_existing(profile)),
+        );
+
+        let other = use_case.execute(view("dummy"), viewer()).await;
+        let owner = use_case
+            .execute(
+                view("dummy"),
+                Some(AuthContext {
+                    account_id: owner_id.as_uuid().to_string(),
+                }),
+            )
+            .await;
+
+        let err = other.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
+        assert!(owner.is_ok());
+    }
+}
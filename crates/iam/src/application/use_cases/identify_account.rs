@@ -9,6 +9,7 @@ use crate::{
     },
     domain::{aggregates::Account, errors::AccountError},
 };
+use async_trait::async_trait;
 use shared::error::SystemError;
 use std::sync::Arc;
 
@@ -22,14 +23,19 @@ impl IdentifyAccountUseCase {
     }
 }
 
+#[async_trait]
 impl AccountIdentificationPort for IdentifyAccountUseCase {
-    fn execute(&self, cmd: IdentifyAccount) -> Result<AccountIdentified, SystemError> {
+    async fn execute(&self, cmd: IdentifyAccount) -> Result<AccountIdentified, SystemError> {
         let mut account: Option<Account> = None;
-        let existing_email = self.account_repository.find_by_email(cmd.identify.as_str());
+        let existing_email = self
+            .account_repository
+            .find_by_email(cmd.identify.as_str())
+            .await?;
         if existing_email.is_none() {
             let existing_username = self
                 .account_repository
-                .find_by_username(cmd.identify.as_str());
+                .find_by_username(cmd.identify.as_str())
+                .await?;
             if existing_username.is_some() {
                 account = existing_username;
             }
@@ -64,32 +70,36 @@ mod tests {
     };
     use std::sync::Arc;
 
-    #[test]
-    fn fails_when_account_not_found() {
+    #[tokio::test]
+    async fn fails_when_account_not_found() {
         let repo = Arc::new(FakeAccountRepository::success());
 
         let use_case = IdentifyAccountUseCase::new(repo);
 
-        let result = use_case.execute(IdentifyAccount {
-            identify: "not-exists@example.com".to_string(),
-        });
+        let result = use_case
+            .execute(IdentifyAccount {
+                identify: "not-exists@example.com".to_string(),
+            })
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
     }
 
-    #[test]
-    fn identify_account_successfully() {
+    #[tokio::test]
+    async fn identify_account_successfully() {
         let repo = Arc::new(FakeAccountRepository::with_existing_email(
             "dummy@example.com",
         ));
 
         let use_case = IdentifyAccountUseCase::new(repo);
 
-        let result = use_case.execute(IdentifyAccount {
-            identify: "dummy@example.com".to_string(),
-        });
+        let result = use_case
+            .execute(IdentifyAccount {
+                identify: "dummy@example.com".to_string(),
+            })
+            .await;
 
         assert!(result.is_ok());
     }
@@ -0,0 +1,151 @@
+use crate::{
+    application::{
+        commands::list_audit_events::ListAuditEvents,
+        ports::{
+            inbound::audit_events_listing::AuditEventsListingPort,
+            outbound::audit_log::AuditLogPort,
+        },
+        results::audit_events_listed::{AuditEventListed, AuditEventsListed},
+    },
+    domain::value_objects::AccountId,
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct ListAuditEventsUseCase {
+    audit_log: Arc<dyn AuditLogPort>,
+}
+
+impl ListAuditEventsUseCase {
+    const DEFAULT_LIMIT: usize = 50;
+    const MAX_LIMIT: usize = 200;
+
+    pub fn new(audit_log: Arc<dyn AuditLogPort>) -> Self {
+        Self { audit_log }
+    }
+}
+
+#[async_trait]
+impl AuditEventsListingPort for ListAuditEventsUseCase {
+    async fn execute(
+        &self,
+        data: ListAuditEvents,
+        auth: AuthContext,
+    ) -> Result<AuditEventsListed, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+        let limit = data
+            .limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT);
+
+        let events = self
+            .audit_log
+            .find_by_account_id(&account_id, limit)
+            .await?;
+
+        Ok(AuditEventsListed {
+            events: events
+                .iter()
+                .map(|event| AuditEventListed {
+                    id: event.id().to_string(),
+                    event_type: event.event_type().as_str().to_owned(),
+                    actor_id: event.actor_id().map(|id| id.as_uuid().to_string()),
+                    ip_address: event.origin().ip_address().map(str::to_owned),
+                    user_agent: event.origin().user_agent().map(str::to_owned),
+                    occurred_at: event.occurred_at(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::list_audit_events::ListAuditEvents,
+            errors::error_codes::IAM_AUDIT_LOG_ERROR,
+            ports::{
+                inbound::audit_events_listing::AuditEventsListingPort,
+                outbound::audit_log::{AuditLogPort, test_utils::FakeAuditLog},
+            },
+            use_cases::list_audit_events::ListAuditEventsUseCase,
+        },
+        domain::{
+            aggregates::AuditEvent,
+            value_objects::{AccountId, AuditEventType, AuditOrigin},
+        },
+    };
+    use shared::application::auth_context::AuthContext;
+    use std::{sync::Arc, time::UNIX_EPOCH};
+
+    fn event_for(account_id: &AccountId, event_type: AuditEventType) -> AuditEvent {
+        AuditEvent::record(
+            event_type,
+            Some(account_id.clone()),
+            Some(account_id.clone()),
+            AuditOrigin::new(Some("127.0.0.1".to_string()), None),
+            UNIX_EPOCH,
+        )
+    }
+
+    #[tokio::test]
+    async fn fails_when_audit_log_fails() {
+        let use_case = ListAuditEventsUseCase::new(Arc::new(FakeAuditLog::fail()));
+
+        let result = use_case
+            .execute(
+                ListAuditEvents { limit: None },
+                AuthContext {
+                    account_id: AccountId::generate().as_uuid().to_string(),
+                },
+            )
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_AUDIT_LOG_ERROR);
+    }
+
+    #[tokio::test]
+    async fn lists_only_own_events_newest_first() {
+        let owner = AccountId::generate();
+        let audit_log = Arc::new(FakeAuditLog::success());
+        audit_log
+            .append(&event_for(&owner, AuditEventType::AccountVerified))
+            .await
+            .unwrap();
+        audit_log
+            .append(&event_for(
+                &AccountId::generate(),
+                AuditEventType::SignInFailed,
+            ))
+            .await
+            .unwrap();
+        audit_log
+            .append(&event_for(&owner, AuditEventType::SignInSucceeded))
+            .await
+            .unwrap();
+
+        let use_case = ListAuditEventsUseCase::new(audit_log);
+
+        let result = use_case
+            .execute(
+                ListAuditEvents { limit: None },
+                AuthContext {
+                    account_id: owner.as_uuid().to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let types: Vec<&str> = result
+            .events
+            .iter()
+            .map(|e| e.event_type.as_str())
+            .collect();
+        assert_eq!(types, vec!["sign_in_succeeded", "account_verified"]);
+        assert_eq!(result.events[0].ip_address.as_deref(), Some("127.0.0.1"));
+    }
+}
@@ -1,4 +1,9 @@
 pub mod authenticate_account;
+pub mod get_current_account;
+pub mod get_own_profile;
+pub mod get_public_profile;
 pub mod identify_account;
+pub mod list_audit_events;
 pub mod register_account;
+pub mod update_profile;
 pub mod verify_account;
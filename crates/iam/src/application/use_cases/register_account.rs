@@ -1,7 +1,7 @@
 use crate::{
     application::{
         commands::register_account::RegisterAccount,
-        policies::password_policy::PasswordPolicy,
+        policies::{password_policy::PasswordPolicy, username_policy::UsernamePolicy},
         ports::{
             inbound::account_registration::AccountRegistrationPort,
             outbound::{
@@ -16,36 +16,55 @@ use crate::{
         value_objects::{AccountId, Email, Username},
     },
 };
-use shared::error::SystemError;
+use async_trait::async_trait;
+use shared::{application::ports::clock::ClockPort, error::SystemError};
 use std::sync::Arc;
 
 pub struct RegisterAccountUseCase {
     account_repository: Arc<dyn AccountRepositoryPort>,
     password_hasher: Arc<dyn PasswordHasherPort>,
+    clock: Arc<dyn ClockPort>,
+    username_policy: UsernamePolicy,
 }
 
 impl RegisterAccountUseCase {
     pub fn new(
         account_repository: Arc<dyn AccountRepositoryPort>,
         password_hasher: Arc<dyn PasswordHasherPort>,
+        clock: Arc<dyn ClockPort>,
     ) -> Self {
         Self {
             account_repository,
             password_hasher,
+            clock,
+            username_policy: UsernamePolicy::default(),
         }
     }
+
+    pub fn with_username_policy(mut self, username_policy: UsernamePolicy) -> Self {
+        self.username_policy = username_policy;
+        self
+    }
 }
 
+#[async_trait]
 impl AccountRegistrationPort for RegisterAccountUseCase {
-    fn execute(&self, cmd: RegisterAccount) -> Result<AccountRegistered, SystemError> {
-        let existing_email = self.account_repository.find_by_email(cmd.email.as_str());
+    async fn execute(&self, cmd: RegisterAccount) -> Result<AccountRegistered, SystemError> {
+        let username = Username::new(cmd.username)?;
+        self.username_policy.validate(&username)?;
+
+        let existing_email = self
+            .account_repository
+            .find_by_email(cmd.email.as_str())
+            .await?;
         if existing_email.is_some() {
             return Err(AccountError::EmailAlreadyExists.into());
         }
 
         let existing_username = self
             .account_repository
-            .find_by_username(cmd.username.as_str());
+            .find_by_username(&username.lookup_key())
+            .await?;
         if existing_username.is_some() {
             return Err(AccountError::UsernameAlreadyExists.into());
         }
@@ -53,13 +72,18 @@ impl AccountRegistrationPort for RegisterAccountUseCase {
         PasswordPolicy::validate(&cmd.password)?;
 
         let account_id = AccountId::generate();
-        let username = Username::new(cmd.username)?;
         let email = Email::new(cmd.email)?;
-        let hashed_password = self.password_hasher.hash(&cmd.password);
+        let hashed_password = self.password_hasher.hash(&cmd.password).await;
 
-        let account = Account::register(account_id, username, email, hashed_password);
+        let account = Account::register(
+            account_id,
+            username,
+            email,
+            hashed_password,
+            self.clock.now(),
+        );
 
-        self.account_repository.save(&account)?;
+        self.account_repository.save(&account).await?;
 
         dbg!(&account);
 
@@ -76,7 +100,10 @@ mod tests {
     use crate::{
         application::{
             commands::register_account::RegisterAccount,
-            errors::error_codes::IAM_ACCOUNT_REPOSITORY_ERROR,
+            errors::error_codes::{
+                IAM_ACCOUNT_REPOSITORY_ERROR, IAM_ACCOUNT_REPOSITORY_UNAVAILABLE,
+                IAM_USERNAME_RESERVED,
+            },
             ports::{
                 inbound::account_registration::AccountRegistrationPort,
                 outbound::{
@@ -91,6 +118,7 @@ mod tests {
             IAM_INVALID_EMAIL, IAM_INVALID_USERNAME,
         },
     };
+    use shared::infrastructure::clock::FixedClock;
     use std::sync::Arc;
 
     fn valid_input() -> RegisterAccount {
@@ -101,24 +129,26 @@ mod tests {
         }
     }
 
-    #[test]
-    fn register_account_successfully() {
+    #[tokio::test]
+    async fn register_account_successfully() {
         let repo = Arc::new(FakeAccountRepository::success());
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
-        let result = use_case.execute(valid_input());
+        let result = use_case.execute(valid_input()).await;
 
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn fails_when_username_is_invalid() {
+    #[tokio::test]
+    async fn fails_when_username_is_invalid() {
         let repo = Arc::new(FakeAccountRepository::success());
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
         let input = RegisterAccount {
             username: "".to_string(),
@@ -126,19 +156,20 @@ mod tests {
             password: "password123456789".to_string(),
         };
 
-        let result = use_case.execute(input);
+        let result = use_case.execute(input).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_INVALID_USERNAME);
     }
 
-    #[test]
-    fn fails_when_email_is_invalid() {
+    #[tokio::test]
+    async fn fails_when_email_is_invalid() {
         let repo = Arc::new(FakeAccountRepository::success());
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
         let input = RegisterAccount {
             username: "john_doe".to_string(),
@@ -146,54 +177,112 @@ mod tests {
             password: "password123456789".to_string(),
         };
 
-        let result = use_case.execute(input);
+        let result = use_case.execute(input).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_INVALID_EMAIL);
     }
 
-    #[test]
-    fn fails_when_repository_fails() {
+    #[tokio::test]
+    async fn fails_when_repository_fails() {
         let repo = Arc::new(FakeAccountRepository::fail());
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
-        let result = use_case.execute(valid_input());
+        let result = use_case.execute(valid_input()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_REPOSITORY_ERROR);
     }
 
-    #[test]
-    fn fails_when_username_already_exists() {
+    #[tokio::test]
+    async fn fails_when_repository_is_unavailable() {
+        let repo = Arc::new(FakeAccountRepository::unavailable());
+        let hasher = Arc::new(FakePasswordHasher);
+
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
+
+        let result = use_case.execute(valid_input()).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_REPOSITORY_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn fails_when_username_already_exists() {
         let repo = Arc::new(FakeAccountRepository::with_existing_username("john_doe"));
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
-        let result = use_case.execute(valid_input());
+        let result = use_case.execute(valid_input()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_USERNAME_ALREADY_EXISTS);
     }
 
-    #[test]
-    fn fails_when_email_already_exists() {
+    #[tokio::test]
+    async fn fails_when_email_already_exists() {
         let repo = Arc::new(FakeAccountRepository::with_existing_email(
             "john@example.com",
         ));
         let hasher = Arc::new(FakePasswordHasher);
 
-        let use_case = RegisterAccountUseCase::new(repo, hasher);
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
 
-        let result = use_case.execute(valid_input());
+        let result = use_case.execute(valid_input()).await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_EMAIL_ALREADY_EXISTS);
     }
+
+    #[tokio::test]
+    async fn fails_when_username_differs_only_by_case() {
+        let repo = Arc::new(FakeAccountRepository::with_existing_username("john_doe"));
+        let hasher = Arc::new(FakePasswordHasher);
+
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
+
+        let input = RegisterAccount {
+            username: "John_Doe".to_string(),
+            ..valid_input()
+        };
+
+        let result = use_case.execute(input).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_USERNAME_ALREADY_EXISTS);
+    }
+
+    #[tokio::test]
+    async fn fails_when_username_is_reserved() {
+        let repo = Arc::new(FakeAccountRepository::success());
+        let hasher = Arc::new(FakePasswordHasher);
+
+        let use_case =
+            RegisterAccountUseCase::new(repo, hasher, Arc::new(FixedClock::at_unix_seconds(0)));
+
+        let input = RegisterAccount {
+            username: "Support".to_string(),
+            ..valid_input()
+        };
+
+        let result = use_case.execute(input).await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_USERNAME_RESERVED);
+    }
 }
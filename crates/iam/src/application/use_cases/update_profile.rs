@@ -0,0 +1,250 @@
+use crate::{
+    application::{
+        commands::update_profile::UpdateProfile,
+        ports::{
+            inbound::profile_update::ProfileUpdatePort,
+            outbound::profile_repository::ProfileRepositoryPort,
+        },
+        results::profile_retrieved::ProfileRetrieved,
+    },
+    domain::{
+        aggregates::Profile,
+        value_objects::{
+            AccountId, AvatarRef, Bio, DisplayName, Locale, ProfileVisibility, Timezone,
+        },
+    },
+};
+use async_trait::async_trait;
+use shared::{application::auth_context::AuthContext, error::SystemError};
+use std::sync::Arc;
+
+pub struct UpdateProfileUseCase {
+    profile_repository: Arc<dyn ProfileRepositoryPort>,
+}
+
+impl UpdateProfileUseCase {
+    pub fn new(profile_repository: Arc<dyn ProfileRepositoryPort>) -> Self {
+        Self { profile_repository }
+    }
+}
+
+fn clearable(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[async_trait]
+impl ProfileUpdatePort for UpdateProfileUseCase {
+    async fn execute(
+        &self,
+        data: UpdateProfile,
+        auth: AuthContext,
+    ) -> Result<ProfileRetrieved, SystemError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())?;
+
+        let mut profile = self
+            .profile_repository
+            .find_by_account_id(&account_id)
+            .await?
+            .unwrap_or_else(|| Profile::create_default(account_id));
+
+        if let Some(display_name) = data.display_name {
+            let display_name = clearable(display_name).map(DisplayName::new).transpose()?;
+            profile.change_display_name(display_name);
+        }
+
+        if let Some(bio) = data.bio {
+            let bio = clearable(bio).map(Bio::new).transpose()?;
+            profile.change_bio(bio);
+        }
+
+        if let Some(avatar) = data.avatar {
+            let avatar = clearable(avatar).map(AvatarRef::new).transpose()?;
+            profile.change_avatar(avatar);
+        }
+
+        if let Some(locale) = data.locale {
+            profile.change_locale(Locale::new(locale)?);
+        }
+
+        if let Some(timezone) = data.timezone {
+            profile.change_timezone(Timezone::new(timezone)?);
+        }
+
+        if let Some(visibility) = data.visibility {
+            profile.change_visibility(ProfileVisibility::parse(&visibility)?);
+        }
+
+        self.profile_repository.save(&profile).await?;
+
+        Ok(ProfileRetrieved::from(&profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::update_profile::UpdateProfile,
+            errors::error_codes::IAM_PROFILE_REPOSITORY_ERROR,
+            ports::{
+                inbound::profile_update::ProfileUpdatePort,
+                outbound::profile_repository::test_utils::FakeProfileRepository,
+            },
+            use_cases::update_profile::UpdateProfileUseCase,
+        },
+        domain::{
+            aggregates::Profile,
+            errors::error_codes::{IAM_INVALID_AVATAR, IAM_INVALID_PROFILE_VISIBILITY},
+            value_objects::{AccountId, Bio, DisplayName},
+        },
+    };
+    use shared::application::auth_context::AuthContext;
+    use std::sync::Arc;
+
+    fn empty_update() -> UpdateProfile {
+        UpdateProfile {
+            display_name: None,
+            bio: None,
+            avatar: None,
+            locale: None,
+            timezone: None,
+            visibility: None,
+        }
+    }
+
+    fn auth_for(account_id: &AccountId) -> AuthContext {
+        AuthContext {
+            account_id: account_id.as_uuid().to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_when_avatar_is_invalid() {
+        let repo = Arc::new(FakeProfileRepository::success());
+
+        let use_case = UpdateProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(
+                UpdateProfile {
+                    avatar: Some("http://cdn.example.com/a.png".to_string()),
+                    ..empty_update()
+                },
+                auth_for(&AccountId::generate()),
+            )
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_INVALID_AVATAR);
+    }
+
+    #[tokio::test]
+    async fn fails_when_visibility_is_unknown() {
+        let repo = Arc::new(FakeProfileRepository::success());
+
+        let use_case = UpdateProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(
+                UpdateProfile {
+                    visibility: Some("friends".to_string()),
+                    ..empty_update()
+                },
+                auth_for(&AccountId::generate()),
+            )
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_INVALID_PROFILE_VISIBILITY);
+    }
+
+    #[tokio::test]
+    async fn fails_when_repository_fails() {
+        let repo = Arc::new(FakeProfileRepository::fail());
+
+        let use_case = UpdateProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(empty_update(), auth_for(&AccountId::generate()))
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_PROFILE_REPOSITORY_ERROR);
+    }
+
+    #[tokio::test]
+    async fn does_not_save_when_the_stored_profile_cannot_be_read() {
+        let repo = Arc::new(FakeProfileRepository::unreadable());
+
+        let use_case = UpdateProfileUseCase::new(repo.clone());
+
+        let result = use_case
+            .execute(empty_update(), auth_for(&AccountId::generate()))
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_PROFILE_REPOSITORY_ERROR);
+        assert!(!repo.saved());
+    }
+
+    #[tokio::test]
+    async fn updates_only_provided_fields() {
+        let account_id = AccountId::generate();
+        let mut profile = Profile::create_default(account_id.clone());
+        profile.change_bio(Some(Bio::new("Existing bio").unwrap()));
+        let repo = Arc::new(FakeProfileRepository::with_existing(profile));
+
+        let use_case = UpdateProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(
+                UpdateProfile {
+                    display_name: Some("Dummy".to_string()),
+                    locale: Some("es_ar".to_string()),
+                    timezone: Some("America/Argentina/Buenos_Aires".to_string()),
+                    visibility: Some("members".to_string()),
+                    ..empty_update()
+                },
+                auth_for(&account_id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.display_name.as_deref(), Some("Dummy"));
+        assert_eq!(result.bio.as_deref(), Some("Existing bio"));
+        assert_eq!(result.locale, "es-AR");
+        assert_eq!(result.timezone, "America/Argentina/Buenos_Aires");
+        assert_eq!(result.visibility, "members");
+    }
+
+    #[tokio::test]
+    async fn clears_optional_fields_with_empty_values() {
+        let account_id = AccountId::generate();
+        let mut profile = Profile::create_default(account_id.clone());
+        profile.change_display_name(Some(DisplayName::new("Dummy").unwrap()));
+        let repo = Arc::new(FakeProfileRepository::with_existing(profile));
+
+        let use_case = UpdateProfileUseCase::new(repo);
+
+        let result = use_case
+            .execute(
+                UpdateProfile {
+                    display_name: Some("".to_string()),
+                    ..empty_update()
+                },
+                auth_for(&account_id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.display_name, None);
+    }
+}
@@ -3,35 +3,81 @@ use crate::{
         commands::verify_account::VerifyAccount,
         ports::{
             inbound::account_verification::AccountVerificationPort,
-            outbound::account_repository::AccountRepositoryPort,
+            outbound::{account_repository::AccountRepositoryPort, audit_log::AuditLogPort},
         },
     },
-    domain::{errors::AccountError, value_objects::CodeValidation},
+    domain::{
+        aggregates::AuditEvent,
+        errors::AccountError,
+        value_objects::{AuditEventType, AuditOrigin, CodeValidation},
+    },
+};
+use async_trait::async_trait;
+use shared::{
+    application::{ports::clock::ClockPort, request_context::RequestContext},
+    error::SystemError,
 };
-use shared::error::SystemError;
 use std::sync::Arc;
 
 pub struct VerifyAccountUseCase {
     account_repository: Arc<dyn AccountRepositoryPort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    clock: Arc<dyn ClockPort>,
 }
 
 impl VerifyAccountUseCase {
-    pub fn new(account_repository: Arc<dyn AccountRepositoryPort>) -> Self {
-        Self { account_repository }
+    pub fn new(
+        account_repository: Arc<dyn AccountRepositoryPort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            account_repository,
+            audit_log,
+            clock,
+        }
     }
 }
 
+#[async_trait]
 impl AccountVerificationPort for VerifyAccountUseCase {
-    fn execute(&self, cmd: VerifyAccount) -> Result<bool, SystemError> {
-        let account = self.account_repository.find_by_email(cmd.email.as_str());
+    async fn execute(
+        &self,
+        cmd: VerifyAccount,
+        context: RequestContext,
+    ) -> Result<bool, SystemError> {
+        let account = self
+            .account_repository
+            .find_by_email(cmd.email.as_str())
+            .await?;
         if account.is_none() {
             return Err(AccountError::AccountNotFound.into());
         }
         let mut account = account.unwrap();
         let code_validation = CodeValidation::new(cmd.code)?;
-        account.confirm_registration(code_validation)?;
-
-        self.account_repository.save(&account)?;
+        account.confirm_registration(code_validation, self.clock.now())?;
+
+        self.account_repository.save(&account).await?;
+
+        for event_type in [
+            AuditEventType::AccountVerified,
+            AuditEventType::StatusChanged,
+        ] {
+            let event = AuditEvent::record(
+                event_type,
+                Some(account.id().clone()),
+                Some(account.id().clone()),
+                AuditOrigin::new(context.ip_address.clone(), context.user_agent.clone()),
+                self.clock.now(),
+            );
+            if let Err(err) = self.audit_log.append(&event).await {
+                tracing::error!(
+                    error = %err,
+                    event_type = event_type.as_str(),
+                    "Failed to record audit event"
+                );
+            }
+        }
 
         Ok(true)
     }
@@ -44,61 +90,162 @@ mod tests {
             commands::verify_account::VerifyAccount,
             ports::{
                 inbound::account_verification::AccountVerificationPort,
-                outbound::account_repository::test_utils::FakeAccountRepository,
+                outbound::{
+                    account_repository::test_utils::FakeAccountRepository,
+                    audit_log::test_utils::FakeAuditLog,
+                },
             },
             use_cases::verify_account::VerifyAccountUseCase,
         },
-        domain::errors::error_codes::{IAM_ACCOUNT_INVALID_VERIFICATION, IAM_ACCOUNT_NOT_FOUND},
+        domain::{
+            aggregates::Account,
+            errors::error_codes::{
+                IAM_ACCOUNT_INVALID_VERIFICATION, IAM_ACCOUNT_NOT_FOUND,
+                IAM_ACCOUNT_VERIFICATION_EXPIRED,
+            },
+            value_objects::AuditEventType,
+        },
     };
-    use std::sync::Arc;
+    use shared::{application::request_context::RequestContext, infrastructure::clock::FixedClock};
+    use std::{sync::Arc, time::UNIX_EPOCH};
 
-    #[test]
-    fn verify_account_successfully() {
+    #[tokio::test]
+    async fn verify_account_successfully() {
         let repo = Arc::new(FakeAccountRepository::with_existing_email(
             "dummy@example.com",
         ));
 
-        let use_case = VerifyAccountUseCase::new(repo);
+        let audit_log = Arc::new(FakeAuditLog::success());
 
-        let result = use_case.execute(VerifyAccount {
-            email: "dummy@example.com".to_string(),
-            code: 123123,
-        });
+        let use_case = VerifyAccountUseCase::new(
+            repo,
+            audit_log.clone(),
+            Arc::new(FixedClock::at_unix_seconds(60)),
+        );
+
+        let result = use_case
+            .execute(
+                VerifyAccount {
+                    email: "dummy@example.com".to_string(),
+                    code: 123123,
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         assert!(result.is_ok());
+        let recorded = audit_log
+            .recorded()
+            .iter()
+            .map(|event| event.event_type())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recorded,
+            [
+                AuditEventType::AccountVerified,
+                AuditEventType::StatusChanged
+            ]
+        );
     }
 
-    #[test]
-    fn fails_when_email_not_found() {
-        let repo = Arc::new(FakeAccountRepository::success());
+    #[tokio::test]
+    async fn verifies_even_when_the_audit_log_fails() {
+        let repo = Arc::new(FakeAccountRepository::with_existing_email(
+            "dummy@example.com",
+        ));
+
+        let use_case = VerifyAccountUseCase::new(
+            repo,
+            Arc::new(FakeAuditLog::fail()),
+            Arc::new(FixedClock::at_unix_seconds(60)),
+        );
+
+        let result = use_case
+            .execute(
+                VerifyAccount {
+                    email: "dummy@example.com".to_string(),
+                    code: 123123,
+                },
+                RequestContext::default(),
+            )
+            .await;
+
+        assert!(result.unwrap());
+    }
 
-        let use_case = VerifyAccountUseCase::new(repo);
+    #[tokio::test]
+    async fn fails_when_email_not_found() {
+        let repo = Arc::new(FakeAccountRepository::success());
 
-        let result = use_case.execute(VerifyAccount {
-            email: "not-exists@example.com".to_string(),
-            code: 123123,
-        });
+        let use_case = VerifyAccountUseCase::new(
+            repo,
+            Arc::new(FakeAuditLog::success()),
+            Arc::new(FixedClock::at_unix_seconds(60)),
+        );
+
+        let result = use_case
+            .execute(
+                VerifyAccount {
+                    email: "not-exists@example.com".to_string(),
+                    code: 123123,
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_NOT_FOUND);
     }
 
-    #[test]
-    fn fails_when_code_not_match() {
+    #[tokio::test]
+    async fn fails_when_code_not_match() {
         let repo = Arc::new(FakeAccountRepository::with_existing_email(
             "dummy@example.com",
         ));
 
-        let use_case = VerifyAccountUseCase::new(repo);
-
-        let result = use_case.execute(VerifyAccount {
-            email: "dummy@example.com".to_string(),
-            code: 123111,
-        });
+        let use_case = VerifyAccountUseCase::new(
+            repo,
+            Arc::new(FakeAuditLog::success()),
+            Arc::new(FixedClock::at_unix_seconds(60)),
+        );
+
+        let result = use_case
+            .execute(
+                VerifyAccount {
+                    email: "dummy@example.com".to_string(),
+                    code: 123111,
+                },
+                RequestContext::default(),
+            )
+            .await;
 
         let err = result.expect_err("Expected error");
 
         assert_eq!(err.code(), IAM_ACCOUNT_INVALID_VERIFICATION);
     }
+
+    #[tokio::test]
+    async fn fails_when_code_has_expired() {
+        let repo = Arc::new(FakeAccountRepository::with_existing_email(
+            "dummy@example.com",
+        ));
+        let clock = Arc::new(FixedClock::new(UNIX_EPOCH + Account::VERIFICATION_CODE_TTL));
+
+        let use_case = VerifyAccountUseCase::new(repo, Arc::new(FakeAuditLog::success()), clock);
+
+        let result = use_case
+            .execute(
+                VerifyAccount {
+                    email: "dummy@example.com".to_string(),
+                    code: 123123,
+                },
+                RequestContext::default(),
+            )
+            .await;
+
+        let err = result.expect_err("Expected error");
+
+        assert_eq!(err.code(), IAM_ACCOUNT_VERIFICATION_EXPIRED);
+    }
 }
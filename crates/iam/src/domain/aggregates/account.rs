@@ -1,6 +1,15 @@
 use crate::domain::{
     errors::{AccountError, AccountStatusTransitionError},
-    value_objects::{AccountId, AccountStatus, CodeValidation, Email, HashedPassword, Username},
+    events::{AccountRegistered, AccountVerified},
+    value_objects::{
+        AccountId, AccountStatus, AccountTimestamps, CodeValidation, Email, HashedPassword,
+        PlatformRole, Username,
+    },
+};
+use shared::domain::events::{DomainEvent, DomainEvents};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
 
 #[derive(Debug, Clone)]
@@ -10,15 +19,30 @@ pub struct Account {
     email: Email,
     password: HashedPassword,
     status: AccountStatus,
+    roles: Vec<PlatformRole>,
+    timestamps: AccountTimestamps,
+    version: u64,
+    events: DomainEvents,
 }
 
 impl Account {
+    pub const VERIFICATION_CODE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
     pub fn register(
         id: AccountId,
         username: Username,
         email: Email,
         password: HashedPassword,
+        now: SystemTime,
     ) -> Self {
+        let mut events = DomainEvents::new();
+        events.record(AccountRegistered {
+            account_id: id.clone(),
+            username: username.as_str().to_owned(),
+            email: email.as_str().to_owned(),
+            occurred_at: now,
+        });
+
         Self {
             id,
             username,
@@ -27,15 +51,23 @@ impl Account {
             status: AccountStatus::Registered {
                 code_validation: CodeValidation::generate(),
             },
+            roles: Vec::new(),
+            timestamps: AccountTimestamps::new(now),
+            version: 0,
+            events,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn reconstitute(
         id: AccountId,
         username: Username,
         email: Email,
         password: HashedPassword,
         status: AccountStatus,
+        roles: Vec<PlatformRole>,
+        timestamps: AccountTimestamps,
+        version: u64,
     ) -> Self {
         Self {
             id,
@@ -43,6 +75,10 @@ impl Account {
             email,
             password,
             status,
+            roles,
+            timestamps,
+            version,
+            events: DomainEvents::new(),
         }
     }
 
@@ -66,10 +102,79 @@ impl Account {
         &self.status
     }
 
+    pub fn timestamps(&self) -> &AccountTimestamps {
+        &self.timestamps
+    }
+
+    pub fn created_at(&self) -> SystemTime {
+        self.timestamps.created_at()
+    }
+
+    pub fn verified_at(&self) -> Option<SystemTime> {
+        self.timestamps.verified_at()
+    }
+
+    pub fn last_login_at(&self) -> Option<SystemTime> {
+        self.timestamps.last_login_at()
+    }
+
+    pub fn status_changed_at(&self) -> SystemTime {
+        self.timestamps.status_changed_at()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub(crate) fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
+    pub fn pending_events(&self) -> &[Arc<dyn DomainEvent>] {
+        self.events.pending()
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn DomainEvent>> {
+        self.events.take()
+    }
+
+    pub fn roles(&self) -> &[PlatformRole] {
+        &self.roles
+    }
+
+    pub fn has_role(&self, role: PlatformRole) -> bool {
+        self.roles.contains(&role)
+    }
+
+    pub fn is_verified(&self) -> bool {
+        !matches!(self.status, AccountStatus::Registered { .. })
+    }
+
     pub fn can_authenticate(&self) -> bool {
         self.status.can_authenticate()
     }
 
+    pub fn is_verification_expired(&self, now: SystemTime) -> bool {
+        matches!(self.status, AccountStatus::Registered { .. })
+            && now
+                .duration_since(self.timestamps.status_changed_at())
+                .is_ok_and(|elapsed| elapsed >= Self::VERIFICATION_CODE_TTL)
+    }
+
+    pub fn record_login(&mut self, now: SystemTime) {
+        self.timestamps = self.timestamps.with_last_login_at(now);
+    }
+
+    pub fn grant_role(&mut self, role: PlatformRole) {
+        if !self.has_role(role) {
+            self.roles.push(role);
+        }
+    }
+
+    pub fn revoke_role(&mut self, role: PlatformRole) {
+        self.roles.retain(|r| *r != role);
+    }
+
     pub fn change_password(&mut self, new_password: HashedPassword) {
         self.password = new_password;
     }
@@ -85,35 +190,48 @@ impl Account {
     fn transition_status(
         &mut self,
         next: AccountStatus,
+        now: SystemTime,
     ) -> Result<(), AccountStatusTransitionError> {
         self.status = self.status.transition_to(next)?;
+        self.timestamps = self.timestamps.with_status_changed_at(now);
         Ok(())
     }
 
-    pub fn deactivate(&mut self) -> Result<(), AccountStatusTransitionError> {
-        self.transition_status(AccountStatus::Deactivated)
+    pub fn deactivate(&mut self, now: SystemTime) -> Result<(), AccountStatusTransitionError> {
+        self.transition_status(AccountStatus::Deactivated, now)
     }
 
-    pub fn activate(&mut self) -> Result<(), AccountStatusTransitionError> {
+    pub fn activate(&mut self, now: SystemTime) -> Result<(), AccountStatusTransitionError> {
         if self.status.as_str() == "registered" {
             return Err(AccountStatusTransitionError::Invalid);
         }
-        self.transition_status(AccountStatus::Active)
+        self.transition_status(AccountStatus::Active, now)
     }
 
-    pub fn suspend(&mut self) -> Result<(), AccountStatusTransitionError> {
-        self.transition_status(AccountStatus::Suspended)
+    pub fn suspend(&mut self, now: SystemTime) -> Result<(), AccountStatusTransitionError> {
+        self.transition_status(AccountStatus::Suspended, now)
     }
 
-    pub fn confirm_registration(&mut self, code: CodeValidation) -> Result<(), AccountError> {
+    pub fn confirm_registration(
+        &mut self,
+        code: CodeValidation,
+        now: SystemTime,
+    ) -> Result<(), AccountError> {
         match self.status {
             AccountStatus::Registered { code_validation } => {
+                if self.is_verification_expired(now) {
+                    return Err(AccountError::VerificationExpired);
+                }
                 if code_validation != code {
                     return Err(AccountError::InvalidVerification);
                 }
-                let _ = self
-                    .transition_status(AccountStatus::Active)
-                    .map_err(|_| AccountError::InvalidVerification);
+                self.transition_status(AccountStatus::Active, now)
+                    .map_err(|_| AccountError::InvalidVerification)?;
+                self.timestamps = self.timestamps.with_verified_at(now);
+                self.events.record(AccountVerified {
+                    account_id: self.id.clone(),
+                    occurred_at: now,
+                });
                 Ok(())
             }
             _ => Err(AccountError::InvalidVerification),
@@ -124,6 +242,7 @@ impl Account {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::UNIX_EPOCH;
 
     impl Account {
         pub fn dummy_account() -> Account {
@@ -135,6 +254,9 @@ mod tests {
                 AccountStatus::Registered {
                     code_validation: CodeValidation::new(123123).unwrap(),
                 },
+                Vec::new(),
+                AccountTimestamps::new(UNIX_EPOCH),
+                0,
             )
         }
 
@@ -145,6 +267,9 @@ mod tests {
                 Email::new("dummy@example.com").unwrap(),
                 HashedPassword::dummy(),
                 status,
+                Vec::new(),
+                AccountTimestamps::new(UNIX_EPOCH),
+                0,
             )
         }
     }
@@ -155,6 +280,7 @@ mod tests {
             Username::new("john_doe".to_string()).unwrap(),
             Email::new("john@example.com").unwrap(),
             HashedPassword::dummy(),
+            UNIX_EPOCH,
         )
     }
 
@@ -169,7 +295,7 @@ mod tests {
     fn registered_user_cannot_be_deactivated() {
         let mut user = registered_user();
 
-        let result = user.deactivate();
+        let result = user.deactivate(UNIX_EPOCH);
 
         assert!(result.is_err());
     }
@@ -192,16 +318,19 @@ mod tests {
             Email::new("john@example.com").unwrap(),
             HashedPassword::dummy(),
             AccountStatus::Deleted,
+            Vec::new(),
+            AccountTimestamps::new(UNIX_EPOCH),
+            0,
         );
 
-        assert!(user.deactivate().is_err());
+        assert!(user.deactivate(UNIX_EPOCH).is_err());
     }
 
     #[test]
     fn registered_user_cannot_be_activated_directly() {
         let mut user = registered_user();
 
-        assert!(user.activate().is_err());
+        assert!(user.activate(UNIX_EPOCH).is_err());
     }
 
     #[test]
@@ -217,7 +346,7 @@ mod tests {
 
         for state in states {
             let mut user = Account::dummy_account_with_status(state);
-            let result = user.confirm_registration(code);
+            let result = user.confirm_registration(code, UNIX_EPOCH);
             assert!(result.is_err());
         }
     }
@@ -228,7 +357,7 @@ mod tests {
 
         let code = CodeValidation::new(123123).unwrap();
 
-        assert!(user.confirm_registration(code).is_ok());
+        assert!(user.confirm_registration(code, UNIX_EPOCH).is_ok());
     }
 
     #[test]
@@ -237,14 +366,14 @@ mod tests {
 
         let code = CodeValidation::new(321321).unwrap();
 
-        assert!(user.confirm_registration(code).is_err());
+        assert!(user.confirm_registration(code, UNIX_EPOCH).is_err());
     }
 
     #[test]
     fn registered_user_cannot_be_suspended() {
         let mut user = registered_user();
 
-        assert!(user.suspend().is_err());
+        assert!(user.suspend(UNIX_EPOCH).is_err());
     }
 
     #[test]
@@ -255,8 +384,104 @@ mod tests {
             Email::new("john@example.com").unwrap(),
             HashedPassword::dummy(),
             AccountStatus::Suspended,
+            Vec::new(),
+            AccountTimestamps::new(UNIX_EPOCH),
+            0,
         );
 
         assert_eq!(user.status(), &AccountStatus::Suspended);
     }
+
+    #[test]
+    fn registered_user_is_not_verified() {
+        let user = registered_user();
+
+        assert!(!user.is_verified());
+        assert!(Account::dummy_account_with_status(AccountStatus::Active).is_verified());
+    }
+
+    #[test]
+    fn granting_a_role_twice_keeps_a_single_entry() {
+        let mut user = registered_user();
+
+        user.grant_role(PlatformRole::Admin);
+        user.grant_role(PlatformRole::Admin);
+
+        assert_eq!(user.roles(), &[PlatformRole::Admin]);
+
+        user.revoke_role(PlatformRole::Admin);
+
+        assert!(!user.has_role(PlatformRole::Admin));
+    }
+
+    #[test]
+    fn confirming_registration_records_verification_time() {
+        let mut user = Account::dummy_account();
+        let now = UNIX_EPOCH + Duration::from_secs(60);
+
+        user.confirm_registration(CodeValidation::new(123123).unwrap(), now)
+            .unwrap();
+
+        assert_eq!(user.verified_at(), Some(now));
+        assert_eq!(user.status_changed_at(), now);
+    }
+
+    #[test]
+    fn verification_code_expires_after_ttl() {
+        let mut user = Account::dummy_account();
+        let now = UNIX_EPOCH + Account::VERIFICATION_CODE_TTL;
+
+        let result = user.confirm_registration(CodeValidation::new(123123).unwrap(), now);
+
+        assert!(matches!(result, Err(AccountError::VerificationExpired)));
+        assert!(user.is_verification_expired(now));
+    }
+
+    #[test]
+    fn status_changes_record_their_time() {
+        let mut user = Account::dummy_account_with_status(AccountStatus::Active);
+        let now = UNIX_EPOCH + Duration::from_secs(3_600);
+
+        user.suspend(now).unwrap();
+
+        assert_eq!(user.status_changed_at(), now);
+        assert_eq!(user.created_at(), UNIX_EPOCH);
+    }
+
+    #[test]
+    fn records_last_login() {
+        let mut user = Account::dummy_account_with_status(AccountStatus::Active);
+        let now = UNIX_EPOCH + Duration::from_secs(5);
+
+        user.record_login(now);
+
+        assert_eq!(user.last_login_at(), Some(now));
+    }
+
+    #[test]
+    fn registering_records_account_registered_event() {
+        let mut user = registered_user();
+
+        let events = user.pull_events();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), AccountRegistered::EVENT_TYPE);
+        assert!(user.pending_events().is_empty());
+    }
+
+    #[test]
+    fn confirming_registration_records_account_verified_event() {
+        let mut user = Account::dummy_account();
+
+        user.confirm_registration(CodeValidation::new(123123).unwrap(), UNIX_EPOCH)
+            .unwrap();
+
+        let events = user.pull_events();
+        let event = events[0]
+            .as_any()
+            .downcast_ref::<AccountVerified>()
+            .expect("Expected AccountVerified");
+
+        assert_eq!(&event.account_id, user.id());
+    }
 }
@@ -0,0 +1,74 @@
+use crate::domain::value_objects::{AccountId, AuditEventType, AuditOrigin};
+use std::time::SystemTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    id: Uuid,
+    event_type: AuditEventType,
+    account_id: Option<AccountId>,
+    actor_id: Option<AccountId>,
+    origin: AuditOrigin,
+    occurred_at: SystemTime,
+}
+
+impl AuditEvent {
+    pub fn record(
+        event_type: AuditEventType,
+        account_id: Option<AccountId>,
+        actor_id: Option<AccountId>,
+        origin: AuditOrigin,
+        occurred_at: SystemTime,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            account_id,
+            actor_id,
+            origin,
+            occurred_at,
+        }
+    }
+
+    pub fn reconstitute(
+        id: Uuid,
+        event_type: AuditEventType,
+        account_id: Option<AccountId>,
+        actor_id: Option<AccountId>,
+        origin: AuditOrigin,
+        occurred_at: SystemTime,
+    ) -> Self {
+        Self {
+            id,
+            event_type,
+            account_id,
+            actor_id,
+            origin,
+            occurred_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn event_type(&self) -> AuditEventType {
+        self.event_type
+    }
+
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.account_id.as_ref()
+    }
+
+    pub fn actor_id(&self) -> Option<&AccountId> {
+        self.actor_id.as_ref()
+    }
+
+    pub fn origin(&self) -> &AuditOrigin {
+        &self.origin
+    }
+
+    pub fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+}
@@ -1,3 +1,7 @@
 pub mod account;
+pub mod audit_event;
+pub mod profile;
 
 pub use account::Account;
+pub use audit_event::AuditEvent;
+pub use profile::Profile;
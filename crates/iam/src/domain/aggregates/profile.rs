@@ -0,0 +1,142 @@
+use crate::domain::value_objects::{
+    AccountId, AvatarRef, Bio, DisplayName, Locale, ProfileVisibility, Timezone,
+};
+
+#[derive(Debug, Clone)]
+pub struct Profile {
+    account_id: AccountId,
+    display_name: Option<DisplayName>,
+    bio: Option<Bio>,
+    avatar: Option<AvatarRef>,
+    locale: Locale,
+    timezone: Timezone,
+    visibility: ProfileVisibility,
+}
+
+impl Profile {
+    pub fn create_default(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            display_name: None,
+            bio: None,
+            avatar: None,
+            locale: Locale::default(),
+            timezone: Timezone::default(),
+            visibility: ProfileVisibility::default(),
+        }
+    }
+
+    pub fn reconstitute(
+        account_id: AccountId,
+        display_name: Option<DisplayName>,
+        bio: Option<Bio>,
+        avatar: Option<AvatarRef>,
+        locale: Locale,
+        timezone: Timezone,
+        visibility: ProfileVisibility,
+    ) -> Self {
+        Self {
+            account_id,
+            display_name,
+            bio,
+            avatar,
+            locale,
+            timezone,
+            visibility,
+        }
+    }
+
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    pub fn display_name(&self) -> Option<&DisplayName> {
+        self.display_name.as_ref()
+    }
+
+    pub fn bio(&self) -> Option<&Bio> {
+        self.bio.as_ref()
+    }
+
+    pub fn avatar(&self) -> Option<&AvatarRef> {
+        self.avatar.as_ref()
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn timezone(&self) -> &Timezone {
+        &self.timezone
+    }
+
+    pub fn visibility(&self) -> ProfileVisibility {
+        self.visibility
+    }
+
+    pub fn is_visible_to(&self, viewer: Option<&AccountId>) -> bool {
+        let is_owner = viewer.is_some_and(|viewer| viewer == &self.account_id);
+        self.visibility.is_visible_to(viewer.is_some(), is_owner)
+    }
+
+    pub fn change_display_name(&mut self, display_name: Option<DisplayName>) {
+        self.display_name = display_name;
+    }
+
+    pub fn change_bio(&mut self, bio: Option<Bio>) {
+        self.bio = bio;
+    }
+
+    pub fn change_avatar(&mut self, avatar: Option<AvatarRef>) {
+        self.avatar = avatar;
+    }
+
+    pub fn change_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    pub fn change_timezone(&mut self, timezone: Timezone) {
+        self.timezone = timezone;
+    }
+
+    pub fn change_visibility(&mut self, visibility: ProfileVisibility) {
+        self.visibility = visibility;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_profile_is_public_and_empty() {
+        let profile = Profile::create_default(AccountId::generate());
+
+        assert!(profile.display_name().is_none());
+        assert_eq!(profile.locale().as_str(), "en");
+        assert_eq!(profile.timezone().as_str(), "UTC");
+        assert_eq!(profile.visibility(), ProfileVisibility::Public);
+    }
+
+    #[test]
+    fn private_profile_is_only_visible_to_owner() {
+        let owner = AccountId::generate();
+        let mut profile = Profile::create_default(owner.clone());
+
+        profile.change_visibility(ProfileVisibility::Private);
+
+        assert!(profile.is_visible_to(Some(&owner)));
+        assert!(!profile.is_visible_to(Some(&AccountId::generate())));
+        assert!(!profile.is_visible_to(None));
+    }
+
+    #[test]
+    fn members_profile_is_hidden_from_anonymous_visitors() {
+        let mut profile = Profile::create_default(AccountId::generate());
+
+        profile.change_visibility(ProfileVisibility::Members);
+
+        assert!(profile.is_visible_to(Some(&AccountId::generate())));
+        assert!(!profile.is_visible_to(None));
+    }
+}
@@ -1,6 +1,6 @@
 use super::error_codes::{
     IAM_ACCOUNT_EMAIL_ALREADY_EXISTS, IAM_ACCOUNT_INVALID_VERIFICATION, IAM_ACCOUNT_NOT_FOUND,
-    IAM_ACCOUNT_USERNAME_ALREADY_EXISTS,
+    IAM_ACCOUNT_USERNAME_ALREADY_EXISTS, IAM_ACCOUNT_VERIFICATION_EXPIRED,
 };
 use shared::error::{ErrorCategory, LayerError};
 use std::fmt;
@@ -11,6 +11,7 @@ pub enum AccountError {
     EmailAlreadyExists,
     UsernameAlreadyExists,
     InvalidVerification,
+    VerificationExpired,
 }
 
 impl fmt::Display for AccountError {
@@ -20,6 +21,7 @@ impl fmt::Display for AccountError {
             AccountError::EmailAlreadyExists => write!(f, "This email is already in use"),
             AccountError::UsernameAlreadyExists => write!(f, "This username is already in use"),
             AccountError::InvalidVerification => write!(f, "Verification process is invalid"),
+            AccountError::VerificationExpired => write!(f, "Verification code has expired"),
         }
     }
 }
@@ -37,6 +39,7 @@ impl LayerError for AccountError {
             AccountError::EmailAlreadyExists => IAM_ACCOUNT_EMAIL_ALREADY_EXISTS,
             AccountError::UsernameAlreadyExists => IAM_ACCOUNT_USERNAME_ALREADY_EXISTS,
             AccountError::InvalidVerification => IAM_ACCOUNT_INVALID_VERIFICATION,
+            AccountError::VerificationExpired => IAM_ACCOUNT_VERIFICATION_EXPIRED,
         }
     }
 
@@ -46,6 +49,7 @@ impl LayerError for AccountError {
             AccountError::EmailAlreadyExists => "This email is already in use.",
             AccountError::UsernameAlreadyExists => "This username is already in use.",
             AccountError::InvalidVerification => "Verification process is invalid.",
+            AccountError::VerificationExpired => "This verification code has expired.",
         }
     }
 }
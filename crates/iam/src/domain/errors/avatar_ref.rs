@@ -0,0 +1,36 @@
+use super::error_codes::IAM_INVALID_AVATAR;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AvatarRefError {
+    Invalid,
+}
+
+impl fmt::Display for AvatarRefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AvatarRefError::Invalid => write!(f, "Please provide a valid avatar URL"),
+        }
+    }
+}
+
+impl std::error::Error for AvatarRefError {}
+
+impl LayerError for AvatarRefError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            AvatarRefError::Invalid => IAM_INVALID_AVATAR,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            AvatarRefError::Invalid => "Please provide a valid avatar URL.",
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::error_codes::IAM_INVALID_BIO;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BioError {
+    Invalid,
+}
+
+impl fmt::Display for BioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BioError::Invalid => write!(f, "The bio is too long or contains invalid characters"),
+        }
+    }
+}
+
+impl std::error::Error for BioError {}
+
+impl LayerError for BioError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            BioError::Invalid => IAM_INVALID_BIO,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            BioError::Invalid => "The bio is too long or contains invalid characters.",
+        }
+    }
+}
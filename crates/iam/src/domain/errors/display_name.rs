@@ -0,0 +1,36 @@
+use super::error_codes::IAM_INVALID_DISPLAY_NAME;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DisplayNameError {
+    Invalid,
+}
+
+impl fmt::Display for DisplayNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplayNameError::Invalid => write!(f, "Please enter a valid display name"),
+        }
+    }
+}
+
+impl std::error::Error for DisplayNameError {}
+
+impl LayerError for DisplayNameError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            DisplayNameError::Invalid => IAM_INVALID_DISPLAY_NAME,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            DisplayNameError::Invalid => "Please enter a valid display name.",
+        }
+    }
+}
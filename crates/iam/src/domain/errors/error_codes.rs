@@ -9,3 +9,11 @@ pub const IAM_ACCOUNT_EMAIL_ALREADY_EXISTS: &str = "IAM_ACCOUNT_EMAIL_ALREADY_EX
 pub const IAM_ACCOUNT_USERNAME_ALREADY_EXISTS: &str = "IAM_ACCOUNT_USERNAME_ALREADY_EXISTS";
 pub const IAM_ACCOUNT_NOT_FOUND: &str = "IAM_ACCOUNT_NOT_FOUND";
 pub const IAM_ACCOUNT_INVALID_VERIFICATION: &str = "IAM_ACCOUNT_INVALID_VERIFICATION";
+pub const IAM_INVALID_DISPLAY_NAME: &str = "IAM_INVALID_DISPLAY_NAME";
+pub const IAM_INVALID_BIO: &str = "IAM_INVALID_BIO";
+pub const IAM_INVALID_AVATAR: &str = "IAM_INVALID_AVATAR";
+pub const IAM_INVALID_LOCALE: &str = "IAM_INVALID_LOCALE";
+pub const IAM_INVALID_TIMEZONE: &str = "IAM_INVALID_TIMEZONE";
+pub const IAM_INVALID_PROFILE_VISIBILITY: &str = "IAM_INVALID_PROFILE_VISIBILITY";
+pub const IAM_INVALID_PLATFORM_ROLE: &str = "IAM_INVALID_PLATFORM_ROLE";
+pub const IAM_ACCOUNT_VERIFICATION_EXPIRED: &str = "IAM_ACCOUNT_VERIFICATION_EXPIRED";
@@ -0,0 +1,36 @@
+use super::error_codes::IAM_INVALID_LOCALE;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LocaleError {
+    Invalid,
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocaleError::Invalid => write!(f, "Please provide a valid locale"),
+        }
+    }
+}
+
+impl std::error::Error for LocaleError {}
+
+impl LayerError for LocaleError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            LocaleError::Invalid => IAM_INVALID_LOCALE,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            LocaleError::Invalid => "Please provide a valid locale.",
+        }
+    }
+}
@@ -1,16 +1,30 @@
 pub mod account;
 pub mod account_id;
 pub mod account_status_transition;
+pub mod avatar_ref;
+pub mod bio;
 pub mod code_validation;
+pub mod display_name;
 pub mod email;
 pub mod error_codes;
 pub mod hashed_password;
+pub mod locale;
+pub mod platform_role;
+pub mod profile_visibility;
+pub mod timezone;
 pub mod username;
 
 pub use account::AccountError;
 pub use account_id::AccountIdError;
 pub use account_status_transition::AccountStatusTransitionError;
+pub use avatar_ref::AvatarRefError;
+pub use bio::BioError;
 pub use code_validation::CodeValidationError;
+pub use display_name::DisplayNameError;
 pub use email::EmailError;
 pub use hashed_password::HashedPasswordError;
+pub use locale::LocaleError;
+pub use platform_role::PlatformRoleError;
+pub use profile_visibility::ProfileVisibilityError;
+pub use timezone::TimezoneError;
 pub use username::UsernameError;
@@ -0,0 +1,38 @@
+use super::error_codes::IAM_INVALID_PLATFORM_ROLE;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PlatformRoleError {
+    Invalid,
+}
+
+impl fmt::Display for PlatformRoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlatformRoleError::Invalid => {
+                write!(f, "Please choose a valid platform role")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlatformRoleError {}
+
+impl LayerError for PlatformRoleError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            PlatformRoleError::Invalid => IAM_INVALID_PLATFORM_ROLE,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            PlatformRoleError::Invalid => "Please choose a valid platform role.",
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use super::error_codes::IAM_INVALID_PROFILE_VISIBILITY;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProfileVisibilityError {
+    Invalid,
+}
+
+impl fmt::Display for ProfileVisibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileVisibilityError::Invalid => {
+                write!(f, "Please choose a valid profile visibility")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfileVisibilityError {}
+
+impl LayerError for ProfileVisibilityError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            ProfileVisibilityError::Invalid => IAM_INVALID_PROFILE_VISIBILITY,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            ProfileVisibilityError::Invalid => "Please choose a valid profile visibility.",
+        }
+    }
+}
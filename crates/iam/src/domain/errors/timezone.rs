@@ -0,0 +1,36 @@
+use super::error_codes::IAM_INVALID_TIMEZONE;
+use shared::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TimezoneError {
+    Invalid,
+}
+
+impl fmt::Display for TimezoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimezoneError::Invalid => write!(f, "Please provide a valid timezone"),
+        }
+    }
+}
+
+impl std::error::Error for TimezoneError {}
+
+impl LayerError for TimezoneError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Domain
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            TimezoneError::Invalid => IAM_INVALID_TIMEZONE,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            TimezoneError::Invalid => "Please provide a valid timezone.",
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::domain::value_objects::AccountId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountRegistered {
+    pub account_id: AccountId,
+    pub username: String,
+    pub email: String,
+    pub occurred_at: SystemTime,
+}
+
+impl AccountRegistered {
+    pub const EVENT_TYPE: &'static str = "iam.account_registered";
+}
+
+impl DomainEvent for AccountRegistered {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.account_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "account_id": self.aggregate_id(),
+            "username": self.username,
+            "email": self.email,
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
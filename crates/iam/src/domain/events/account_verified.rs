@@ -0,0 +1,37 @@
+use crate::domain::value_objects::AccountId;
+use shared::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountVerified {
+    pub account_id: AccountId,
+    pub occurred_at: SystemTime,
+}
+
+impl AccountVerified {
+    pub const EVENT_TYPE: &'static str = "iam.account_verified";
+}
+
+impl DomainEvent for AccountVerified {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.account_id.as_uuid().to_string()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "account_id": self.aggregate_id(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
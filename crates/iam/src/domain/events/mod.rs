@@ -0,0 +1,5 @@
+pub mod account_registered;
+pub mod account_verified;
+
+pub use account_registered::AccountRegistered;
+pub use account_verified::AccountVerified;
@@ -1,3 +1,4 @@
 pub mod aggregates;
 pub mod errors;
+pub mod events;
 pub mod value_objects;
@@ -0,0 +1,88 @@
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountTimestamps {
+    created_at: SystemTime,
+    verified_at: Option<SystemTime>,
+    last_login_at: Option<SystemTime>,
+    status_changed_at: SystemTime,
+}
+
+impl AccountTimestamps {
+    pub fn new(created_at: SystemTime) -> Self {
+        Self {
+            created_at,
+            verified_at: None,
+            last_login_at: None,
+            status_changed_at: created_at,
+        }
+    }
+
+    pub fn reconstitute(
+        created_at: SystemTime,
+        verified_at: Option<SystemTime>,
+        last_login_at: Option<SystemTime>,
+        status_changed_at: SystemTime,
+    ) -> Self {
+        Self {
+            created_at,
+            verified_at,
+            last_login_at,
+            status_changed_at,
+        }
+    }
+
+    pub fn created_at(&self) -> SystemTime {
+        self.created_at
+    }
+
+    pub fn verified_at(&self) -> Option<SystemTime> {
+        self.verified_at
+    }
+
+    pub fn last_login_at(&self) -> Option<SystemTime> {
+        self.last_login_at
+    }
+
+    pub fn status_changed_at(&self) -> SystemTime {
+        self.status_changed_at
+    }
+
+    pub fn with_verified_at(self, at: SystemTime) -> Self {
+        Self {
+            verified_at: Some(at),
+            ..self
+        }
+    }
+
+    pub fn with_last_login_at(self, at: SystemTime) -> Self {
+        Self {
+            last_login_at: Some(at),
+            ..self
+        }
+    }
+
+    pub fn with_status_changed_at(self, at: SystemTime) -> Self {
+        Self {
+            status_changed_at: at,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn new_timestamps_start_with_status_changed_at_creation() {
+        let created_at = UNIX_EPOCH + Duration::from_secs(10);
+
+        let timestamps = AccountTimestamps::new(created_at);
+
+        assert_eq!(timestamps.status_changed_at(), created_at);
+        assert_eq!(timestamps.verified_at(), None);
+        assert_eq!(timestamps.last_login_at(), None);
+    }
+}
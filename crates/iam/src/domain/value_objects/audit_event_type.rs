@@ -0,0 +1,59 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditEventType {
+    SignInSucceeded,
+    SignInFailed,
+    AccountVerified,
+    PasswordChanged,
+    EmailChanged,
+    StatusChanged,
+    TokenRevoked,
+}
+
+impl AuditEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEventType::SignInSucceeded => "sign_in_succeeded",
+            AuditEventType::SignInFailed => "sign_in_failed",
+            AuditEventType::AccountVerified => "account_verified",
+            AuditEventType::PasswordChanged => "password_changed",
+            AuditEventType::EmailChanged => "email_changed",
+            AuditEventType::StatusChanged => "status_changed",
+            AuditEventType::TokenRevoked => "token_revoked",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "sign_in_succeeded" => Some(AuditEventType::SignInSucceeded),
+            "sign_in_failed" => Some(AuditEventType::SignInFailed),
+            "account_verified" => Some(AuditEventType::AccountVerified),
+            "password_changed" => Some(AuditEventType::PasswordChanged),
+            "email_changed" => Some(AuditEventType::EmailChanged),
+            "status_changed" => Some(AuditEventType::StatusChanged),
+            "token_revoked" => Some(AuditEventType::TokenRevoked),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_its_string_form() {
+        let types = [
+            AuditEventType::SignInSucceeded,
+            AuditEventType::SignInFailed,
+            AuditEventType::AccountVerified,
+            AuditEventType::PasswordChanged,
+            AuditEventType::EmailChanged,
+            AuditEventType::StatusChanged,
+            AuditEventType::TokenRevoked,
+        ];
+
+        for event_type in types {
+            assert_eq!(AuditEventType::parse(event_type.as_str()), Some(event_type));
+        }
+    }
+}
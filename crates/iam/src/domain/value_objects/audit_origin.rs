@@ -0,0 +1,25 @@
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditOrigin {
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl AuditOrigin {
+    const MAX_USER_AGENT_LENGTH: usize = 512;
+
+    pub fn new(ip_address: Option<String>, user_agent: Option<String>) -> Self {
+        Self {
+            ip_address,
+            user_agent: user_agent
+                .map(|value| value.chars().take(Self::MAX_USER_AGENT_LENGTH).collect()),
+        }
+    }
+
+    pub fn ip_address(&self) -> Option<&str> {
+        self.ip_address.as_deref()
+    }
+
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+}
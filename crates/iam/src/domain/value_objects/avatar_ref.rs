@@ -0,0 +1,76 @@
+use crate::domain::errors::AvatarRefError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AvatarRef(String);
+
+impl AvatarRef {
+    const MAX_LENGTH: usize = 512;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, AvatarRefError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed.len() > Self::MAX_LENGTH {
+            return Err(AvatarRefError::Invalid);
+        }
+
+        let Some(rest) = trimmed.strip_prefix("https://") else {
+            return Err(AvatarRefError::Invalid);
+        };
+
+        let host = rest.split('/').next().unwrap_or_default();
+        if host.is_empty() || !host.contains('.') {
+            return Err(AvatarRefError::Invalid);
+        }
+
+        if trimmed.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(AvatarRefError::Invalid);
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_valid_avatar_ref() {
+        let avatar = AvatarRef::new("https://cdn.example.com/avatars/john.png").unwrap();
+
+        assert_eq!(avatar.as_str(), "https://cdn.example.com/avatars/john.png");
+    }
+
+    #[test]
+    fn rejects_non_https_urls() {
+        assert_eq!(
+            AvatarRef::new("http://cdn.example.com/john.png"),
+            Err(AvatarRefError::Invalid)
+        );
+        assert_eq!(
+            AvatarRef::new("javascript:alert(1)"),
+            Err(AvatarRefError::Invalid)
+        );
+    }
+
+    #[test]
+    fn rejects_missing_host() {
+        assert_eq!(
+            AvatarRef::new("https:///john.png"),
+            Err(AvatarRefError::Invalid)
+        );
+    }
+
+    #[test]
+    fn rejects_whitespace() {
+        assert_eq!(
+            AvatarRef::new("https://cdn.example.com/john doe.png"),
+            Err(AvatarRefError::Invalid)
+        );
+    }
+}
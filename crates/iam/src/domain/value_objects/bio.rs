@@ -0,0 +1,58 @@
+use crate::domain::errors::BioError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bio(String);
+
+impl Bio {
+    const MAX_LENGTH: usize = 280;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, BioError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err(BioError::Invalid);
+        }
+
+        if trimmed.chars().count() > Self::MAX_LENGTH {
+            return Err(BioError::Invalid);
+        }
+
+        if trimmed.chars().any(|c| c.is_control() && c != '\n') {
+            return Err(BioError::Invalid);
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_multiline_bio() {
+        let bio = Bio::new("Rustacean.\nLikes climbing.").unwrap();
+
+        assert_eq!(bio.as_str(), "Rustacean.\nLikes climbing.");
+    }
+
+    #[test]
+    fn rejects_empty_bio() {
+        assert_eq!(Bio::new(""), Err(BioError::Invalid));
+    }
+
+    #[test]
+    fn rejects_too_long_bio() {
+        assert_eq!(Bio::new("a".repeat(281)), Err(BioError::Invalid));
+    }
+
+    #[test]
+    fn rejects_control_characters_other_than_newline() {
+        assert_eq!(Bio::new("tab\there"), Err(BioError::Invalid));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::errors::DisplayNameError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayName(String);
+
+impl DisplayName {
+    const MAX_LENGTH: usize = 50;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, DisplayNameError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err(DisplayNameError::Invalid);
+        }
+
+        if trimmed.chars().count() > Self::MAX_LENGTH {
+            return Err(DisplayNameError::Invalid);
+        }
+
+        if trimmed.chars().any(char::is_control) {
+            return Err(DisplayNameError::Invalid);
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_valid_display_name() {
+        let display_name = DisplayName::new("  John Doe  ").unwrap();
+
+        assert_eq!(display_name.as_str(), "John Doe");
+    }
+
+    #[test]
+    fn rejects_empty_display_name() {
+        assert_eq!(DisplayName::new("   "), Err(DisplayNameError::Invalid));
+    }
+
+    #[test]
+    fn rejects_too_long_display_name() {
+        assert_eq!(
+            DisplayName::new("a".repeat(51)),
+            Err(DisplayNameError::Invalid)
+        );
+    }
+
+    #[test]
+    fn rejects_control_characters() {
+        assert_eq!(
+            DisplayName::new("John\nDoe"),
+            Err(DisplayNameError::Invalid)
+        );
+    }
+}
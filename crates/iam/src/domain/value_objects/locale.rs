@@ -0,0 +1,68 @@
+use crate::domain::errors::LocaleError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(String);
+
+impl Locale {
+    pub fn new(value: impl Into<String>) -> Result<Self, LocaleError> {
+        let value = value.into().trim().replace('_', "-");
+        let mut parts = value.split('-');
+
+        let language = parts.next().unwrap_or_default();
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(LocaleError::Invalid);
+        }
+
+        let region = parts.next();
+        if let Some(region) = region
+            && (region.len() != 2 || !region.chars().all(|c| c.is_ascii_alphabetic()))
+        {
+            return Err(LocaleError::Invalid);
+        }
+
+        if parts.next().is_some() {
+            return Err(LocaleError::Invalid);
+        }
+
+        let normalized = match region {
+            Some(region) => format!(
+                "{}-{}",
+                language.to_ascii_lowercase(),
+                region.to_ascii_uppercase()
+            ),
+            None => language.to_ascii_lowercase(),
+        };
+
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self("en".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_language_and_region() {
+        assert_eq!(Locale::new("ES_ar").unwrap().as_str(), "es-AR");
+        assert_eq!(Locale::new("PT").unwrap().as_str(), "pt");
+    }
+
+    #[test]
+    fn rejects_malformed_locale() {
+        assert_eq!(Locale::new(""), Err(LocaleError::Invalid));
+        assert_eq!(Locale::new("english"), Err(LocaleError::Invalid));
+        assert_eq!(Locale::new("en-USA"), Err(LocaleError::Invalid));
+        assert_eq!(Locale::new("en-US-x"), Err(LocaleError::Invalid));
+    }
+}
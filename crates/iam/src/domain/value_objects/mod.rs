@@ -1,13 +1,33 @@
 pub mod account_id;
 pub mod account_status;
+pub mod account_timestamps;
+pub mod audit_event_type;
+pub mod audit_origin;
+pub mod avatar_ref;
+pub mod bio;
 pub mod code_validation;
+pub mod display_name;
 pub mod email;
 pub mod hashed_password;
+pub mod locale;
+pub mod platform_role;
+pub mod profile_visibility;
+pub mod timezone;
 pub mod username;
 
 pub use account_id::AccountId;
 pub use account_status::AccountStatus;
+pub use account_timestamps::AccountTimestamps;
+pub use audit_event_type::AuditEventType;
+pub use audit_origin::AuditOrigin;
+pub use avatar_ref::AvatarRef;
+pub use bio::Bio;
 pub use code_validation::CodeValidation;
+pub use display_name::DisplayName;
 pub use email::Email;
 pub use hashed_password::HashedPassword;
+pub use locale::Locale;
+pub use platform_role::PlatformRole;
+pub use profile_visibility::ProfileVisibility;
+pub use timezone::Timezone;
 pub use username::Username;
@@ -0,0 +1,46 @@
+use crate::domain::errors::PlatformRoleError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlatformRole {
+    Admin,
+    Moderator,
+}
+
+impl PlatformRole {
+    pub fn parse(value: &str) -> Result<Self, PlatformRoleError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "admin" => Ok(PlatformRole::Admin),
+            "moderator" => Ok(PlatformRole::Moderator),
+            _ => Err(PlatformRoleError::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlatformRole::Admin => "admin",
+            PlatformRole::Moderator => "moderator",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_roles() {
+        assert_eq!(PlatformRole::parse(" Admin "), Ok(PlatformRole::Admin));
+        assert_eq!(
+            PlatformRole::parse("moderator"),
+            Ok(PlatformRole::Moderator)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_roles() {
+        assert_eq!(
+            PlatformRole::parse("owner"),
+            Err(PlatformRoleError::Invalid)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::errors::ProfileVisibilityError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProfileVisibility {
+    #[default]
+    Public,
+    Members,
+    Private,
+}
+
+impl ProfileVisibility {
+    pub fn parse(value: &str) -> Result<Self, ProfileVisibilityError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "public" => Ok(ProfileVisibility::Public),
+            "members" => Ok(ProfileVisibility::Members),
+            "private" => Ok(ProfileVisibility::Private),
+            _ => Err(ProfileVisibilityError::Invalid),
+        }
+    }
+
+    pub fn is_visible_to(&self, is_authenticated: bool, is_owner: bool) -> bool {
+        match self {
+            ProfileVisibility::Public => true,
+            ProfileVisibility::Members => is_authenticated,
+            ProfileVisibility::Private => is_owner,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileVisibility::Public => "public",
+            ProfileVisibility::Members => "members",
+            ProfileVisibility::Private => "private",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_values() {
+        assert_eq!(
+            ProfileVisibility::parse("Members"),
+            Ok(ProfileVisibility::Members)
+        );
+        assert_eq!(
+            ProfileVisibility::parse("unknown"),
+            Err(ProfileVisibilityError::Invalid)
+        );
+    }
+
+    #[test]
+    fn public_profile_is_visible_to_anonymous_visitors() {
+        assert!(ProfileVisibility::Public.is_visible_to(false, false));
+    }
+
+    #[test]
+    fn members_profile_requires_authentication() {
+        assert!(!ProfileVisibility::Members.is_visible_to(false, false));
+        assert!(ProfileVisibility::Members.is_visible_to(true, false));
+    }
+
+    #[test]
+    fn private_profile_is_only_visible_to_owner() {
+        assert!(!ProfileVisibility::Private.is_visible_to(true, false));
+        assert!(ProfileVisibility::Private.is_visible_to(true, true));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::domain::errors::TimezoneError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timezone(String);
+
+impl Timezone {
+    const MAX_LENGTH: usize = 64;
+    const UTC: &'static str = "UTC";
+
+    pub fn new(value: impl Into<String>) -> Result<Self, TimezoneError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed == Self::UTC {
+            return Ok(Self(trimmed.to_string()));
+        }
+
+        if trimmed.len() > Self::MAX_LENGTH {
+            return Err(TimezoneError::Invalid);
+        }
+
+        let segments: Vec<&str> = trimmed.split('/').collect();
+        if !(2..=3).contains(&segments.len()) {
+            return Err(TimezoneError::Invalid);
+        }
+
+        if !segments
+            .iter()
+            .all(|segment| Self::is_valid_segment(segment))
+        {
+            return Err(TimezoneError::Invalid);
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn is_valid_segment(segment: &str) -> bool {
+        segment
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_uppercase())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    }
+}
+
+impl Default for Timezone {
+    fn default() -> Self {
+        Self(Self::UTC.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_iana_names() {
+        assert!(Timezone::new("UTC").is_ok());
+        assert!(Timezone::new("Europe/Madrid").is_ok());
+        assert!(Timezone::new("America/Argentina/Buenos_Aires").is_ok());
+        assert!(Timezone::new("Etc/GMT+3").is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_timezone() {
+        assert_eq!(Timezone::new(""), Err(TimezoneError::Invalid));
+        assert_eq!(Timezone::new("Madrid"), Err(TimezoneError::Invalid));
+        assert_eq!(Timezone::new("europe/madrid"), Err(TimezoneError::Invalid));
+        assert_eq!(Timezone::new("Europe/Ma drid"), Err(TimezoneError::Invalid));
+    }
+}
@@ -1,9 +1,14 @@
 use crate::domain::errors::UsernameError;
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::skeleton;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Username(String);
 
 impl Username {
+    const MIN_LENGTH: usize = 3;
+    const MAX_LENGTH: usize = 32;
+
     pub fn new(value: String) -> Result<Self, UsernameError> {
         let trimmed = value.trim();
 
@@ -11,21 +16,31 @@ impl Username {
             return Err(UsernameError::Invalid);
         }
 
-        let length = trimmed.len();
-        if length < 3 || length > 32 {
+        let length = trimmed.chars().count();
+        if !(Self::MIN_LENGTH..=Self::MAX_LENGTH).contains(&length) {
             return Err(UsernameError::Invalid);
         }
 
-        if trimmed.contains(' ') {
+        if trimmed.chars().any(|c| c.is_whitespace() || c.is_control()) {
             return Err(UsernameError::Invalid);
         }
 
-        Ok(Self(trimmed.to_string()))
+        Ok(Self(trimmed.nfc().collect()))
     }
 
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    pub fn lookup_key(&self) -> String {
+        Self::lookup_key_of(&self.0)
+    }
+
+    pub fn lookup_key_of(value: &str) -> String {
+        let folded: String = value.trim().nfkc().flat_map(char::to_lowercase).collect();
+
+        skeleton(&folded).collect()
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +85,13 @@ mod tests {
         assert_eq!(result, Err(UsernameError::Invalid));
     }
 
+    #[test]
+    fn counts_length_in_characters() {
+        let result = Username::new("ñandú".to_string());
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn rejects_username_with_spaces() {
         let result = Username::new("john doe".to_string());
@@ -77,6 +99,15 @@ mod tests {
         assert_eq!(result, Err(UsernameError::Invalid));
     }
 
+    #[test]
+    fn rejects_username_with_control_characters() {
+        let result = Username::new("john\u{7}doe".to_string());
+        let result2 = Username::new("john\u{a0}doe".to_string());
+
+        assert_eq!(result, Err(UsernameError::Invalid));
+        assert_eq!(result2, Err(UsernameError::Invalid));
+    }
+
     #[test]
     fn usernames_with_same_value_are_equal() {
         let u1 = Username::new("bob".to_string()).unwrap();
@@ -84,4 +115,31 @@ mod tests {
 
         assert_eq!(u1, u2);
     }
+
+    #[test]
+    fn lookup_key_ignores_case() {
+        let u1 = Username::new("Admin".to_string()).unwrap();
+        let u2 = Username::new("admin".to_string()).unwrap();
+
+        assert_eq!(u1.lookup_key(), u2.lookup_key());
+    }
+
+    #[test]
+    fn lookup_key_folds_confusable_characters() {
+        let latin = Username::new("paypal".to_string()).unwrap();
+        let cyrillic = Username::new("\u{440}\u{430}yp\u{430}l".to_string()).unwrap();
+
+        assert_ne!(latin, cyrillic);
+        assert_eq!(latin.lookup_key(), cyrillic.lookup_key());
+    }
+
+    #[test]
+    fn lookup_key_of_raw_input_matches_username_key() {
+        let username = Username::new("John_Doe".to_string()).unwrap();
+
+        assert_eq!(
+            Username::lookup_key_of("  JOHN_doe "),
+            username.lookup_key()
+        );
+    }
 }
@@ -0,0 +1,279 @@
+use crate::domain::{
+    aggregates::Account,
+    value_objects::{
+        AccountId, AccountStatus, AccountTimestamps, CodeValidation, Email, HashedPassword,
+        Username,
+    },
+};
+use std::time::{Duration, UNIX_EPOCH};
+
+pub fn account(username: &str, email: &str) -> Account {
+    Account::reconstitute(
+        AccountId::generate(),
+        Username::new(username.to_string()).unwrap(),
+        Email::new(email).unwrap(),
+        HashedPassword::dummy(),
+        AccountStatus::Registered {
+            code_validation: CodeValidation::new(123123).unwrap(),
+        },
+        Vec::new(),
+        AccountTimestamps::new(UNIX_EPOCH + Duration::from_secs(1_000)),
+        0,
+    )
+}
+
+macro_rules! account_repository_contract {
+    ($repository:expr, $with_unit_of_work:expr) => {
+        mod contract {
+            use super::*;
+            use crate::{
+                application::{
+                    errors::account_repository::AccountRepositoryError,
+                    ports::outbound::account_repository::AccountRepositoryPort,
+                },
+                domain::value_objects::{AccountStatus, CodeValidation, PlatformRole},
+                infrastructure::persistence::account_repository_contract::account,
+            };
+            use shared::application::{
+                errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWorkPort,
+            };
+            use std::time::{Duration, UNIX_EPOCH};
+
+            #[tokio::test]
+            async fn finds_saved_account_by_id_username_and_email() {
+                let repository = $repository;
+                let saved = account("john_doe", "john@example.com");
+
+                repository.save(&saved).await.unwrap();
+
+                for found in [
+                    repository.find_by_id(saved.id()).await.unwrap(),
+                    repository.find_by_username("john_doe").await.unwrap(),
+                    repository.find_by_email("john@example.com").await.unwrap(),
+                ] {
+                    assert_eq!(found.unwrap().id(), saved.id());
+                }
+            }
+
+            #[tokio::test]
+            async fn returns_none_for_unknown_accounts() {
+                let repository = $repository;
+
+                assert!(
+                    repository
+                        .find_by_username("ghost")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+                assert!(
+                    repository
+                        .find_by_email("ghost@example.com")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+            }
+
+            #[tokio::test]
+            async fn looks_up_username_and_email_case_insensitively() {
+                let repository = $repository;
+                let saved = account("John_Doe", "john@example.com");
+
+                repository.save(&saved).await.unwrap();
+
+                assert!(
+                    repository
+                        .find_by_username("JOHN_doe")
+                        .await
+                        .unwrap()
+                        .is_some()
+                );
+                assert!(
+                    repository
+                        .find_by_email(" John@Example.COM ")
+                        .await
+                        .unwrap()
+                        .is_some()
+                );
+            }
+
+            #[tokio::test]
+            async fn preserves_registered_status_with_its_code() {
+                let repository = $repository;
+                let saved = account("john_doe", "john@example.com");
+
+                repository.save(&saved).await.unwrap();
+                let found = repository.find_by_id(saved.id()).await.unwrap().unwrap();
+
+                assert_eq!(
+                    found.status(),
+                    &AccountStatus::Registered {
+                        code_validation: CodeValidation::new(123123).unwrap()
+                    }
+                );
+            }
+
+            #[tokio::test]
+            async fn save_updates_an_existing_account() {
+                let repository = $repository;
+                let created = account("john_doe", "john@example.com");
+                repository.save(&created).await.unwrap();
+                let mut saved = repository.find_by_id(created.id()).await.unwrap().unwrap();
+                let verified_at = UNIX_EPOCH + Duration::from_secs(2_000);
+
+                saved
+                    .confirm_registration(CodeValidation::new(123123).unwrap(), verified_at)
+                    .unwrap();
+                saved.grant_role(PlatformRole::Moderator);
+                saved.record_login(verified_at);
+                repository.save(&saved).await.unwrap();
+
+                let found = repository.find_by_id(saved.id()).await.unwrap().unwrap();
+                assert_eq!(found.status(), &AccountStatus::Active);
+                assert_eq!(found.roles(), &[PlatformRole::Moderator]);
+                assert_eq!(found.verified_at(), Some(verified_at));
+                assert_eq!(found.last_login_at(), Some(verified_at));
+                assert_eq!(found.created_at(), saved.created_at());
+                assert_eq!(found.version(), 2);
+            }
+
+            #[tokio::test]
+            async fn rejects_saving_a_new_account_twice() {
+                let repository = $repository;
+                let saved = account("john_doe", "john@example.com");
+                repository.save(&saved).await.unwrap();
+
+                let result = repository.save(&saved).await;
+
+                assert!(matches!(result, Err(AccountRepositoryError::Conflict)));
+            }
+
+            #[tokio::test]
+            async fn rejects_stale_writes() {
+                let repository = $repository;
+                let created = account("john_doe", "john@example.com");
+                repository.save(&created).await.unwrap();
+                let mut first = repository.find_by_id(created.id()).await.unwrap().unwrap();
+                let mut second = repository.find_by_id(created.id()).await.unwrap().unwrap();
+
+                first.grant_role(PlatformRole::Moderator);
+                repository.save(&first).await.unwrap();
+                second.grant_role(PlatformRole::Admin);
+                let result = repository.save(&second).await;
+
+                assert!(matches!(result, Err(AccountRepositoryError::Conflict)));
+                assert_eq!(
+                    repository
+                        .find_by_id(created.id())
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .roles(),
+                    &[PlatformRole::Moderator]
+                );
+            }
+
+            #[tokio::test]
+            async fn rejects_another_account_with_the_same_username() {
+                let repository = $repository;
+                repository
+                    .save(&account("john_doe", "john@example.com"))
+                    .await
+                    .unwrap();
+
+                let result = repository
+                    .save(&account("JOHN_DOE", "other@example.com"))
+                    .await;
+
+                assert!(matches!(
+                    result,
+                    Err(AccountRepositoryError::UsernameAlreadyExists)
+                ));
+            }
+
+            #[tokio::test]
+            async fn rejects_another_account_with_the_same_email() {
+                let repository = $repository;
+                repository
+                    .save(&account("john_doe", "john@example.com"))
+                    .await
+                    .unwrap();
+
+                let result = repository
+                    .save(&account("jane_doe", "john@example.com"))
+                    .await;
+
+                assert!(matches!(
+                    result,
+                    Err(AccountRepositoryError::EmailAlreadyExists)
+                ));
+            }
+
+            #[tokio::test]
+            async fn save_in_writes_only_when_the_unit_of_work_commits() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let saved = account("john_doe", "john@example.com");
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &saved).await.unwrap();
+                assert!(repository.find_by_id(saved.id()).await.unwrap().is_none());
+                work.commit().await.unwrap();
+
+                assert_eq!(
+                    repository
+                        .find_by_id(saved.id())
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .version(),
+                    1
+                );
+            }
+
+            #[tokio::test]
+            async fn rolled_back_unit_of_work_writes_nothing() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let saved = account("john_doe", "john@example.com");
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &saved).await.unwrap();
+                work.rollback();
+
+                assert!(repository.find_by_id(saved.id()).await.unwrap().is_none());
+            }
+
+            #[tokio::test]
+            async fn failing_step_undoes_earlier_steps() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let existing = account("jane_doe", "jane@example.com");
+                repository.save(&existing).await.unwrap();
+                let mut stale = repository.find_by_id(existing.id()).await.unwrap().unwrap();
+                let mut fresh = repository.find_by_id(existing.id()).await.unwrap().unwrap();
+                fresh.grant_role(PlatformRole::Admin);
+                repository.save(&fresh).await.unwrap();
+                let created = account("john_doe", "john@example.com");
+                stale.grant_role(PlatformRole::Moderator);
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &created).await.unwrap();
+                repository.save_in(work.as_mut(), &stale).await.unwrap();
+                let result = work.commit().await;
+
+                assert!(matches!(result, Err(UnitOfWorkError::Conflict(_))));
+                assert!(repository.find_by_id(created.id()).await.unwrap().is_none());
+                assert_eq!(
+                    repository
+                        .find_by_id(existing.id())
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .roles(),
+                    &[PlatformRole::Admin]
+                );
+            }
+        }
+    };
+}
+
+pub(crate) use account_repository_contract;
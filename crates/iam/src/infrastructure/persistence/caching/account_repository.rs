@@ -0,0 +1,292 @@
+use crate::{
+    application::{
+        errors::account_repository::AccountRepositoryError,
+        ports::outbound::account_repository::AccountRepositoryPort,
+    },
+    domain::{
+        aggregates::Account,
+        value_objects::{AccountId, Username},
+    },
+};
+use async_trait::async_trait;
+use shared::{
+    application::ports::{clock::ClockPort, unit_of_work::UnitOfWork},
+    infrastructure::cache::{CachePolicy, CacheStats, LruCache},
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Lookup {
+    Username(String),
+    Email(String),
+}
+
+impl Lookup {
+    fn of(account: &Account) -> [Lookup; 2] {
+        [
+            Lookup::Username(account.username().lookup_key()),
+            Lookup::Email(account.email().as_str().to_string()),
+        ]
+    }
+
+    fn matches(&self, account: &Account) -> bool {
+        Lookup::of(account).contains(self)
+    }
+}
+
+struct Caches {
+    accounts: LruCache<AccountId, Option<Account>>,
+    lookups: LruCache<Lookup, Option<AccountId>>,
+}
+
+impl Caches {
+    fn invalidate(&self, account: &Account) {
+        let mut lookups = Lookup::of(account).to_vec();
+        if let Some(Some(cached)) = self.accounts.peek(account.id()) {
+            lookups.extend(Lookup::of(&cached));
+        }
+
+        self.lookups.invalidate(&lookups);
+        self.accounts.invalidate(&[account.id().clone()]);
+    }
+}
+
+pub struct CachingAccountRepository {
+    inner: Arc<dyn AccountRepositoryPort>,
+    caches: Arc<Caches>,
+    policy: CachePolicy,
+}
+
+impl CachingAccountRepository {
+    pub fn new(
+        inner: Arc<dyn AccountRepositoryPort>,
+        policy: CachePolicy,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            inner,
+            caches: Arc::new(Caches {
+                accounts: LruCache::new(policy.capacity, policy.ttl, clock.clone()),
+                lookups: LruCache::new(policy.capacity, policy.ttl, clock),
+            }),
+            policy,
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.caches.accounts.stats() + self.caches.lookups.stats()
+    }
+
+    fn ttl_for<T>(&self, found: &Option<T>) -> std::time::Duration {
+        if found.is_some() {
+            self.policy.ttl
+        } else {
+            self.policy.negative_ttl
+        }
+    }
+
+    async fn find_by_lookup(
+        &self,
+        lookup: Lookup,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        match self.caches.lookups.get(&lookup) {
+            Some(None) => return Ok(None),
+            Some(Some(id)) => {
+                if let Some(account) = self.find_by_id(&id).await?
+                    && lookup.matches(&account)
+                {
+                    return Ok(Some(account));
+                }
+            }
+            None => {}
+        }
+
+        let generation = (
+            self.caches.accounts.generation(),
+            self.caches.lookups.generation(),
+        );
+        let found = match &lookup {
+            Lookup::Username(username) => self.inner.find_by_username(username).await?,
+            Lookup::Email(email) => self.inner.find_by_email(email).await?,
+        };
+        let id = found.as_ref().map(|account| account.id().clone());
+        let ttl = self.ttl_for(&id);
+        if let Some(account) = &found {
+            self.caches.accounts.fill(
+                generation.0,
+                account.id().clone(),
+                Some(account.clone()),
+                ttl,
+            );
+        }
+        self.caches.lookups.fill(generation.1, lookup, id, ttl);
+
+        Ok(found)
+    }
+}
+
+#[async_trait]
+impl AccountRepositoryPort for CachingAccountRepository {
+    async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, AccountRepositoryError> {
+        if let Some(cached) = self.caches.accounts.get(id) {
+            return Ok(cached);
+        }
+
+        let generation = self.caches.accounts.generation();
+        let found = self.inner.find_by_id(id).await?;
+        let ttl = self.ttl_for(&found);
+        self.caches
+            .accounts
+            .fill(generation, id.clone(), found.clone(), ttl);
+
+        Ok(found)
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        self.find_by_lookup(Lookup::Username(Username::lookup_key_of(username)))
+            .await
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<Account>, AccountRepositoryError> {
+        self.find_by_lookup(Lookup::Email(email.trim().to_lowercase()))
+            .await
+    }
+
+    async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
+        let result = self.inner.save(account).await;
+        self.caches.invalidate(account);
+        result
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        account: &Account,
+    ) -> Result<(), AccountRepositoryError> {
+        self.inner.save_in(unit_of_work, account).await?;
+
+        let caches = self.caches.clone();
+        let saved = account.clone();
+        unit_of_work.on_commit(Box::new(move || caches.invalidate(&saved)));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::Email,
+        infrastructure::persistence::{
+            account_repository_contract::{account, account_repository_contract},
+            in_memory::account_repository::InMemoryAccountRepository,
+        },
+    };
+    use shared::infrastructure::{
+        clock::FixedClock, outbox::InMemoryOutboxStore, unit_of_work::InMemoryUnitOfWorkFactory,
+    };
+    use std::time::Duration;
+
+    const POLICY: CachePolicy = CachePolicy {
+        capacity: 16,
+        ttl: Duration::from_secs(60),
+        negative_ttl: Duration::from_secs(5),
+    };
+
+    fn repository() -> CachingAccountRepository {
+        CachingAccountRepository::new(
+            Arc::new(InMemoryAccountRepository::new(Arc::new(
+                InMemoryOutboxStore::new(),
+            ))),
+            POLICY,
+            Arc::new(FixedClock::at_unix_seconds(0)),
+        )
+    }
+
+    account_repository_contract!(
+        repository(),
+        (repository(), InMemoryUnitOfWorkFactory::new())
+    );
+
+    #[tokio::test]
+    async fn serves_repeated_reads_from_the_cache() {
+        let repository = repository();
+        let saved = account("john_doe", "john@example.com");
+        repository.save(&saved).await.unwrap();
+
+        repository.find_by_username("john_doe").await.unwrap();
+        repository.find_by_username("John_Doe").await.unwrap();
+        repository.find_by_id(saved.id()).await.unwrap();
+
+        assert_eq!(repository.stats(), CacheStats { hits: 3, misses: 1 });
+    }
+
+    #[tokio::test]
+    async fn saving_replaces_cached_negative_results() {
+        let repository = repository();
+        assert!(
+            repository
+                .find_by_email("john@example.com")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        repository
+            .save(&account("john_doe", "john@example.com"))
+            .await
+            .unwrap();
+
+        assert!(
+            repository
+                .find_by_email("john@example.com")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn expires_negative_results_sooner_than_found_accounts() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
+        let inner = Arc::new(InMemoryAccountRepository::new(Arc::new(
+            InMemoryOutboxStore::new(),
+        )));
+        let repository = CachingAccountRepository::new(inner.clone(), POLICY, clock.clone());
+        repository.find_by_username("john_doe").await.unwrap();
+        inner
+            .save(&account("john_doe", "john@example.com"))
+            .await
+            .unwrap();
+
+        let cached = repository.find_by_username("john_doe").await.unwrap();
+        clock.advance(Duration::from_secs(10));
+        let expired = repository.find_by_username("john_doe").await.unwrap();
+
+        assert!(cached.is_none());
+        assert!(expired.is_some());
+    }
+
+    #[tokio::test]
+    async fn changing_an_email_invalidates_the_old_lookup() {
+        let repository = repository();
+        let saved = account("john_doe", "john@example.com");
+        repository.save(&saved).await.unwrap();
+        let mut renamed = repository.find_by_id(saved.id()).await.unwrap().unwrap();
+        repository.find_by_email("john@example.com").await.unwrap();
+        renamed.change_email(Email::new("jane@example.com").unwrap());
+        repository.save(&renamed).await.unwrap();
+
+        assert!(
+            repository
+                .find_by_email("john@example.com")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    application::errors::account_repository::AccountRepositoryError,
+    domain::{
+        aggregates::Account,
+        value_objects::{
+            AccountId, AccountStatus, AccountTimestamps, CodeValidation, Email, HashedPassword,
+            PlatformRole, Username,
+        },
+    },
+};
+use serde::{Deserialize, Serialize};
+use shared::domain::timestamps::{from_unix_millis, to_unix_millis};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct AccountRecord {
+    id: String,
+    username: String,
+    email: String,
+    password_hash: String,
+    status: String,
+    verification_code: Option<u32>,
+    roles: Vec<String>,
+    created_at_ms: i64,
+    verified_at_ms: Option<i64>,
+    last_login_at_ms: Option<i64>,
+    status_changed_at_ms: i64,
+    version: u64,
+}
+
+impl From<&Account> for AccountRecord {
+    fn from(account: &Account) -> Self {
+        let verification_code = match account.status() {
+            AccountStatus::Registered { code_validation } => Some(code_validation.value()),
+            _ => None,
+        };
+        let timestamps = account.timestamps();
+
+        Self {
+            id: account.id().as_uuid().to_string(),
+            username: account.username().as_str().to_string(),
+            email: account.email().as_str().to_string(),
+            password_hash: account.password().as_str().to_string(),
+            status: account.status().as_str().to_string(),
+            verification_code,
+            roles: account
+                .roles()
+                .iter()
+                .map(|role| role.as_str().to_string())
+                .collect(),
+            created_at_ms: to_unix_millis(timestamps.created_at()),
+            verified_at_ms: timestamps.verified_at().map(to_unix_millis),
+            last_login_at_ms: timestamps.last_login_at().map(to_unix_millis),
+            status_changed_at_ms: to_unix_millis(timestamps.status_changed_at()),
+            version: account.version(),
+        }
+    }
+}
+
+impl TryFrom<AccountRecord> for Account {
+    type Error = AccountRepositoryError;
+
+    fn try_from(record: AccountRecord) -> Result<Self, Self::Error> {
+        let corrupted =
+            || AccountRepositoryError::Storage(format!("Corrupted account record {}", record.id));
+
+        let status = match record.status.as_str() {
+            "registered" => record
+                .verification_code
+                .and_then(|code| CodeValidation::new(code).ok())
+                .map(|code_validation| AccountStatus::Registered { code_validation }),
+            "active" => Some(AccountStatus::Active),
+            "suspended" => Some(AccountStatus::Suspended),
+            "deactivated" => Some(AccountStatus::Deactivated),
+            "deleted" => Some(AccountStatus::Deleted),
+            _ => None,
+        }
+        .ok_or_else(corrupted)?;
+        let roles = record
+            .roles
+            .iter()
+            .map(|role| PlatformRole::parse(role))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| corrupted())?;
+
+        Ok(Account::reconstitute(
+            AccountId::from_str(&record.id).map_err(|_| corrupted())?,
+            Username::new(record.username.clone()).map_err(|_| corrupted())?,
+            Email::new(record.email.as_str()).map_err(|_| corrupted())?,
+            HashedPassword::from_hash(record.password_hash.as_str()).map_err(|_| corrupted())?,
+            status,
+            roles,
+            AccountTimestamps::reconstitute(
+                from_unix_millis(record.created_at_ms),
+                record.verified_at_ms.map(from_unix_millis),
+                record.last_login_at_ms.map(from_unix_millis),
+                from_unix_millis(record.status_changed_at_ms),
+            ),
+            record.version,
+        ))
+    }
+}
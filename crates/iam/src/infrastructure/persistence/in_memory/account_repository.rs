@@ -1,49 +1,538 @@
+use super::account_record::AccountRecord;
 use crate::{
     application::{
         errors::account_repository::AccountRepositoryError,
         ports::outbound::account_repository::AccountRepositoryPort,
     },
-    domain::{aggregates::Account, value_objects::AccountId},
+    domain::{
+        aggregates::Account,
+        value_objects::{AccountId, Username},
+    },
+};
+use async_trait::async_trait;
+use shared::{
+    application::{
+        errors::unit_of_work::UnitOfWorkError,
+        outbox::OutboxMessage,
+        ports::{outbox_store::OutboxStorePort, unit_of_work::UnitOfWork},
+    },
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::json_snapshot::{
+            JsonSnapshotStore, LogEntry, Recovered, SnapshotError, SnapshotSource,
+        },
+        unit_of_work::{InMemoryUnitOfWork, Undo},
+    },
 };
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{Arc, RwLock},
 };
 
+#[derive(Default)]
+struct Accounts {
+    by_id: HashMap<AccountId, Account>,
+    by_username: HashMap<String, AccountId>,
+    by_email: HashMap<String, AccountId>,
+    log: Option<Arc<JsonSnapshotStore>>,
+}
+
+impl Accounts {
+    fn taken_error(&self, account: &Account) -> Option<AccountRepositoryError> {
+        let other_than = |id: &AccountId| id != account.id();
+
+        if self
+            .by_username
+            .get(&account.username().lookup_key())
+            .is_some_and(other_than)
+        {
+            Some(AccountRepositoryError::UsernameAlreadyExists)
+        } else if self
+            .by_email
+            .get(account.email().as_str())
+            .is_some_and(other_than)
+        {
+            Some(AccountRepositoryError::EmailAlreadyExists)
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, account: Account) -> Option<Account> {
+        let previous = self.remove(account.id());
+
+        self.by_username
+            .insert(account.username().lookup_key(), account.id().clone());
+        self.by_email
+            .insert(account.email().as_str().to_string(), account.id().clone());
+        self.by_id.insert(account.id().clone(), account);
+
+        previous
+    }
+
+    fn remove(&mut self, id: &AccountId) -> Option<Account> {
+        let removed = self.by_id.remove(id)?;
+        self.by_username.remove(&removed.username().lookup_key());
+        self.by_email.remove(removed.email().as_str());
+
+        Some(removed)
+    }
+
+    fn restore(
+        &mut self,
+        id: &AccountId,
+        written_version: u64,
+        previous: Option<Account>,
+    ) -> Result<(), SnapshotError> {
+        if self.by_id.get(id).map(Account::version) != Some(written_version) {
+            return Ok(());
+        }
+
+        match previous {
+            Some(previous) => {
+                self.log(LogEntry::Put(AccountRecord::from(&previous)))?;
+                self.insert(previous);
+            }
+            None => {
+                self.log(LogEntry::Remove(id.as_uuid().to_string()))?;
+                self.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    fn store(&mut self, account: &Account) -> Result<Option<Account>, AccountRepositoryError> {
+        if let Some(taken) = self.taken_error(account) {
+            return Err(taken);
+        }
+
+        let stored_version = self.by_id.get(account.id()).map_or(0, Account::version);
+        if stored_version != account.version() {
+            return Err(AccountRepositoryError::Conflict);
+        }
+
+        let mut stored = account.clone();
+        stored.pull_events();
+        stored.increment_version();
+        self.log(LogEntry::Put(AccountRecord::from(&stored)))
+            .map_err(|e| AccountRepositoryError::Storage(e.to_string()))?;
+        Ok(self.insert(stored))
+    }
+
+    fn log(&self, entry: LogEntry<AccountRecord>) -> Result<(), SnapshotError> {
+        match &self.log {
+            Some(log) => log.append(&entry),
+            None => Ok(()),
+        }
+    }
+}
+
 pub struct InMemoryAccountRepository {
-    accounts: Arc<Mutex<HashMap<AccountId, Account>>>,
+    accounts: Arc<RwLock<Accounts>>,
+    outbox: Arc<dyn OutboxStorePort>,
 }
 
 impl InMemoryAccountRepository {
-    pub fn new() -> Self {
+    pub fn new(outbox: Arc<dyn OutboxStorePort>) -> Self {
         Self {
-            accounts: Arc::new(Mutex::new(HashMap::new())),
+            accounts: Arc::new(RwLock::new(Accounts::default())),
+            outbox,
+        }
+    }
+
+    pub fn with_snapshots(
+        outbox: Arc<dyn OutboxStorePort>,
+        snapshots: Arc<JsonSnapshotStore>,
+    ) -> Result<Self, SnapshotError> {
+        let recovered: Recovered<Vec<AccountRecord>, LogEntry<AccountRecord>> = snapshots.load()?;
+        let corrupted = |e: AccountRepositoryError| SnapshotError::Corrupted(e.to_string());
+
+        let mut accounts = Accounts::default();
+        for record in recovered.snapshot.into_iter().flatten() {
+            accounts.insert(Account::try_from(record).map_err(corrupted)?);
+        }
+        for entry in recovered.log {
+            match entry {
+                LogEntry::Put(record) => {
+                    accounts.insert(Account::try_from(record).map_err(corrupted)?);
+                }
+                LogEntry::Remove(id) => {
+                    let id = AccountId::from_str(&id).map_err(|_| {
+                        SnapshotError::Corrupted(format!("Corrupted account record {}", id))
+                    })?;
+                    accounts.remove(&id);
+                }
+            }
         }
+        accounts.log = Some(snapshots);
+
+        Ok(Self {
+            accounts: Arc::new(RwLock::new(accounts)),
+            outbox,
+        })
     }
 }
 
+impl InMemoryAccountRepository {
+    fn outbox_messages(account: &Account) -> Vec<OutboxMessage> {
+        account
+            .pending_events()
+            .iter()
+            .map(|event| OutboxMessage::from_event(event.as_ref()))
+            .collect()
+    }
+}
+
+#[async_trait]
 impl AccountRepositoryPort for InMemoryAccountRepository {
-    fn find_by_username(&self, username: &str) -> Option<Account> {
-        let accounts = self.accounts.lock().expect("mutex poisoned");
-        accounts
-            .values()
-            .find(|account| account.username().as_str() == username)
-            .cloned()
+    async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, AccountRepositoryError> {
+        let accounts = self.accounts.read().expect("lock poisoned");
+        Ok(accounts.by_id.get(id).cloned())
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        let accounts = self.accounts.read().expect("lock poisoned");
+        Ok(accounts
+            .by_username
+            .get(&Username::lookup_key_of(username))
+            .and_then(|id| accounts.by_id.get(id))
+            .cloned())
     }
 
-    fn find_by_email(&self, email: &str) -> Option<Account> {
-        let accounts = self.accounts.lock().expect("mutex poisoned");
-        accounts
-            .values()
-            .find(|account| account.email().as_str() == email)
-            .cloned()
+    async fn find_by_email(&self, email: &str) -> Result<Option<Account>, AccountRepositoryError> {
+        let accounts = self.accounts.read().expect("lock poisoned");
+        Ok(accounts
+            .by_email
+            .get(&email.trim().to_lowercase())
+            .and_then(|id| accounts.by_id.get(id))
+            .cloned())
+    }
+
+    async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
+        let accounts = self.accounts.clone();
+        let outbox = self.outbox.clone();
+        let account = account.clone();
+
+        run_blocking(move || {
+            let mut accounts = accounts.write().expect("lock poisoned");
+            let previous = accounts.store(&account)?;
+            if let Err(e) = outbox.append(Self::outbox_messages(&account)) {
+                accounts
+                    .restore(account.id(), account.version() + 1, previous)
+                    .map_err(|e| AccountRepositoryError::Storage(e.to_string()))?;
+                return Err(AccountRepositoryError::Storage(e.0));
+            }
+            Ok(())
+        })
+        .await
     }
 
-    fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
-        let mut accounts = self.accounts.lock().expect("mutex poisoned");
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        account: &Account,
+    ) -> Result<(), AccountRepositoryError> {
+        let unit_of_work = InMemoryUnitOfWork::of(unit_of_work)
+            .map_err(|e| AccountRepositoryError::Storage(e.to_string()))?;
+        let accounts = self.accounts.clone();
+        let outbox = self.outbox.clone();
+        let messages = Self::outbox_messages(account);
+        let account = account.clone();
 
-        accounts.insert(account.id().clone(), account.clone());
+        unit_of_work.register(move || {
+            let previous = accounts
+                .write()
+                .expect("lock poisoned")
+                .store(&account)
+                .map_err(|e| match e {
+                    AccountRepositoryError::Conflict
+                    | AccountRepositoryError::UsernameAlreadyExists
+                    | AccountRepositoryError::EmailAlreadyExists => {
+                        UnitOfWorkError::Conflict(e.to_string())
+                    }
+                    AccountRepositoryError::Storage(message)
+                    | AccountRepositoryError::Unavailable(message) => {
+                        UnitOfWorkError::Failed(message)
+                    }
+                })?;
+            let id = account.id().clone();
+            let written_version = account.version() + 1;
+            Ok(Box::new(move || {
+                accounts
+                    .write()
+                    .expect("lock poisoned")
+                    .restore(&id, written_version, previous)
+                    .map_err(|e| UnitOfWorkError::Failed(e.to_string()))
+            }) as Undo)
+        });
+        unit_of_work.append_to_outbox(outbox, messages);
 
         Ok(())
     }
 }
+
+impl SnapshotSource for InMemoryAccountRepository {
+    fn write_snapshot(&self) -> Result<(), SnapshotError> {
+        let accounts = self.accounts.read().expect("lock poisoned");
+        let Some(log) = &accounts.log else {
+            return Ok(());
+        };
+
+        log.write_snapshot(|| {
+            accounts
+                .by_id
+                .values()
+                .map(AccountRecord::from)
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            events::AccountRegistered,
+            value_objects::{Email, HashedPassword, PlatformRole},
+        },
+        infrastructure::persistence::account_repository_contract::account_repository_contract,
+    };
+    use shared::{
+        application::{
+            errors::outbox_store::OutboxStoreError, ports::unit_of_work::UnitOfWorkPort,
+        },
+        infrastructure::{outbox::InMemoryOutboxStore, unit_of_work::InMemoryUnitOfWorkFactory},
+    };
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use uuid::Uuid;
+
+    struct FailingOutbox;
+
+    impl OutboxStorePort for FailingOutbox {
+        fn append(&self, _messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError> {
+            Err(OutboxStoreError("outbox is full".to_string()))
+        }
+
+        fn due(
+            &self,
+            _now: SystemTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboxMessage>, OutboxStoreError> {
+            Ok(Vec::new())
+        }
+
+        fn mark_delivered(&self, _id: &Uuid, _at: SystemTime) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_retry(
+            &self,
+            _id: &Uuid,
+            _error: String,
+            _next_attempt_at: SystemTime,
+        ) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_failed(&self, _id: &Uuid, _error: String) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+    }
+
+    fn repository() -> InMemoryAccountRepository {
+        InMemoryAccountRepository::new(Arc::new(InMemoryOutboxStore::new()))
+    }
+
+    account_repository_contract!(
+        repository(),
+        (repository(), InMemoryUnitOfWorkFactory::new())
+    );
+
+    fn registered_account() -> Account {
+        Account::register(
+            AccountId::generate(),
+            Username::new("john_doe".to_string()).unwrap(),
+            Email::new("john@example.com").unwrap(),
+            HashedPassword::dummy(),
+            UNIX_EPOCH,
+        )
+    }
+
+    #[tokio::test]
+    async fn save_writes_pending_events_to_the_outbox() {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        let repository = InMemoryAccountRepository::new(outbox.clone());
+        let account = registered_account();
+
+        repository.save(&account).await.unwrap();
+
+        let messages = outbox.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].event_type, AccountRegistered::EVENT_TYPE);
+        assert_eq!(messages[0].aggregate_id, account.id().as_uuid().to_string());
+    }
+
+    #[tokio::test]
+    async fn save_keeps_nothing_when_the_outbox_rejects_the_events() {
+        let repository = InMemoryAccountRepository::new(Arc::new(FailingOutbox));
+        let account = registered_account();
+
+        let result = repository.save(&account).await;
+
+        assert!(matches!(result, Err(AccountRepositoryError::Storage(_))));
+        assert!(repository.find_by_id(account.id()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn saving_a_reloaded_account_does_not_duplicate_events() {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        let repository = InMemoryAccountRepository::new(outbox.clone());
+        let account = registered_account();
+        repository.save(&account).await.unwrap();
+
+        let reloaded = repository.find_by_id(account.id()).await.unwrap().unwrap();
+        repository.save(&reloaded).await.unwrap();
+
+        assert_eq!(outbox.messages().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_saves_with_the_same_email_admit_only_one() {
+        let repository = Arc::new(repository());
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    let account = Account::register(
+                        AccountId::generate(),
+                        Username::new(format!("user_{}", i)).unwrap(),
+                        Email::new("john@example.com").unwrap(),
+                        HashedPassword::dummy(),
+                        UNIX_EPOCH,
+                    );
+                    repository.save(&account).await
+                })
+            })
+            .collect();
+        let mut saved = 0;
+        for handle in handles {
+            if handle.await.unwrap().is_ok() {
+                saved += 1;
+            }
+        }
+
+        assert_eq!(saved, 1);
+    }
+
+    #[tokio::test]
+    async fn undone_saves_release_the_indexed_username_and_email() {
+        let repository = repository();
+        let account = registered_account();
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+
+        repository.save_in(work.as_mut(), &account).await.unwrap();
+        repository.save_in(work.as_mut(), &account).await.unwrap();
+        assert!(work.commit().await.is_err());
+
+        assert!(
+            repository
+                .find_by_username("john_doe")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(repository.save(&registered_account()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn undone_saves_keep_later_writes_to_the_same_account() {
+        let repository = repository();
+        let account = registered_account();
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+        repository.save_in(work.as_mut(), &account).await.unwrap();
+        let accounts = repository.accounts.clone();
+        let id = account.id().clone();
+        InMemoryUnitOfWork::of(work.as_mut())
+            .unwrap()
+            .register(move || {
+                let mut newer = accounts.read().unwrap().by_id[&id].clone();
+                newer.grant_role(PlatformRole::Moderator);
+                accounts.write().unwrap().store(&newer).unwrap();
+                Err(UnitOfWorkError::Conflict("stale".to_string()))
+            });
+
+        assert!(work.commit().await.is_err());
+
+        let found = repository.find_by_id(account.id()).await.unwrap().unwrap();
+        assert_eq!(found.roles(), &[PlatformRole::Moderator]);
+    }
+
+    fn snapshot_store(dir: &tempfile::TempDir) -> Arc<JsonSnapshotStore> {
+        Arc::new(JsonSnapshotStore::open(dir.path(), "accounts").unwrap())
+    }
+
+    fn snapshotting_repository(dir: &tempfile::TempDir) -> InMemoryAccountRepository {
+        InMemoryAccountRepository::with_snapshots(
+            Arc::new(InMemoryOutboxStore::new()),
+            snapshot_store(dir),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn restores_accounts_from_the_snapshot_and_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = snapshotting_repository(&dir);
+        let account = registered_account();
+        repository.save(&account).await.unwrap();
+        repository.write_snapshot().unwrap();
+        let mut reloaded = repository.find_by_id(account.id()).await.unwrap().unwrap();
+        reloaded.grant_role(PlatformRole::Moderator);
+        repository.save(&reloaded).await.unwrap();
+        drop(repository);
+
+        let restored = snapshotting_repository(&dir);
+
+        let found = restored
+            .find_by_username("john_doe")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.version(), 2);
+        assert_eq!(found.roles(), &[PlatformRole::Moderator]);
+        assert!(
+            restored
+                .find_by_email("john@example.com")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn undone_unit_of_work_saves_are_not_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = snapshotting_repository(&dir);
+        let account = registered_account();
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+        repository.save_in(work.as_mut(), &account).await.unwrap();
+        repository.save_in(work.as_mut(), &account).await.unwrap();
+        assert!(work.commit().await.is_err());
+        drop(repository);
+
+        let restored = snapshotting_repository(&dir);
+
+        assert!(
+            restored
+                .find_by_username("john_doe")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    application::{errors::audit_log::AuditLogError, ports::outbound::audit_log::AuditLogPort},
+    domain::{aggregates::AuditEvent, value_objects::AccountId},
+};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+pub struct InMemoryAuditLog {
+    events: Arc<Mutex<Vec<AuditEvent>>>,
+}
+
+impl InMemoryAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AuditLogPort for InMemoryAuditLog {
+    async fn append(&self, event: &AuditEvent) -> Result<(), AuditLogError> {
+        let mut events = self.events.lock().expect("mutex poisoned");
+
+        events.push(event.clone());
+
+        Ok(())
+    }
+
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+        limit: usize,
+    ) -> Result<Vec<AuditEvent>, AuditLogError> {
+        let events = self.events.lock().expect("mutex poisoned");
+
+        Ok(events
+            .iter()
+            .rev()
+            .filter(|event| event.account_id() == Some(account_id))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
@@ -1 +1,5 @@
+mod account_record;
 pub mod account_repository;
+pub mod audit_log;
+mod profile_record;
+pub mod profile_repository;
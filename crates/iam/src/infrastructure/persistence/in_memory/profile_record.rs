@@ -0,0 +1,69 @@
+use crate::{
+    application::errors::profile_repository::ProfileRepositoryError,
+    domain::{
+        aggregates::Profile,
+        value_objects::{
+            AccountId, AvatarRef, Bio, DisplayName, Locale, ProfileVisibility, Timezone,
+        },
+    },
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct ProfileRecord {
+    account_id: String,
+    display_name: Option<String>,
+    bio: Option<String>,
+    avatar: Option<String>,
+    locale: String,
+    timezone: String,
+    visibility: String,
+}
+
+impl From<&Profile> for ProfileRecord {
+    fn from(profile: &Profile) -> Self {
+        Self {
+            account_id: profile.account_id().as_uuid().to_string(),
+            display_name: profile.display_name().map(|v| v.as_str().to_string()),
+            bio: profile.bio().map(|v| v.as_str().to_string()),
+            avatar: profile.avatar().map(|v| v.as_str().to_string()),
+            locale: profile.locale().as_str().to_string(),
+            timezone: profile.timezone().as_str().to_string(),
+            visibility: profile.visibility().as_str().to_string(),
+        }
+    }
+}
+
+impl TryFrom<ProfileRecord> for Profile {
+    type Error = ProfileRepositoryError;
+
+    fn try_from(record: ProfileRecord) -> Result<Self, Self::Error> {
+        let corrupted =
+            || ProfileRepositoryError(format!("Corrupted profile record {}", record.account_id));
+
+        Ok(Profile::reconstitute(
+            AccountId::from_str(&record.account_id).map_err(|_| corrupted())?,
+            record
+                .display_name
+                .clone()
+                .map(DisplayName::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            record
+                .bio
+                .clone()
+                .map(Bio::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            record
+                .avatar
+                .clone()
+                .map(AvatarRef::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            Locale::new(record.locale.clone()).map_err(|_| corrupted())?,
+            Timezone::new(record.timezone.clone()).map_err(|_| corrupted())?,
+            ProfileVisibility::parse(&record.visibility).map_err(|_| corrupted())?,
+        ))
+    }
+}
@@ -0,0 +1,155 @@
+use super::profile_record::ProfileRecord;
+use crate::{
+    application::{
+        errors::profile_repository::ProfileRepositoryError,
+        ports::outbound::profile_repository::ProfileRepositoryPort,
+    },
+    domain::{aggregates::Profile, value_objects::AccountId},
+};
+use async_trait::async_trait;
+use shared::infrastructure::{
+    blocking::run_blocking,
+    persistence::json_snapshot::{
+        JsonSnapshotStore, LogEntry, Recovered, SnapshotError, SnapshotSource,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+struct Profiles {
+    by_account_id: HashMap<AccountId, Profile>,
+    log: Option<Arc<JsonSnapshotStore>>,
+}
+
+impl Profiles {
+    fn store(&mut self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
+        if let Some(log) = &self.log {
+            log.append(&LogEntry::Put(ProfileRecord::from(profile)))
+                .map_err(|e| ProfileRepositoryError(e.to_string()))?;
+        }
+        self.by_account_id
+            .insert(profile.account_id().clone(), profile.clone());
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryProfileRepository {
+    profiles: Arc<RwLock<Profiles>>,
+}
+
+impl InMemoryProfileRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_snapshots(snapshots: Arc<JsonSnapshotStore>) -> Result<Self, SnapshotError> {
+        let recovered: Recovered<Vec<ProfileRecord>, LogEntry<ProfileRecord>> = snapshots.load()?;
+        let corrupted = |e: ProfileRepositoryError| SnapshotError::Corrupted(e.to_string());
+
+        let mut profiles = Profiles::default();
+        for record in recovered.snapshot.into_iter().flatten() {
+            let profile = Profile::try_from(record).map_err(corrupted)?;
+            profiles
+                .by_account_id
+                .insert(profile.account_id().clone(), profile);
+        }
+        for entry in recovered.log {
+            match entry {
+                LogEntry::Put(record) => {
+                    let profile = Profile::try_from(record).map_err(corrupted)?;
+                    profiles
+                        .by_account_id
+                        .insert(profile.account_id().clone(), profile);
+                }
+                LogEntry::Remove(id) => {
+                    let id = AccountId::from_str(&id).map_err(|_| {
+                        SnapshotError::Corrupted(format!("Corrupted profile record {}", id))
+                    })?;
+                    profiles.by_account_id.remove(&id);
+                }
+            }
+        }
+        profiles.log = Some(snapshots);
+
+        Ok(Self {
+            profiles: Arc::new(RwLock::new(profiles)),
+        })
+    }
+}
+
+#[async_trait]
+impl ProfileRepositoryPort for InMemoryProfileRepository {
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        let profiles = self.profiles.read().expect("lock poisoned");
+        Ok(profiles.by_account_id.get(account_id).cloned())
+    }
+
+    async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
+        let profiles = self.profiles.clone();
+        let profile = profile.clone();
+
+        run_blocking(move || profiles.write().expect("lock poisoned").store(&profile)).await
+    }
+}
+
+impl SnapshotSource for InMemoryProfileRepository {
+    fn write_snapshot(&self) -> Result<(), SnapshotError> {
+        let profiles = self.profiles.read().expect("lock poisoned");
+        let Some(log) = &profiles.log else {
+            return Ok(());
+        };
+
+        log.write_snapshot(|| {
+            profiles
+                .by_account_id
+                .values()
+                .map(ProfileRecord::from)
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{DisplayName, ProfileVisibility};
+
+    fn snapshot_store(dir: &tempfile::TempDir) -> Arc<JsonSnapshotStore> {
+        Arc::new(JsonSnapshotStore::open(dir.path(), "profiles").unwrap())
+    }
+
+    fn snapshotting_repository(dir: &tempfile::TempDir) -> InMemoryProfileRepository {
+        InMemoryProfileRepository::with_snapshots(snapshot_store(dir)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn restores_profiles_from_the_snapshot_and_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = snapshotting_repository(&dir);
+        let mut profile = Profile::create_default(AccountId::generate());
+        profile.change_display_name(Some(DisplayName::new("John").unwrap()));
+        repository.save(&profile).await.unwrap();
+        repository.write_snapshot().unwrap();
+        profile.change_visibility(ProfileVisibility::Private);
+        repository.save(&profile).await.unwrap();
+        drop(repository);
+
+        let restored = snapshotting_repository(&dir);
+
+        let found = restored
+            .find_by_account_id(profile.account_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.display_name().map(DisplayName::as_str), Some("John"));
+        assert_eq!(found.visibility(), ProfileVisibility::Private);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    application::{errors::audit_log::AuditLogError, ports::outbound::audit_log::AuditLogPort},
+    domain::{
+        aggregates::AuditEvent,
+        value_objects::{AccountId, AuditEventType, AuditOrigin},
+    },
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::infrastructure::blocking::run_blocking;
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize)]
+struct AuditRecord {
+    id: String,
+    event_type: String,
+    account_id: Option<String>,
+    actor_id: Option<String>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    occurred_at_ms: u64,
+}
+
+impl From<&AuditEvent> for AuditRecord {
+    fn from(event: &AuditEvent) -> Self {
+        Self {
+            id: event.id().to_string(),
+            event_type: event.event_type().as_str().to_string(),
+            account_id: event.account_id().map(|id| id.as_uuid().to_string()),
+            actor_id: event.actor_id().map(|id| id.as_uuid().to_string()),
+            ip_address: event.origin().ip_address().map(str::to_string),
+            user_agent: event.origin().user_agent().map(str::to_string),
+            occurred_at_ms: event
+                .occurred_at()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<AuditRecord> for AuditEvent {
+    type Error = AuditLogError;
+
+    fn try_from(record: AuditRecord) -> Result<Self, Self::Error> {
+        let corrupted = || AuditLogError(format!("Corrupted audit record {}", record.id));
+        let parse_id = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(AccountId::from_str)
+                .transpose()
+                .map_err(|_| corrupted())
+        };
+
+        Ok(AuditEvent::reconstitute(
+            Uuid::parse_str(&record.id).map_err(|_| corrupted())?,
+            AuditEventType::parse(&record.event_type).ok_or_else(corrupted)?,
+            parse_id(&record.account_id)?,
+            parse_id(&record.actor_id)?,
+            AuditOrigin::new(record.ip_address.clone(), record.user_agent.clone()),
+            UNIX_EPOCH + Duration::from_millis(record.occurred_at_ms),
+        ))
+    }
+}
+
+pub struct JsonLinesAuditLog {
+    path: PathBuf,
+    file: Arc<Mutex<File>>,
+}
+
+impl JsonLinesAuditLog {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AuditLogError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| AuditLogError(e.to_string()))?;
+
+        Ok(Self {
+            path,
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+}
+
+#[async_trait]
+impl AuditLogPort for JsonLinesAuditLog {
+    async fn append(&self, event: &AuditEvent) -> Result<(), AuditLogError> {
+        let mut line = serde_json::to_string(&AuditRecord::from(event))
+            .map_err(|e| AuditLogError(e.to_string()))?;
+        line.push('\n');
+        let file = self.file.clone();
+
+        run_blocking(move || {
+            let mut file = file.lock().expect("mutex poisoned");
+            file.write_all(line.as_bytes())
+                .and_then(|_| file.sync_data())
+                .map_err(|e| AuditLogError(e.to_string()))
+        })
+        .await
+    }
+
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+        limit: usize,
+    ) -> Result<Vec<AuditEvent>, AuditLogError> {
+        let lock = self.file.clone();
+        let path = self.path.clone();
+        let account_id = account_id.as_uuid().to_string();
+
+        run_blocking(move || {
+            let _guard = lock.lock().expect("mutex poisoned");
+            let file = File::open(&path).map_err(|e| AuditLogError(e.to_string()))?;
+
+            let mut events = Vec::new();
+            for line in BufReader::new(file).lines() {
+                let line = line.map_err(|e| AuditLogError(e.to_string()))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let record: AuditRecord =
+                    serde_json::from_str(&line).map_err(|e| AuditLogError(e.to_string()))?;
+                if record.account_id.as_deref() == Some(account_id.as_str()) {
+                    events.push(AuditEvent::try_from(record)?);
+                }
+            }
+
+            Ok(events.into_iter().rev().take(limit).collect())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_for(account_id: &AccountId, event_type: AuditEventType) -> AuditEvent {
+        AuditEvent::record(
+            event_type,
+            Some(account_id.clone()),
+            Some(account_id.clone()),
+            AuditOrigin::new(Some("10.0.0.1".to_string()), Some("curl/8".to_string())),
+            UNIX_EPOCH + Duration::from_millis(1_234),
+        )
+    }
+
+    #[tokio::test]
+    async fn persists_events_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let owner = AccountId::generate();
+
+        let log = JsonLinesAuditLog::open(&path).unwrap();
+        log.append(&event_for(&owner, AuditEventType::SignInFailed))
+            .await
+            .unwrap();
+        log.append(&event_for(
+            &AccountId::generate(),
+            AuditEventType::SignInSucceeded,
+        ))
+        .await
+        .unwrap();
+        log.append(&event_for(&owner, AuditEventType::SignInSucceeded))
+            .await
+            .unwrap();
+        drop(log);
+
+        let reopened = JsonLinesAuditLog::open(&path).unwrap();
+        let events = reopened.find_by_account_id(&owner, 10).await.unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type(), AuditEventType::SignInSucceeded);
+        assert_eq!(events[1].event_type(), AuditEventType::SignInFailed);
+        assert_eq!(events[1].origin().user_agent(), Some("curl/8"));
+        assert_eq!(
+            events[1].occurred_at(),
+            UNIX_EPOCH + Duration::from_millis(1_234)
+        );
+    }
+
+    #[tokio::test]
+    async fn appends_one_json_object_per_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = JsonLinesAuditLog::open(&path).unwrap();
+
+        log.append(&event_for(
+            &AccountId::generate(),
+            AuditEventType::AccountVerified,
+        ))
+        .await
+        .unwrap();
+        log.append(&event_for(
+            &AccountId::generate(),
+            AuditEventType::AccountVerified,
+        ))
+        .await
+        .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.lines().all(|line| line.starts_with('{')));
+    }
+}
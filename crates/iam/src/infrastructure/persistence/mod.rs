@@ -1 +1,6 @@
+#[cfg(test)]
+pub mod account_repository_contract;
+pub mod caching;
 pub mod in_memory;
+pub mod json_lines;
+pub mod sqlite;
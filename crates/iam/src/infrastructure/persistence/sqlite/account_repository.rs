@@ -0,0 +1,349 @@
+use crate::{
+    application::{
+        errors::account_repository::AccountRepositoryError,
+        ports::outbound::account_repository::AccountRepositoryPort,
+    },
+    domain::{
+        aggregates::Account,
+        value_objects::{
+            AccountId, AccountStatus, AccountTimestamps, CodeValidation, Email, HashedPassword,
+            PlatformRole, Username,
+        },
+    },
+};
+use async_trait::async_trait;
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Row, params};
+use shared::{
+    application::{errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWork},
+    domain::timestamps::{from_unix_millis, to_unix_millis},
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::sqlite::{SqliteDatabase, SqliteOutboxStore, SqliteUnitOfWork},
+    },
+};
+use std::sync::Arc;
+
+struct AccountRow {
+    id: String,
+    username: String,
+    email: String,
+    password_hash: String,
+    status: String,
+    verification_code: Option<u32>,
+    roles: String,
+    created_at: i64,
+    verified_at: Option<i64>,
+    last_login_at: Option<i64>,
+    status_changed_at: i64,
+    version: i64,
+}
+
+impl AccountRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get("id")?,
+            username: row.get("username")?,
+            email: row.get("email")?,
+            password_hash: row.get("password_hash")?,
+            status: row.get("status")?,
+            verification_code: row.get("verification_code")?,
+            roles: row.get("roles")?,
+            created_at: row.get("created_at")?,
+            verified_at: row.get("verified_at")?,
+            last_login_at: row.get("last_login_at")?,
+            status_changed_at: row.get("status_changed_at")?,
+            version: row.get("version")?,
+        })
+    }
+
+    fn status(&self) -> Option<AccountStatus> {
+        match self.status.as_str() {
+            "registered" => {
+                let code_validation = CodeValidation::new(self.verification_code?).ok()?;
+                Some(AccountStatus::Registered { code_validation })
+            }
+            "active" => Some(AccountStatus::Active),
+            "suspended" => Some(AccountStatus::Suspended),
+            "deactivated" => Some(AccountStatus::Deactivated),
+            "deleted" => Some(AccountStatus::Deleted),
+            _ => None,
+        }
+    }
+
+    fn into_account(self) -> Result<Account, AccountRepositoryError> {
+        let corrupted =
+            || AccountRepositoryError::Storage(format!("Corrupted account record {}", self.id));
+
+        let roles = self
+            .roles
+            .split(',')
+            .filter(|role| !role.is_empty())
+            .map(PlatformRole::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| corrupted())?;
+
+        Ok(Account::reconstitute(
+            AccountId::from_str(&self.id).map_err(|_| corrupted())?,
+            Username::new(self.username.clone()).map_err(|_| corrupted())?,
+            Email::new(self.email.as_str()).map_err(|_| corrupted())?,
+            HashedPassword::from_hash(self.password_hash.as_str()).map_err(|_| corrupted())?,
+            self.status().ok_or_else(corrupted)?,
+            roles,
+            AccountTimestamps::reconstitute(
+                from_unix_millis(self.created_at),
+                self.verified_at.map(from_unix_millis),
+                self.last_login_at.map(from_unix_millis),
+                from_unix_millis(self.status_changed_at),
+            ),
+            self.version as u64,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct SqliteAccountRepository {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteAccountRepository {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+
+    fn find_one(
+        &self,
+        clause: &str,
+        value: &str,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        let row = self
+            .database
+            .connection()
+            .query_row(
+                &format!("SELECT * FROM accounts WHERE {} = ?1", clause),
+                params![value],
+                AccountRow::read,
+            )
+            .optional()
+            .map_err(Self::storage_error)?;
+
+        row.map(AccountRow::into_account).transpose()
+    }
+
+    fn storage_error(e: rusqlite::Error) -> AccountRepositoryError {
+        if SqliteDatabase::is_unavailable(&e) {
+            AccountRepositoryError::Unavailable(e.to_string())
+        } else {
+            AccountRepositoryError::Storage(e.to_string())
+        }
+    }
+
+    fn write(connection: &Connection, account: &Account) -> rusqlite::Result<bool> {
+        let verification_code = match account.status() {
+            AccountStatus::Registered { code_validation } => Some(code_validation.value()),
+            _ => None,
+        };
+        let roles = account
+            .roles()
+            .iter()
+            .map(|role| role.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let timestamps = account.timestamps();
+
+        let params = params![
+            account.id().as_uuid().to_string(),
+            account.username().as_str(),
+            account.username().lookup_key(),
+            account.email().as_str(),
+            account.password().as_str(),
+            account.status().as_str(),
+            verification_code,
+            roles,
+            to_unix_millis(timestamps.created_at()),
+            timestamps.verified_at().map(to_unix_millis),
+            timestamps.last_login_at().map(to_unix_millis),
+            to_unix_millis(timestamps.status_changed_at()),
+            account.version() as i64,
+        ];
+        let written = if account.version() == 0 {
+            connection.execute(
+                "INSERT INTO accounts (id, username, username_key, email, password_hash, status, verification_code, roles, created_at, verified_at, last_login_at, status_changed_at, version)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13 + 1)
+                 ON CONFLICT (id) DO NOTHING",
+                params,
+            )?
+        } else {
+            connection.execute(
+                "UPDATE accounts SET
+                    username = ?2,
+                    username_key = ?3,
+                    email = ?4,
+                    password_hash = ?5,
+                    status = ?6,
+                    verification_code = ?7,
+                    roles = ?8,
+                    created_at = ?9,
+                    verified_at = ?10,
+                    last_login_at = ?11,
+                    status_changed_at = ?12,
+                    version = ?13 + 1
+                 WHERE id = ?1 AND version = ?13",
+                params,
+            )?
+        };
+        if written == 1 {
+            SqliteOutboxStore::record_events(connection, account.pending_events())?;
+        }
+        Ok(written == 1)
+    }
+
+    fn write_error(e: rusqlite::Error) -> AccountRepositoryError {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::ConstraintViolation)
+                if e.to_string().contains("accounts.username_key") =>
+            {
+                AccountRepositoryError::UsernameAlreadyExists
+            }
+            Some(ErrorCode::ConstraintViolation) if e.to_string().contains("accounts.email") => {
+                AccountRepositoryError::EmailAlreadyExists
+            }
+            _ => Self::storage_error(e),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountRepositoryPort for SqliteAccountRepository {
+    async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, AccountRepositoryError> {
+        let repository = self.clone();
+        let id = id.as_uuid().to_string();
+        run_blocking(move || repository.find_one("id", &id)).await
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        let repository = self.clone();
+        let lookup_key = Username::lookup_key_of(username);
+        run_blocking(move || repository.find_one("username_key", &lookup_key)).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<Account>, AccountRepositoryError> {
+        let repository = self.clone();
+        let email = email.trim().to_lowercase();
+        run_blocking(move || repository.find_one("email", &email)).await
+    }
+
+    async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
+        let database = self.database.clone();
+        let account = account.clone();
+
+        let written = run_blocking(move || database.transaction(|tx| Self::write(tx, &account)))
+            .await
+            .map_err(Self::write_error)?;
+
+        if written {
+            Ok(())
+        } else {
+            Err(AccountRepositoryError::Conflict)
+        }
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        account: &Account,
+    ) -> Result<(), AccountRepositoryError> {
+        let unit_of_work = SqliteUnitOfWork::of(unit_of_work, &self.database)
+            .map_err(|e| AccountRepositoryError::Storage(e.to_string()))?;
+        let account = account.clone();
+
+        unit_of_work.register(move |tx| match Self::write(tx, &account) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(UnitOfWorkError::Conflict(
+                AccountRepositoryError::Conflict.to_string(),
+            )),
+            Err(e) => Err(match Self::write_error(e) {
+                AccountRepositoryError::Storage(message)
+                | AccountRepositoryError::Unavailable(message) => UnitOfWorkError::Failed(message),
+                taken => UnitOfWorkError::Conflict(taken.to_string()),
+            }),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::{
+        account_repository_contract::account_repository_contract, sqlite::migrations::MIGRATIONS,
+    };
+    use shared::{
+        application::ports::outbox_store::OutboxStorePort,
+        infrastructure::persistence::sqlite::SqliteUnitOfWorkFactory,
+    };
+    use std::time::UNIX_EPOCH;
+
+    fn migrated(database: SqliteDatabase) -> Arc<SqliteDatabase> {
+        database.migrate(&SqliteOutboxStore::MIGRATIONS).unwrap();
+        database.migrate(&MIGRATIONS).unwrap();
+        Arc::new(database)
+    }
+
+    fn repository() -> SqliteAccountRepository {
+        SqliteAccountRepository::new(migrated(SqliteDatabase::open_in_memory().unwrap()))
+    }
+
+    fn repository_with_unit_of_work() -> (SqliteAccountRepository, SqliteUnitOfWorkFactory) {
+        let database = migrated(SqliteDatabase::open_in_memory().unwrap());
+        (
+            SqliteAccountRepository::new(database.clone()),
+            SqliteUnitOfWorkFactory::new(database),
+        )
+    }
+
+    account_repository_contract!(repository(), repository_with_unit_of_work());
+
+    #[tokio::test]
+    async fn save_records_pending_events_in_the_same_database() {
+        let database = migrated(SqliteDatabase::open_in_memory().unwrap());
+        let repository = SqliteAccountRepository::new(database.clone());
+        let outbox = SqliteOutboxStore::new(database);
+
+        repository
+            .save(&Account::register(
+                AccountId::generate(),
+                Username::new("john_doe".to_string()).unwrap(),
+                Email::new("john@example.com").unwrap(),
+                HashedPassword::dummy(),
+                UNIX_EPOCH,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(outbox.due(UNIX_EPOCH, 10).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn accounts_survive_reopening_the_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("iam.db");
+        let account = Account::dummy_account();
+
+        {
+            let database = migrated(SqliteDatabase::open(&path).unwrap());
+            SqliteAccountRepository::new(database)
+                .save(&account)
+                .await
+                .unwrap();
+        }
+
+        let database = Arc::new(SqliteDatabase::open(&path).unwrap());
+        let reopened = SqliteAccountRepository::new(database);
+
+        assert!(reopened.find_by_id(account.id()).await.unwrap().is_some());
+    }
+}
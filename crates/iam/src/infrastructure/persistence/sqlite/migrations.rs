@@ -0,0 +1,22 @@
+use shared::infrastructure::persistence::sqlite::{Migration, MigrationSet};
+
+pub const MIGRATIONS: MigrationSet = MigrationSet {
+    context: "iam",
+    migrations: &[
+        Migration {
+            version: 1,
+            name: "create_accounts",
+            sql: include_str!("../../../../migrations/0001_create_accounts.sql"),
+        },
+        Migration {
+            version: 2,
+            name: "add_account_version",
+            sql: include_str!("../../../../migrations/0002_add_account_version.sql"),
+        },
+        Migration {
+            version: 3,
+            name: "create_profiles",
+            sql: include_str!("../../../../migrations/0003_create_profiles.sql"),
+        },
+    ],
+};
@@ -0,0 +1,3 @@
+pub mod account_repository;
+pub mod migrations;
+pub mod profile_repository;
@@ -0,0 +1,213 @@
+use crate::{
+    application::{
+        errors::profile_repository::ProfileRepositoryError,
+        ports::outbound::profile_repository::ProfileRepositoryPort,
+    },
+    domain::{
+        aggregates::Profile,
+        value_objects::{
+            AccountId, AvatarRef, Bio, DisplayName, Locale, ProfileVisibility, Timezone,
+        },
+    },
+};
+use async_trait::async_trait;
+use rusqlite::{OptionalExtension, Row, params};
+use shared::infrastructure::{blocking::run_blocking, persistence::sqlite::SqliteDatabase};
+use std::sync::Arc;
+
+struct ProfileRow {
+    account_id: String,
+    display_name: Option<String>,
+    bio: Option<String>,
+    avatar: Option<String>,
+    locale: String,
+    timezone: String,
+    visibility: String,
+}
+
+impl ProfileRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            account_id: row.get("account_id")?,
+            display_name: row.get("display_name")?,
+            bio: row.get("bio")?,
+            avatar: row.get("avatar")?,
+            locale: row.get("locale")?,
+            timezone: row.get("timezone")?,
+            visibility: row.get("visibility")?,
+        })
+    }
+
+    fn into_profile(self) -> Result<Profile, ProfileRepositoryError> {
+        let corrupted =
+            || ProfileRepositoryError(format!("Corrupted profile record {}", self.account_id));
+
+        Ok(Profile::reconstitute(
+            AccountId::from_str(&self.account_id).map_err(|_| corrupted())?,
+            self.display_name
+                .clone()
+                .map(DisplayName::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            self.bio
+                .clone()
+                .map(Bio::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            self.avatar
+                .clone()
+                .map(AvatarRef::new)
+                .transpose()
+                .map_err(|_| corrupted())?,
+            Locale::new(self.locale.clone()).map_err(|_| corrupted())?,
+            Timezone::new(self.timezone.clone()).map_err(|_| corrupted())?,
+            ProfileVisibility::parse(&self.visibility).map_err(|_| corrupted())?,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct SqliteProfileRepository {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteProfileRepository {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+
+    fn find(&self, account_id: &str) -> Result<Option<Profile>, ProfileRepositoryError> {
+        let row = self
+            .database
+            .connection()
+            .query_row(
+                "SELECT * FROM profiles WHERE account_id = ?1",
+                params![account_id],
+                ProfileRow::read,
+            )
+            .optional()
+            .map_err(|e| ProfileRepositoryError(e.to_string()))?;
+
+        row.map(ProfileRow::into_profile).transpose()
+    }
+
+    fn write(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
+        self.database
+            .connection()
+            .execute(
+                "INSERT INTO profiles (account_id, display_name, bio, avatar, locale, timezone, visibility)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (account_id) DO UPDATE SET
+                    display_name = excluded.display_name,
+                    bio = excluded.bio,
+                    avatar = excluded.avatar,
+                    locale = excluded.locale,
+                    timezone = excluded.timezone,
+                    visibility = excluded.visibility",
+                params![
+                    profile.account_id().as_uuid().to_string(),
+                    profile.display_name().map(DisplayName::as_str),
+                    profile.bio().map(Bio::as_str),
+                    profile.avatar().map(AvatarRef::as_str),
+                    profile.locale().as_str(),
+                    profile.timezone().as_str(),
+                    profile.visibility().as_str(),
+                ],
+            )
+            .map_err(|e| ProfileRepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProfileRepositoryPort for SqliteProfileRepository {
+    async fn find_by_account_id(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        let repository = self.clone();
+        let account_id = account_id.as_uuid().to_string();
+        run_blocking(move || repository.find(&account_id)).await
+    }
+
+    async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
+        let repository = self.clone();
+        let profile = profile.clone();
+        run_blocking(move || repository.write(&profile)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::migrations::MIGRATIONS;
+
+    fn migrated(database: SqliteDatabase) -> Arc<SqliteDatabase> {
+        database.migrate(&MIGRATIONS).unwrap();
+        Arc::new(database)
+    }
+
+    fn repository() -> SqliteProfileRepository {
+        SqliteProfileRepository::new(migrated(SqliteDatabase::open_in_memory().unwrap()))
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_unknown_accounts() {
+        let repository = repository();
+
+        let found = repository
+            .find_by_account_id(&AccountId::generate())
+            .await
+            .unwrap();
+
+        assert!(found.is_none());
+    }
+
+    #[tokio::test]
+    async fn saving_twice_keeps_the_latest_profile() {
+        let repository = repository();
+        let mut profile = Profile::create_default(AccountId::generate());
+        profile.change_display_name(Some(DisplayName::new("John").unwrap()));
+        repository.save(&profile).await.unwrap();
+        profile.change_bio(Some(Bio::new("Hello").unwrap()));
+        profile.change_visibility(ProfileVisibility::Members);
+        repository.save(&profile).await.unwrap();
+
+        let found = repository
+            .find_by_account_id(profile.account_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(found.display_name().map(DisplayName::as_str), Some("John"));
+        assert_eq!(found.bio().map(Bio::as_str), Some("Hello"));
+        assert_eq!(found.visibility(), ProfileVisibility::Members);
+    }
+
+    #[tokio::test]
+    async fn profiles_survive_reopening_the_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("iam.db");
+        let profile = Profile::create_default(AccountId::generate());
+
+        {
+            let database = migrated(SqliteDatabase::open(&path).unwrap());
+            SqliteProfileRepository::new(database)
+                .save(&profile)
+                .await
+                .unwrap();
+        }
+
+        let database = Arc::new(SqliteDatabase::open(&path).unwrap());
+        let reopened = SqliteProfileRepository::new(database);
+
+        assert!(
+            reopened
+                .find_by_account_id(profile.account_id())
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
@@ -6,7 +6,9 @@ use argon2::{
     Argon2,
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
 };
+use async_trait::async_trait;
 use rand::rngs::OsRng;
+use shared::infrastructure::blocking::run_blocking;
 
 pub struct Argon2PasswordHasher {
     argon2: Argon2<'static>,
@@ -20,26 +22,39 @@ impl Argon2PasswordHasher {
     }
 }
 
+#[async_trait]
 impl PasswordHasherPort for Argon2PasswordHasher {
-    fn hash(&self, raw_password: &str) -> HashedPassword {
-        let salt = SaltString::generate(&mut OsRng);
+    async fn hash(&self, raw_password: &str) -> HashedPassword {
+        let argon2 = self.argon2.clone();
+        let raw_password = raw_password.to_owned();
 
-        let password_hash = self
-            .argon2
-            .hash_password(raw_password.as_bytes(), &salt)
-            .expect("argon2 hashing must not fail")
-            .to_string();
+        run_blocking(move || {
+            let salt = SaltString::generate(&mut OsRng);
 
-        HashedPassword::from_hash(password_hash)
-            .expect("argon2 hash must be a valid HashedPassword")
+            let password_hash = argon2
+                .hash_password(raw_password.as_bytes(), &salt)
+                .expect("argon2 hashing must not fail")
+                .to_string();
+
+            HashedPassword::from_hash(password_hash)
+                .expect("argon2 hash must be a valid HashedPassword")
+        })
+        .await
     }
 
-    fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool {
-        let parsed_hash = PasswordHash::new(hashed_password.as_str())
-            .expect("stored password hash must be valid");
+    async fn verify(&self, password: &str, hashed_password: &HashedPassword) -> bool {
+        let argon2 = self.argon2.clone();
+        let password = password.to_owned();
+        let hashed_password = hashed_password.clone();
+
+        run_blocking(move || {
+            let parsed_hash = PasswordHash::new(hashed_password.as_str())
+                .expect("stored password hash must be valid");
 
-        self.argon2
-            .verify_password(password.as_bytes(), &parsed_hash)
-            .is_ok()
+            argon2
+                .verify_password(password.as_bytes(), &parsed_hash)
+                .is_ok()
+        })
+        .await
     }
 }
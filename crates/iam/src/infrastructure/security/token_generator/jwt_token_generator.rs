@@ -6,18 +6,24 @@ use crate::{
     infrastructure::security::token_generator::{claims::Claims, error::JwtError},
 };
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
-use std::time::{SystemTime, UNIX_EPOCH};
+use shared::application::ports::clock::ClockPort;
+use std::{
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
 
 pub struct JwtTokenGenerator {
     secret: String,
     ttl_seconds: u64,
+    clock: Arc<dyn ClockPort>,
 }
 
 impl JwtTokenGenerator {
-    pub fn new(secret: String, ttl_seconds: u64) -> Self {
+    pub fn new(secret: String, ttl_seconds: u64, clock: Arc<dyn ClockPort>) -> Self {
         Self {
             secret,
             ttl_seconds,
+            clock,
         }
     }
 }
@@ -25,7 +31,8 @@ impl JwtTokenGenerator {
 impl JwtTokenGenerator {
     pub fn decode(&self, token: &str) -> Result<Claims, JwtError> {
         let mut validation = Validation::new(Algorithm::HS256);
-        validation.validate_exp = true;
+        validation.validate_exp = false;
+        validation.required_spec_claims.clear();
 
         let data = decode::<Claims>(
             token,
@@ -41,14 +48,26 @@ impl JwtTokenGenerator {
             }
         })?;
 
+        let now = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as usize)
+            .map_err(|_| JwtError::InvalidToken)?;
+        if data.claims.exp <= now {
+            return Err(JwtError::Expired);
+        }
+
         Ok(data.claims)
     }
 }
 
 impl TokenGeneratorPort for JwtTokenGenerator {
     fn generate(&self, user_id: &str) -> Result<String, TokenGeneratorError> {
-        let expiration = SystemTime::now()
-            .checked_add(std::time::Duration::from_secs(self.ttl_seconds))
+        let expiration = self
+            .clock
+            .now()
+            .checked_add(Duration::from_secs(self.ttl_seconds))
             .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
             .map(|d| d.as_secs() as usize)
             .ok_or("Unable to calculate expiration time")
@@ -67,3 +86,38 @@ impl TokenGeneratorPort for JwtTokenGenerator {
         .map_err(|e| TokenGeneratorError(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::infrastructure::clock::FixedClock;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn generator(clock: Arc<FixedClock>) -> JwtTokenGenerator {
+        JwtTokenGenerator::new("secret".to_string(), 60, clock)
+    }
+
+    #[test]
+    fn expiration_is_based_on_the_clock() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(NOW));
+        let generator = generator(clock);
+
+        let token = generator.generate("account").unwrap();
+        let claims = generator.decode(&token).unwrap();
+
+        assert_eq!(claims.sub, "account");
+        assert_eq!(claims.exp, (NOW + 60) as usize);
+    }
+
+    #[test]
+    fn rejects_token_once_the_clock_passes_expiration() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(NOW));
+        let generator = generator(clock.clone());
+        let token = generator.generate("account").unwrap();
+
+        clock.advance(Duration::from_secs(60));
+
+        assert!(matches!(generator.decode(&token), Err(JwtError::Expired)));
+    }
+}
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS membership_communities (
+    id TEXT PRIMARY KEY,
+    owner_id TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    name TEXT NOT NULL,
+    public INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS membership_communities_slug
+    ON membership_communities (slug);
+CREATE TABLE IF NOT EXISTS memberships (
+    community_id TEXT NOT NULL
+        REFERENCES membership_communities (id) ON DELETE CASCADE,
+    account_id TEXT NOT NULL,
+    role TEXT NOT NULL,
+    status TEXT NOT NULL,
+    nickname TEXT,
+    PRIMARY KEY (community_id, account_id)
+) WITHOUT ROWID;
+CREATE INDEX IF NOT EXISTS memberships_account ON memberships (account_id);
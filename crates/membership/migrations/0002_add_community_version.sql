@@ -0,0 +1 @@
+ALTER TABLE membership_communities ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,3 @@
+pub struct JoinCommunity {
+    pub slug: String,
+}
@@ -6,6 +6,12 @@ pub enum ApplicationError {
     InvalidName,
     InvalidSlug,
     SlugAlreadyExists,
+    CommunityNotFound,
+    AlreadyMember,
+    InvitationRequired,
+    JoiningClosed,
+    Banned,
+    Conflict,
 }
 
 impl From<CommonApplicationError> for ApplicationError {
@@ -0,0 +1,20 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommunityDirectoryError {
+    Corrupted(String),
+    Unavailable(String),
+}
+
+impl fmt::Display for CommunityDirectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommunityDirectoryError::Corrupted(id) => write!(f, "corrupted community {}", id),
+            CommunityDirectoryError::Unavailable(message) => {
+                write!(f, "community directory unavailable: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommunityDirectoryError {}
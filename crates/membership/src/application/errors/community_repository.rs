@@ -0,0 +1,28 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommunityRepositoryError {
+    SlugAlreadyExists,
+    Conflict,
+    Corrupted(String),
+    Storage(String),
+    Unavailable(String),
+}
+
+impl fmt::Display for CommunityRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommunityRepositoryError::SlugAlreadyExists => write!(f, "slug already exists"),
+            CommunityRepositoryError::Conflict => {
+                write!(f, "community was modified by another request")
+            }
+            CommunityRepositoryError::Corrupted(id) => write!(f, "corrupted community {}", id),
+            CommunityRepositoryError::Storage(message) => write!(f, "storage error: {}", message),
+            CommunityRepositoryError::Unavailable(message) => {
+                write!(f, "community repository unavailable: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommunityRepositoryError {}
@@ -0,0 +1,6 @@
+pub const MEMBERSHIP_ALREADY_MEMBER: &str = "MEMBERSHIP_ALREADY_MEMBER";
+pub const MEMBERSHIP_INVITATION_REQUIRED: &str = "MEMBERSHIP_INVITATION_REQUIRED";
+pub const MEMBERSHIP_JOINING_CLOSED: &str = "MEMBERSHIP_JOINING_CLOSED";
+pub const MEMBERSHIP_CONFLICT: &str = "MEMBERSHIP_CONFLICT";
+pub const MEMBERSHIP_BANNED: &str = "MEMBERSHIP_BANNED";
+pub const MEMBERSHIP_ERROR: &str = "MEMBERSHIP_ERROR";
@@ -0,0 +1,24 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MembershipRepositoryError {
+    CommunityNotFound,
+    Corrupted(String),
+    Storage(String),
+    Unavailable(String),
+}
+
+impl fmt::Display for MembershipRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MembershipRepositoryError::CommunityNotFound => write!(f, "community not found"),
+            MembershipRepositoryError::Corrupted(key) => write!(f, "corrupted membership {}", key),
+            MembershipRepositoryError::Storage(message) => write!(f, "storage error: {}", message),
+            MembershipRepositoryError::Unavailable(message) => {
+                write!(f, "membership repository unavailable: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MembershipRepositoryError {}
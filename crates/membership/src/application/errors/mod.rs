@@ -1 +1,5 @@
 pub mod application_error;
+pub mod community_directory;
+pub mod community_repository;
+pub mod error_codes;
+pub mod membership_repository;
@@ -1,11 +1,13 @@
+use crate::application::{
+    commands::create_community::CreateCommunity, errors::application_error::ApplicationError,
+    results::community_created::CommunityCreated,
+};
+use async_trait::async_trait;
 use shared::application::auth_context::AuthContext;
 
-use crate::application::commands::create_community::CreateCommunity;
-use crate::application::errors::application_error::ApplicationError;
-use crate::application::results::community_created::CommunityCreated;
-
-pub trait CommunityCreationPort {
-    fn execute(
+#[async_trait]
+pub trait CommunityCreationPort: Send + Sync {
+    async fn execute(
         &self,
         data: CreateCommunity,
         auth: AuthContext,
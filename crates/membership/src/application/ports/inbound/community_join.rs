@@ -0,0 +1,15 @@
+use crate::application::{
+    commands::join_community::JoinCommunity, errors::application_error::ApplicationError,
+    results::community_joined::CommunityJoined,
+};
+use async_trait::async_trait;
+use shared::application::auth_context::AuthContext;
+
+#[async_trait]
+pub trait CommunityJoinPort: Send + Sync {
+    async fn execute(
+        &self,
+        data: JoinCommunity,
+        auth: AuthContext,
+    ) -> Result<CommunityJoined, ApplicationError>;
+}
@@ -1 +1,2 @@
 pub mod community_creation;
+pub mod community_join;
@@ -0,0 +1,150 @@
+use crate::{
+    application::errors::community_directory::CommunityDirectoryError,
+    domain::value_objects::{
+        community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+    },
+};
+use async_trait::async_trait;
+use communities::domain::policies::membership_policy::MembershipPolicy;
+use iam::domain::value_objects::AccountId;
+use shared::application::ports::unit_of_work::UnitOfWork;
+
+#[derive(Debug, Clone)]
+pub struct ListedCommunity {
+    pub id: CommunityId,
+    pub owner_id: AccountId,
+    pub slug: CommunitySlug,
+    pub name: CommunityName,
+    pub public: bool,
+    pub membership_policy: MembershipPolicy,
+}
+
+#[async_trait]
+pub trait CommunityDirectoryPort: Send + Sync {
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<ListedCommunity>, CommunityDirectoryError>;
+
+    async fn member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community_id: &CommunityId,
+    ) -> Result<(), CommunityDirectoryError>;
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::{
+        application::{
+            errors::community_directory::CommunityDirectoryError,
+            ports::outbound::community_directory::{CommunityDirectoryPort, ListedCommunity},
+        },
+        domain::{
+            aggregates::community::Community,
+            value_objects::{
+                community_id::CommunityId, community_name::CommunityName,
+                community_slug::CommunitySlug,
+            },
+        },
+    };
+    use async_trait::async_trait;
+    use communities::domain::policies::membership_policy::MembershipPolicy;
+    use iam::domain::value_objects::AccountId;
+    use shared::{
+        application::{errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWork},
+        infrastructure::unit_of_work::InMemoryUnitOfWork,
+    };
+    use std::sync::{Arc, Mutex};
+
+    pub struct FakeCommunityDirectory {
+        listed: Option<ListedCommunity>,
+        counting_fails: bool,
+        pub joined: Arc<Mutex<Vec<CommunityId>>>,
+    }
+
+    impl FakeCommunityDirectory {
+        pub fn empty() -> Self {
+            Self {
+                listed: None,
+                counting_fails: false,
+                joined: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        pub fn listing(public: bool, membership_policy: MembershipPolicy) -> Self {
+            Self {
+                listed: Some(ListedCommunity {
+                    id: CommunityId::generate(),
+                    owner_id: AccountId::generate(),
+                    slug: CommunitySlug::new("rust-community".to_string()).unwrap(),
+                    name: CommunityName::new("Rust Community".to_string()).unwrap(),
+                    public,
+                    membership_policy,
+                }),
+                counting_fails: false,
+                joined: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        pub fn of(community: &Community, membership_policy: MembershipPolicy) -> Self {
+            Self {
+                listed: Some(ListedCommunity {
+                    id: community.id().clone(),
+                    owner_id: community.owner_id().clone(),
+                    slug: community.slug().clone(),
+                    name: community.name().clone(),
+                    public: community.is_public(),
+                    membership_policy,
+                }),
+                counting_fails: false,
+                joined: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        pub fn failing_to_count(
+            community: &Community,
+            membership_policy: MembershipPolicy,
+        ) -> Self {
+            Self {
+                counting_fails: true,
+                ..Self::of(community, membership_policy)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CommunityDirectoryPort for FakeCommunityDirectory {
+        async fn find_by_slug(
+            &self,
+            slug: &str,
+        ) -> Result<Option<ListedCommunity>, CommunityDirectoryError> {
+            Ok(self
+                .listed
+                .clone()
+                .filter(|listed| listed.slug.as_str() == slug))
+        }
+
+        async fn member_joined_in(
+            &self,
+            unit_of_work: &mut dyn UnitOfWork,
+            community_id: &CommunityId,
+        ) -> Result<(), CommunityDirectoryError> {
+            if self.counting_fails {
+                InMemoryUnitOfWork::of(unit_of_work)
+                    .map_err(|e| CommunityDirectoryError::Unavailable(e.to_string()))?
+                    .register(|| {
+                        Err(UnitOfWorkError::Failed(
+                            "member count unavailable".to_string(),
+                        ))
+                    });
+                return Ok(());
+            }
+
+            let joined = self.joined.clone();
+            let community_id = community_id.clone();
+            unit_of_work.on_commit(Box::new(move || joined.lock().unwrap().push(community_id)));
+            Ok(())
+        }
+    }
+}
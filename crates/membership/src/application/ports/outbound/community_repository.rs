@@ -1,19 +1,39 @@
-use crate::domain::aggregates::community::Community;
+use crate::{
+    application::errors::community_repository::CommunityRepositoryError,
+    domain::aggregates::community::Community,
+};
+use async_trait::async_trait;
+use shared::application::ports::unit_of_work::UnitOfWork;
 
+#[async_trait]
 pub trait CommunityRepositoryPort: Send + Sync {
-    fn find_by_id(&self, id: &str) -> Option<Community>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError>;
 
-    fn find_by_slug(&self, slug: &str) -> Option<Community>;
-    fn save(&self, community: &Community) -> Result<(), String>;
+    async fn find_by_slug(&self, slug: &str)
+    -> Result<Option<Community>, CommunityRepositoryError>;
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError>;
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError>;
 }
 
 #[cfg(test)]
 pub mod test_utils {
-    use crate::application::ports::outbound::community_repository::CommunityRepositoryPort;
-    use crate::domain::aggregates::community::Community;
+    use crate::{
+        application::{
+            errors::community_repository::CommunityRepositoryError,
+            ports::outbound::community_repository::CommunityRepositoryPort,
+        },
+        domain::aggregates::community::Community,
+    };
+    use async_trait::async_trait;
+    use shared::application::ports::unit_of_work::UnitOfWork;
 
     pub struct FakeCommunityRepository {
         should_fail: bool,
+        conflicting: bool,
         _activated: bool,
         existing_id: Option<String>,
         existing_slug: Option<String>,
@@ -23,6 +43,7 @@ pub mod test_utils {
         pub fn success() -> Self {
             Self {
                 should_fail: false,
+                conflicting: false,
                 _activated: false,
                 existing_id: None,
                 existing_slug: None,
@@ -32,15 +53,37 @@ pub mod test_utils {
         pub fn fail() -> Self {
             Self {
                 should_fail: true,
+                conflicting: false,
                 _activated: false,
                 existing_id: None,
                 existing_slug: None,
             }
         }
 
+        pub fn conflicting() -> Self {
+            Self {
+                should_fail: false,
+                conflicting: true,
+                _activated: false,
+                existing_id: None,
+                existing_slug: None,
+            }
+        }
+
+        pub fn conflicting_with_existing_id(id: &str) -> Self {
+            Self {
+                should_fail: false,
+                conflicting: true,
+                _activated: false,
+                existing_id: Some(id.to_string()),
+                existing_slug: None,
+            }
+        }
+
         pub fn with_existing_slug(slug: &str) -> Self {
             Self {
                 should_fail: false,
+                conflicting: false,
                 _activated: false,
                 existing_id: None,
                 existing_slug: Some(slug.to_string()),
@@ -50,6 +93,7 @@ pub mod test_utils {
         pub fn with_existing_id(id: &str) -> Self {
             Self {
                 should_fail: false,
+                conflicting: false,
                 _activated: false,
                 existing_id: Some(id.to_string()),
                 existing_slug: None,
@@ -59,6 +103,7 @@ pub mod test_utils {
         pub fn active_with_existing_username(username: &str) -> Self {
             Self {
                 should_fail: false,
+                conflicting: false,
                 _activated: true,
                 existing_id: Some(username.to_string()),
                 existing_slug: None,
@@ -66,27 +111,53 @@ pub mod test_utils {
         }
     }
 
+    #[async_trait]
     impl CommunityRepositoryPort for FakeCommunityRepository {
-        fn find_by_id(&self, id: &str) -> Option<Community> {
-            self.existing_id
+        async fn find_by_id(
+            &self,
+            id: &str,
+        ) -> Result<Option<Community>, CommunityRepositoryError> {
+            if self.should_fail {
+                return Err(CommunityRepositoryError::Storage(
+                    "Unexpected error".to_string(),
+                ));
+            }
+            Ok(self
+                .existing_id
                 .as_ref()
                 .filter(|c| c.as_str() == id)
-                .map(|_| Community::dummy_community())
+                .map(|_| Community::dummy_community()))
         }
 
-        fn find_by_slug(&self, slug: &str) -> Option<Community> {
-            self.existing_slug
+        async fn find_by_slug(
+            &self,
+            slug: &str,
+        ) -> Result<Option<Community>, CommunityRepositoryError> {
+            Ok(self
+                .existing_slug
                 .as_ref()
                 .filter(|c| c.as_str() == slug)
-                .map(|_| Community::dummy_community())
+                .map(|_| Community::dummy_community()))
         }
 
-        fn save(&self, _community: &Community) -> Result<(), String> {
+        async fn save(&self, _community: &Community) -> Result<(), CommunityRepositoryError> {
             if self.should_fail {
-                Err("Unexpected error".to_string())
+                Err(CommunityRepositoryError::Storage(
+                    "Unexpected error".to_string(),
+                ))
+            } else if self.conflicting {
+                Err(CommunityRepositoryError::Conflict)
             } else {
                 Ok(())
             }
         }
+
+        async fn save_in(
+            &self,
+            _unit_of_work: &mut dyn UnitOfWork,
+            community: &Community,
+        ) -> Result<(), CommunityRepositoryError> {
+            self.save(community).await
+        }
     }
 }
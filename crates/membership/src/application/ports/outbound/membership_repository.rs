@@ -1,5 +1,27 @@
-use crate::domain::entities::membership::Membership;
+use crate::{
+    application::errors::membership_repository::MembershipRepositoryError,
+    domain::{entities::membership::Membership, value_objects::community_id::CommunityId},
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
 
+#[async_trait]
 pub trait MembershipRepositoryPort: Send + Sync {
-    fn save(&self, membership: &Membership) -> Result<(), String>;
+    async fn find(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+    ) -> Result<Option<Membership>, MembershipRepositoryError>;
+
+    async fn list_by_community(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<Vec<(AccountId, Membership)>, MembershipRepositoryError>;
+
+    async fn save_membership(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+        membership: &Membership,
+    ) -> Result<(), MembershipRepositoryError>;
 }
@@ -1,2 +1,3 @@
+pub mod community_directory;
 pub mod community_repository;
 pub mod membership_repository;
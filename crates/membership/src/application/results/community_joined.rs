@@ -0,0 +1,4 @@
+pub struct CommunityJoined {
+    pub community_id: String,
+    pub status: String,
+}
@@ -1 +1,2 @@
 pub mod community_created;
+pub mod community_joined;
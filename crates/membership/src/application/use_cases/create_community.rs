@@ -1,18 +1,28 @@
-use std::sync::Arc;
-
+use crate::{
+    application::{
+        commands::create_community::CreateCommunity,
+        errors::{
+            application_error::ApplicationError, community_repository::CommunityRepositoryError,
+        },
+        ports::{
+            inbound::community_creation::CommunityCreationPort,
+            outbound::community_repository::CommunityRepositoryPort,
+        },
+        results::community_created::CommunityCreated,
+    },
+    domain::{
+        aggregates::community::Community,
+        value_objects::{
+            community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+        },
+    },
+};
+use async_trait::async_trait;
 use iam::domain::value_objects::AccountId;
-use shared::application::auth_context::AuthContext;
-use shared::application::common_application_error::CommonApplicationError;
-
-use crate::application::commands::create_community::CreateCommunity;
-use crate::application::errors::application_error::ApplicationError;
-use crate::application::ports::inbound::community_creation::CommunityCreationPort;
-use crate::application::ports::outbound::community_repository::CommunityRepositoryPort;
-use crate::application::results::community_created::CommunityCreated;
-use crate::domain::aggregates::community::Community;
-use crate::domain::value_objects::community_id::CommunityId;
-use crate::domain::value_objects::community_name::CommunityName;
-use crate::domain::value_objects::community_slug::CommunitySlug;
+use shared::application::{
+    auth_context::AuthContext, common_application_error::CommonApplicationError,
+};
+use std::sync::Arc;
 
 pub struct CreateCommunityUseCase {
     community_repository: Arc<dyn CommunityRepositoryPort>,
@@ -26,8 +36,9 @@ impl CreateCommunityUseCase {
     }
 }
 
+#[async_trait]
 impl CommunityCreationPort for CreateCommunityUseCase {
-    fn execute(
+    async fn execute(
         &self,
         data: CreateCommunity,
         auth: AuthContext,
@@ -35,7 +46,9 @@ impl CommunityCreationPort for CreateCommunityUseCase {
         let slug = CommunitySlug::new(data.slug).map_err(|_| ApplicationError::InvalidSlug)?;
         let existing_slug = self
             .community_repository
-            .find_by_slug(slug.clone().as_str());
+            .find_by_slug(slug.clone().as_str())
+            .await
+            .map_err(|_| CommonApplicationError::Infrastructure)?;
         if existing_slug.is_some() {
             return Err(ApplicationError::SlugAlreadyExists);
         }
@@ -49,7 +62,11 @@ impl CommunityCreationPort for CreateCommunityUseCase {
 
         self.community_repository
             .save(&community)
-            .map_err(|_| CommonApplicationError::Infrastructure)?;
+            .await
+            .map_err(|err| match err {
+                CommunityRepositoryError::SlugAlreadyExists => ApplicationError::SlugAlreadyExists,
+                _ => CommonApplicationError::Infrastructure.into(),
+            })?;
 
         Ok(CommunityCreated {
             id: community.id().as_uuid().to_string(),
@@ -61,17 +78,20 @@ impl CommunityCreationPort for CreateCommunityUseCase {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
+    use crate::application::{
+        commands::create_community::CreateCommunity,
+        errors::application_error::ApplicationError,
+        ports::{
+            inbound::community_creation::CommunityCreationPort,
+            outbound::community_repository::test_utils::FakeCommunityRepository,
+        },
+        use_cases::create_community::CreateCommunityUseCase,
+    };
     use iam::domain::value_objects::AccountId;
-    use shared::application::auth_context::AuthContext;
-    use shared::application::common_application_error::CommonApplicationError;
-
-    use crate::application::commands::create_community::CreateCommunity;
-    use crate::application::errors::application_error::ApplicationError;
-    use crate::application::ports::inbound::community_creation::CommunityCreationPort;
-    use crate::application::ports::outbound::community_repository::test_utils::FakeCommunityRepository;
-    use crate::application::use_cases::create_community::CreateCommunityUseCase;
+    use shared::application::{
+        auth_context::AuthContext, common_application_error::CommonApplicationError,
+    };
+    use std::sync::Arc;
 
     fn valid_auth_context() -> AuthContext {
         AuthContext {
@@ -87,19 +107,19 @@ mod tests {
         }
     }
 
-    #[test]
-    fn create_community_successfully() {
+    #[tokio::test]
+    async fn create_community_successfully() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
         let use_case = CreateCommunityUseCase::new(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn fails_when_name_is_invalid() {
+    #[tokio::test]
+    async fn fails_when_name_is_invalid() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
         let use_case = CreateCommunityUseCase::new(repo);
@@ -110,13 +130,13 @@ mod tests {
             is_public: false,
         };
 
-        let result = use_case.execute(input, valid_auth_context());
+        let result = use_case.execute(input, valid_auth_context()).await;
 
         assert!(matches!(result, Err(ApplicationError::InvalidName)));
     }
 
-    #[test]
-    fn fails_when_slug_is_invalid() {
+    #[tokio::test]
+    async fn fails_when_slug_is_invalid() {
         let repo = Arc::new(FakeCommunityRepository::success());
 
         let use_case = CreateCommunityUseCase::new(repo);
@@ -127,18 +147,18 @@ mod tests {
             is_public: false,
         };
 
-        let result = use_case.execute(input, valid_auth_context());
+        let result = use_case.execute(input, valid_auth_context()).await;
 
         assert!(matches!(result, Err(ApplicationError::InvalidSlug)));
     }
 
-    #[test]
-    fn fails_when_repository_fails() {
+    #[tokio::test]
+    async fn fails_when_repository_fails() {
         let repo = Arc::new(FakeCommunityRepository::fail());
 
         let use_case = CreateCommunityUseCase::new(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         assert!(matches!(
             result,
@@ -148,15 +168,15 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn fails_when_slug_already_exists() {
+    #[tokio::test]
+    async fn fails_when_slug_already_exists() {
         let repo = Arc::new(FakeCommunityRepository::with_existing_slug(
             "community-test",
         ));
 
         let use_case = CreateCommunityUseCase::new(repo);
 
-        let result = use_case.execute(valid_input(), valid_auth_context());
+        let result = use_case.execute(valid_input(), valid_auth_context()).await;
 
         assert!(matches!(result, Err(ApplicationError::SlugAlreadyExists)));
     }
@@ -0,0 +1,382 @@
+use crate::{
+    application::{
+        commands::join_community::JoinCommunity,
+        errors::{
+            application_error::ApplicationError, community_repository::CommunityRepositoryError,
+        },
+        ports::{
+            inbound::community_join::CommunityJoinPort,
+            outbound::{
+                community_directory::CommunityDirectoryPort,
+                community_repository::CommunityRepositoryPort,
+            },
+        },
+        results::community_joined::CommunityJoined,
+    },
+    domain::errors::community_error::CommunityError,
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::application::{
+    auth_context::AuthContext, common_application_error::CommonApplicationError,
+    errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWorkPort,
+};
+use std::sync::Arc;
+
+pub struct JoinCommunityUseCase {
+    community_directory: Arc<dyn CommunityDirectoryPort>,
+    community_repository: Arc<dyn CommunityRepositoryPort>,
+    unit_of_work: Arc<dyn UnitOfWorkPort>,
+}
+
+impl JoinCommunityUseCase {
+    pub fn new(
+        community_directory: Arc<dyn CommunityDirectoryPort>,
+        community_repository: Arc<dyn CommunityRepositoryPort>,
+        unit_of_work: Arc<dyn UnitOfWorkPort>,
+    ) -> Self {
+        Self {
+            community_directory,
+            community_repository,
+            unit_of_work,
+        }
+    }
+}
+
+#[async_trait]
+impl CommunityJoinPort for JoinCommunityUseCase {
+    async fn execute(
+        &self,
+        data: JoinCommunity,
+        auth: AuthContext,
+    ) -> Result<CommunityJoined, ApplicationError> {
+        let account_id = AccountId::from_str(auth.account_id.as_str())
+            .map_err(|_| CommonApplicationError::Unauthorized)?;
+        let listed = self
+            .community_directory
+            .find_by_slug(data.slug.trim())
+            .await
+            .map_err(|_| CommonApplicationError::Infrastructure)?
+            .ok_or(ApplicationError::CommunityNotFound)?;
+
+        let mut community = self
+            .community_repository
+            .find_by_id(&listed.id.as_uuid().to_string())
+            .await
+            .map_err(|_| CommonApplicationError::Infrastructure)?
+            .ok_or_else(|| {
+                tracing::error!(
+                    community_id = %listed.id.as_uuid(),
+                    "Listed community has no membership aggregate"
+                );
+                CommonApplicationError::Infrastructure
+            })?;
+
+        let status = community
+            .join(account_id, listed.membership_policy)
+            .map_err(|err| match err {
+                CommunityError::AlreadyMember => ApplicationError::AlreadyMember,
+                CommunityError::InvitationRequired => ApplicationError::InvitationRequired,
+                CommunityError::JoiningClosed => ApplicationError::JoiningClosed,
+                CommunityError::Banned => ApplicationError::Banned,
+                _ => CommonApplicationError::Infrastructure.into(),
+            })?;
+
+        let mut unit_of_work = self.unit_of_work.begin();
+        self.community_repository
+            .save_in(unit_of_work.as_mut(), &community)
+            .await
+            .map_err(|err| match err {
+                CommunityRepositoryError::Conflict => ApplicationError::Conflict,
+                _ => CommonApplicationError::Infrastructure.into(),
+            })?;
+        if status.can_interact() {
+            self.community_directory
+                .member_joined_in(unit_of_work.as_mut(), &listed.id)
+                .await
+                .map_err(|_| CommonApplicationError::Infrastructure)?;
+        }
+        unit_of_work.commit().await.map_err(|err| match err {
+            UnitOfWorkError::Conflict(_) => ApplicationError::Conflict,
+            UnitOfWorkError::Failed(_) => CommonApplicationError::Infrastructure.into(),
+        })?;
+
+        Ok(CommunityJoined {
+            community_id: listed.id.as_uuid().to_string(),
+            status: status.as_str().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::{
+            commands::join_community::JoinCommunity,
+            errors::application_error::ApplicationError,
+            ports::{
+                inbound::community_join::CommunityJoinPort,
+                outbound::{
+                    community_directory::test_utils::FakeCommunityDirectory,
+                    community_repository::{
+                        CommunityRepositoryPort, test_utils::FakeCommunityRepository,
+                    },
+                },
+            },
+            use_cases::join_community::JoinCommunityUseCase,
+        },
+        domain::aggregates::community::Community,
+        infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository,
+    };
+    use communities::domain::policies::membership_policy::MembershipPolicy;
+    use iam::domain::value_objects::AccountId;
+    use shared::{
+        application::{
+            auth_context::AuthContext, common_application_error::CommonApplicationError,
+        },
+        infrastructure::unit_of_work::InMemoryUnitOfWorkFactory,
+    };
+    use std::sync::Arc;
+
+    fn valid_auth_context() -> AuthContext {
+        AuthContext {
+            account_id: AccountId::generate().as_uuid().to_string(),
+        }
+    }
+
+    fn join() -> JoinCommunity {
+        JoinCommunity {
+            slug: "rust-community".to_string(),
+        }
+    }
+
+    async fn stored(community: &Community) -> Arc<InMemoryCommunityRepository> {
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        repository.save(community).await.unwrap();
+        repository
+    }
+
+    async fn use_case_for(
+        community: &Community,
+        membership_policy: MembershipPolicy,
+    ) -> (JoinCommunityUseCase, Arc<FakeCommunityDirectory>) {
+        let directory = Arc::new(FakeCommunityDirectory::of(community, membership_policy));
+        let use_case = JoinCommunityUseCase::new(
+            directory.clone(),
+            stored(community).await,
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+        (use_case, directory)
+    }
+
+    #[tokio::test]
+    async fn joining_open_community_counts_new_member() {
+        let (use_case, directory) =
+            use_case_for(&Community::dummy_community(), MembershipPolicy::Open).await;
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert_eq!(result.unwrap().status, "active");
+        assert_eq!(directory.joined.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn applying_to_community_awaits_approval() {
+        let (use_case, directory) = use_case_for(
+            &Community::dummy_community(),
+            MembershipPolicy::ByApplication,
+        )
+        .await;
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert_eq!(result.unwrap().status, "pending");
+        assert!(directory.joined.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fails_when_community_is_closed() {
+        let (use_case, _) =
+            use_case_for(&Community::dummy_community(), MembershipPolicy::Closed).await;
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(result, Err(ApplicationError::JoiningClosed)));
+    }
+
+    #[tokio::test]
+    async fn fails_when_invitation_is_missing() {
+        let (use_case, _) = use_case_for(
+            &Community::dummy_community(),
+            MembershipPolicy::ByInvitation,
+        )
+        .await;
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(result, Err(ApplicationError::InvitationRequired)));
+    }
+
+    #[tokio::test]
+    async fn fails_when_account_is_banned() {
+        let mut community = Community::dummy_private_community();
+        let owner_id = community.owner_id().clone();
+        let banned_id = AccountId::generate();
+        community
+            .join(banned_id.clone(), MembershipPolicy::ByApplication)
+            .unwrap();
+        community.activate_member(&owner_id, &banned_id).unwrap();
+        community.ban_member(&owner_id, &banned_id).unwrap();
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        repository.save(&community).await.unwrap();
+        let use_case = JoinCommunityUseCase::new(
+            Arc::new(FakeCommunityDirectory::of(
+                &community,
+                MembershipPolicy::Open,
+            )),
+            repository,
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case
+            .execute(
+                join(),
+                AuthContext {
+                    account_id: banned_id.as_uuid().to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Banned)));
+    }
+
+    #[tokio::test]
+    async fn applies_membership_policy_to_private_communities() {
+        let (open, _) = use_case_for(
+            &Community::dummy_private_community(),
+            MembershipPolicy::Open,
+        )
+        .await;
+        let (by_invitation, _) = use_case_for(
+            &Community::dummy_private_community(),
+            MembershipPolicy::ByInvitation,
+        )
+        .await;
+
+        let joined = open.execute(join(), valid_auth_context()).await;
+        let refused = by_invitation.execute(join(), valid_auth_context()).await;
+
+        assert_eq!(joined.unwrap().status, "active");
+        assert!(matches!(refused, Err(ApplicationError::InvitationRequired)));
+    }
+
+    #[tokio::test]
+    async fn fails_when_community_does_not_exist() {
+        let use_case = JoinCommunityUseCase::new(
+            Arc::new(FakeCommunityDirectory::empty()),
+            Arc::new(FakeCommunityRepository::success()),
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(result, Err(ApplicationError::CommunityNotFound)));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_membership_aggregate_is_missing() {
+        let directory = Arc::new(FakeCommunityDirectory::listing(
+            true,
+            MembershipPolicy::Open,
+        ));
+        let use_case = JoinCommunityUseCase::new(
+            directory.clone(),
+            Arc::new(FakeCommunityRepository::success()),
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Common(
+                CommonApplicationError::Infrastructure
+            ))
+        ));
+        assert!(directory.joined.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_concurrent_modifications_as_conflicts() {
+        let community = Community::dummy_community();
+        let use_case = JoinCommunityUseCase::new(
+            Arc::new(FakeCommunityDirectory::of(
+                &community,
+                MembershipPolicy::Open,
+            )),
+            Arc::new(FakeCommunityRepository::conflicting_with_existing_id(
+                &community.id().as_uuid().to_string(),
+            )),
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict)));
+    }
+
+    #[tokio::test]
+    async fn keeps_membership_unchanged_when_member_count_fails() {
+        let community = Community::dummy_private_community();
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        repository.save(&community).await.unwrap();
+        let use_case = JoinCommunityUseCase::new(
+            Arc::new(FakeCommunityDirectory::failing_to_count(
+                &community,
+                MembershipPolicy::Open,
+            )),
+            repository.clone(),
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Common(
+                CommonApplicationError::Infrastructure
+            ))
+        ));
+        let reloaded = repository
+            .find_by_id(&community.id().as_uuid().to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            reloaded.memberships().count(),
+            community.memberships().count()
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_repository_fails() {
+        let community = Community::dummy_community();
+        let use_case = JoinCommunityUseCase::new(
+            Arc::new(FakeCommunityDirectory::of(
+                &community,
+                MembershipPolicy::Open,
+            )),
+            Arc::new(FakeCommunityRepository::fail()),
+            Arc::new(InMemoryUnitOfWorkFactory::new()),
+        );
+
+        let result = use_case.execute(join(), valid_auth_context()).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Common(
+                CommonApplicationError::Infrastructure
+            ))
+        ));
+    }
+}
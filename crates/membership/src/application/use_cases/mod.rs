@@ -1 +1,2 @@
 pub mod create_community;
+pub mod join_community;
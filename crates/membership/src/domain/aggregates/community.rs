@@ -1,14 +1,14 @@
-use std::collections::HashMap;
-
+use crate::domain::{
+    entities::membership::Membership,
+    errors::community_error::CommunityError,
+    value_objects::{
+        community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+        membership_status::MembershipStatus, nickname::Nickname, role::Role,
+    },
+};
+use communities::domain::policies::membership_policy::MembershipPolicy;
 use iam::domain::value_objects::AccountId;
-
-use crate::domain::entities::membership::Membership;
-use crate::domain::errors::community_error::CommunityError;
-use crate::domain::value_objects::community_id::CommunityId;
-use crate::domain::value_objects::community_name::CommunityName;
-use crate::domain::value_objects::community_slug::CommunitySlug;
-use crate::domain::value_objects::nickname::Nickname;
-use crate::domain::value_objects::role::Role;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct Community {
@@ -18,6 +18,8 @@ pub struct Community {
     name: CommunityName,
     public: bool,
     memberships: HashMap<AccountId, Membership>,
+    changed_members: HashSet<AccountId>,
+    version: u64,
 }
 
 impl Community {
@@ -33,6 +35,27 @@ impl Community {
 
         memberships.insert(owner_id.clone(), Membership::owner(owner_nickname));
 
+        Self {
+            id,
+            changed_members: HashSet::from([owner_id.clone()]),
+            owner_id,
+            slug,
+            name,
+            public,
+            memberships,
+            version: 0,
+        }
+    }
+
+    pub fn reconstitute(
+        id: CommunityId,
+        owner_id: AccountId,
+        slug: CommunitySlug,
+        name: CommunityName,
+        public: bool,
+        memberships: HashMap<AccountId, Membership>,
+        version: u64,
+    ) -> Self {
         Self {
             id,
             owner_id,
@@ -40,6 +63,8 @@ impl Community {
             name,
             public,
             memberships,
+            changed_members: HashSet::new(),
+            version,
         }
     }
 
@@ -47,6 +72,10 @@ impl Community {
         &self.id
     }
 
+    pub fn owner_id(&self) -> &AccountId {
+        &self.owner_id
+    }
+
     pub fn slug(&self) -> &CommunitySlug {
         &self.slug
     }
@@ -59,6 +88,14 @@ impl Community {
         self.public
     }
 
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub(crate) fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
     pub fn is_member(&self, account_id: &AccountId) -> bool {
         self.memberships.contains_key(account_id)
     }
@@ -67,12 +104,50 @@ impl Community {
         self.memberships.get(account_id)
     }
 
+    pub fn memberships(&self) -> impl Iterator<Item = (&AccountId, &Membership)> {
+        self.memberships.iter()
+    }
+
+    pub fn changed_members(&self) -> impl Iterator<Item = &AccountId> {
+        self.changed_members.iter()
+    }
+
+    pub(crate) fn restore_membership(&mut self, account_id: AccountId, membership: Membership) {
+        self.changed_members.insert(account_id.clone());
+        self.memberships.insert(account_id, membership);
+    }
+
     pub fn add_member(
         &mut self,
         actor: &AccountId,
         new_member: AccountId,
         role: Role,
         nickname: Option<Nickname>,
+    ) -> Result<(), CommunityError> {
+        let membership = Membership::member(role, nickname)
+            .map_err(|_| CommunityError::InsufficientPermissions)?;
+
+        self.admit(actor, new_member, membership)
+    }
+
+    pub fn invite_member(
+        &mut self,
+        actor: &AccountId,
+        invitee: AccountId,
+        role: Role,
+        nickname: Option<Nickname>,
+    ) -> Result<(), CommunityError> {
+        let membership = Membership::invited(role, nickname)
+            .map_err(|_| CommunityError::InsufficientPermissions)?;
+
+        self.admit(actor, invitee, membership)
+    }
+
+    fn admit(
+        &mut self,
+        actor: &AccountId,
+        account_id: AccountId,
+        membership: Membership,
     ) -> Result<(), CommunityError> {
         let actor_membership = self
             .memberships
@@ -83,17 +158,63 @@ impl Community {
             return Err(CommunityError::InsufficientPermissions);
         }
 
-        if self.memberships.contains_key(&new_member) {
+        if self.memberships.contains_key(&account_id) {
             return Err(CommunityError::AlreadyMember);
         }
 
-        let membership = Membership::member(role, nickname)
-            .map_err(|_| CommunityError::InsufficientPermissions)?;
-
-        self.memberships.insert(new_member, membership);
+        self.changed_members.insert(account_id.clone());
+        self.memberships.insert(account_id, membership);
         Ok(())
     }
 
+    pub fn join(
+        &mut self,
+        account_id: AccountId,
+        policy: MembershipPolicy,
+    ) -> Result<MembershipStatus, CommunityError> {
+        if self
+            .memberships
+            .get(&account_id)
+            .is_some_and(|membership| membership.status() == MembershipStatus::Banned)
+        {
+            return Err(CommunityError::Banned);
+        }
+
+        if policy == MembershipPolicy::Closed {
+            return Err(CommunityError::JoiningClosed);
+        }
+
+        if let Some(membership) = self.memberships.get_mut(&account_id) {
+            if membership.status() != MembershipStatus::Invited {
+                return Err(CommunityError::AlreadyMember);
+            }
+
+            membership
+                .activate()
+                .map_err(|_| CommunityError::InvalidState)?;
+            let status = membership.status();
+            self.changed_members.insert(account_id);
+            return Ok(status);
+        }
+
+        let mut membership =
+            Membership::member(Role::Member, None).map_err(|_| CommunityError::InvalidState)?;
+        match policy {
+            MembershipPolicy::Open => membership
+                .activate()
+                .map_err(|_| CommunityError::InvalidState)?,
+            MembershipPolicy::ByApplication => {}
+            MembershipPolicy::ByInvitation | MembershipPolicy::Closed => {
+                return Err(CommunityError::InvitationRequired);
+            }
+        }
+
+        let status = membership.status();
+        self.changed_members.insert(account_id.clone());
+        self.memberships.insert(account_id, membership);
+        Ok(status)
+    }
+
     pub fn activate_member(
         &mut self,
         actor: &AccountId,
@@ -116,6 +237,7 @@ impl Community {
         membership
             .activate()
             .map_err(|_| CommunityError::InsufficientPermissions)?;
+        self.changed_members.insert(target.clone());
         Ok(())
     }
     pub fn change_member_role(
@@ -145,6 +267,7 @@ impl Community {
         membership
             .change_role(new_role)
             .map_err(|_| CommunityError::InsufficientPermissions)?;
+        self.changed_members.insert(target.clone());
 
         Ok(())
     }
@@ -169,6 +292,7 @@ impl Community {
         self.memberships
             .remove(target)
             .ok_or(CommunityError::NotMember)?;
+        self.changed_members.insert(target.clone());
 
         Ok(())
     }
@@ -199,6 +323,7 @@ impl Community {
         membership
             .suspend()
             .map_err(|_| CommunityError::InvalidState)?;
+        self.changed_members.insert(target.clone());
 
         Ok(())
     }
@@ -227,6 +352,7 @@ impl Community {
             .ok_or(CommunityError::NotMember)?;
 
         membership.ban().map_err(|_| CommunityError::InvalidState)?;
+        self.changed_members.insert(target.clone());
 
         Ok(())
     }
@@ -235,7 +361,6 @@ impl Community {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::value_objects::membership_status::MembershipStatus;
 
     impl Community {
         pub fn dummy_community() -> Community {
@@ -314,6 +439,102 @@ mod tests {
         assert_eq!(result, Err(CommunityError::AlreadyMember));
     }
 
+    #[test]
+    fn joining_open_community_activates_membership() {
+        let mut community = Community::dummy_community();
+        let account_id = AccountId::generate();
+
+        let result = community.join(account_id.clone(), MembershipPolicy::Open);
+
+        assert_eq!(result, Ok(MembershipStatus::Active));
+        assert_eq!(
+            community.join(account_id, MembershipPolicy::Open),
+            Err(CommunityError::AlreadyMember)
+        );
+    }
+
+    #[test]
+    fn joining_by_application_leaves_membership_pending() {
+        let mut community = Community::dummy_community();
+
+        let result = community.join(AccountId::generate(), MembershipPolicy::ByApplication);
+
+        assert_eq!(result, Ok(MembershipStatus::Pending));
+    }
+
+    #[test]
+    fn joining_by_invitation_requires_invitation() {
+        let mut community = Community::dummy_community();
+        let owner_id = community.owner_id.clone();
+        let invited_id = AccountId::generate();
+        community
+            .invite_member(&owner_id, invited_id.clone(), Role::Member, None)
+            .unwrap();
+
+        let stranger = community.join(AccountId::generate(), MembershipPolicy::ByInvitation);
+        let invited = community.join(invited_id, MembershipPolicy::ByInvitation);
+
+        assert_eq!(stranger, Err(CommunityError::InvitationRequired));
+        assert_eq!(invited, Ok(MembershipStatus::Active));
+    }
+
+    #[test]
+    fn pending_applicant_cannot_accept_own_application() {
+        let mut community = Community::dummy_community();
+        let applicant_id = AccountId::generate();
+        community
+            .join(applicant_id.clone(), MembershipPolicy::ByApplication)
+            .unwrap();
+
+        let result = community.join(applicant_id.clone(), MembershipPolicy::ByInvitation);
+
+        assert_eq!(result, Err(CommunityError::AlreadyMember));
+        assert_eq!(
+            community.member(&applicant_id).unwrap().status(),
+            MembershipStatus::Pending
+        );
+    }
+
+    #[test]
+    fn only_admins_can_invite() {
+        let mut community = Community::dummy_community();
+
+        let result = community.invite_member(
+            &AccountId::generate(),
+            AccountId::generate(),
+            Role::Member,
+            None,
+        );
+
+        assert_eq!(result, Err(CommunityError::NotMember));
+    }
+
+    #[test]
+    fn closed_community_cannot_be_joined() {
+        let mut community = Community::dummy_community();
+
+        let result = community.join(AccountId::generate(), MembershipPolicy::Closed);
+
+        assert_eq!(result, Err(CommunityError::JoiningClosed));
+    }
+
+    #[test]
+    fn banned_account_cannot_rejoin() {
+        let mut community = Community::dummy_community();
+        let owner_id = community.owner_id.clone();
+        let banned_id = AccountId::generate();
+        community
+            .join(banned_id.clone(), MembershipPolicy::Open)
+            .unwrap();
+        community.ban_member(&owner_id, &banned_id).unwrap();
+
+        let open = community.join(banned_id.clone(), MembershipPolicy::Open);
+        let invitation = community.join(banned_id, MembershipPolicy::ByInvitation);
+
+        assert_eq!(open, Err(CommunityError::Banned));
+        assert_eq!(invitation, Err(CommunityError::Banned));
+    }
+
     #[test]
     fn owner_can_activate_member() {
         let mut community = Community::dummy_community();
@@ -431,6 +652,30 @@ mod tests {
         assert!(!community.is_member(&member_id));
     }
 
+    #[test]
+    fn tracks_only_the_members_that_changed() {
+        let owner_id = AccountId::generate();
+        let mut community = Community::reconstitute(
+            CommunityId::generate(),
+            owner_id.clone(),
+            CommunitySlug::new("rust-community".to_string()).unwrap(),
+            CommunityName::new("Rust Community".to_string()).unwrap(),
+            true,
+            HashMap::from([(owner_id, Membership::owner(None))]),
+            1,
+        );
+        let joined = AccountId::generate();
+
+        community
+            .join(joined.clone(), MembershipPolicy::Open)
+            .unwrap();
+
+        assert_eq!(
+            community.changed_members().collect::<Vec<_>>(),
+            vec![&joined]
+        );
+    }
+
     #[test]
     fn cannot_remove_owner() {
         let mut community = Community::dummy_community();
@@ -1,8 +1,10 @@
-use crate::domain::errors::invalid_membership_status_transition::InvalidMembershipStatusTransition;
-use crate::domain::errors::membership_error::MembershipError;
-use crate::domain::value_objects::membership_status::MembershipStatus;
-use crate::domain::value_objects::nickname::Nickname;
-use crate::domain::value_objects::role::Role;
+use crate::domain::{
+    errors::{
+        invalid_membership_status_transition::InvalidMembershipStatusTransition,
+        membership_error::MembershipError,
+    },
+    value_objects::{membership_status::MembershipStatus, nickname::Nickname, role::Role},
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Membership {
@@ -32,6 +34,20 @@ impl Membership {
         })
     }
 
+    pub fn invited(role: Role, nickname: Option<Nickname>) -> Result<Self, MembershipError> {
+        let mut membership = Self::member(role, nickname)?;
+        membership.status = MembershipStatus::Invited;
+        Ok(membership)
+    }
+
+    pub fn reconstitute(role: Role, nickname: Option<Nickname>, status: MembershipStatus) -> Self {
+        Self {
+            role,
+            nickname,
+            status,
+        }
+    }
+
     pub fn role(&self) -> Role {
         self.role
     }
@@ -121,6 +137,15 @@ mod tests {
         assert_eq!(membership.status(), MembershipStatus::Pending);
     }
 
+    #[test]
+    fn invited_member_can_be_activated() {
+        let mut membership = Membership::invited(Role::Member, None).unwrap();
+
+        membership.activate().unwrap();
+
+        assert_eq!(membership.status(), MembershipStatus::Active);
+    }
+
     #[test]
     fn member_can_change_role() {
         let mut membership = Membership::member(Role::Member, None).unwrap();
@@ -6,4 +6,7 @@ pub enum CommunityError {
     CannotRemoveOwner,
     InsufficientPermissions,
     InvalidState,
+    InvitationRequired,
+    JoiningClosed,
+    Banned,
 }
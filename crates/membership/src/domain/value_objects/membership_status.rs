@@ -2,6 +2,7 @@ use crate::domain::errors::invalid_membership_status_transition::InvalidMembersh
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MembershipStatus {
+    Invited,
     Pending,
     Active,
     Suspended,
@@ -18,7 +19,8 @@ impl MembershipStatus {
 
         matches!(
             (self, next),
-            (Pending, Active)
+            (Invited, Active)
+                | (Pending, Active)
                 | (Active, Suspended)
                 | (Active, Banned)
                 | (Suspended, Active)
@@ -37,8 +39,20 @@ impl MembershipStatus {
         }
     }
 
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "invited" => Some(MembershipStatus::Invited),
+            "pending" => Some(MembershipStatus::Pending),
+            "active" => Some(MembershipStatus::Active),
+            "suspended" => Some(MembershipStatus::Suspended),
+            "banned" => Some(MembershipStatus::Banned),
+            _ => None,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
+            MembershipStatus::Invited => "invited",
             MembershipStatus::Pending => "pending",
             MembershipStatus::Active => "active",
             MembershipStatus::Suspended => "suspended",
@@ -59,6 +73,15 @@ mod tests {
         assert_eq!(next.unwrap(), MembershipStatus::Active);
     }
 
+    #[test]
+    fn invited_can_only_transition_to_active() {
+        let status = MembershipStatus::Invited;
+
+        assert!(status.can_transition_to(MembershipStatus::Active));
+        assert!(!status.can_transition_to(MembershipStatus::Pending));
+        assert!(!status.can_transition_to(MembershipStatus::Suspended));
+    }
+
     #[test]
     fn active_can_transition_to_suspended() {
         let status = MembershipStatus::Active;
@@ -103,6 +126,7 @@ mod tests {
     #[test]
     fn only_active_user_can_interact() {
         assert!(MembershipStatus::Active.can_interact());
+        assert!(!MembershipStatus::Invited.can_interact());
         assert!(!MembershipStatus::Pending.can_interact());
         assert!(!MembershipStatus::Suspended.can_interact());
         assert!(!MembershipStatus::Banned.can_interact());
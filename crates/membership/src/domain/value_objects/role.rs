@@ -6,6 +6,23 @@ pub enum Role {
 }
 
 impl Role {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "owner" => Some(Role::Owner),
+            "admin" => Some(Role::Admin),
+            "member" => Some(Role::Member),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Admin => "admin",
+            Role::Member => "member",
+        }
+    }
+
     pub fn can_manage_members(self) -> bool {
         matches!(self, Role::Owner | Role::Admin)
     }
@@ -0,0 +1,161 @@
+use crate::{
+    application::{
+        errors::community_directory::CommunityDirectoryError,
+        ports::outbound::community_directory::{CommunityDirectoryPort, ListedCommunity},
+    },
+    domain::value_objects::{
+        community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+    },
+};
+use async_trait::async_trait;
+use communities::{
+    application::ports::outbound::community_repository::CommunityRepositoryPort,
+    domain::{
+        aggregates::community::Community,
+        value_objects::{community_id, community_slug},
+    },
+};
+use shared::application::ports::unit_of_work::UnitOfWork;
+use std::sync::Arc;
+
+pub struct CommunityDirectoryAdapter {
+    communities: Arc<dyn CommunityRepositoryPort>,
+}
+
+impl CommunityDirectoryAdapter {
+    pub fn new(communities: Arc<dyn CommunityRepositoryPort>) -> Self {
+        Self { communities }
+    }
+
+    fn listed(community: &Community) -> Result<ListedCommunity, CommunityDirectoryError> {
+        let corrupted = || CommunityDirectoryError::Corrupted(community.id().as_uuid().to_string());
+
+        Ok(ListedCommunity {
+            id: CommunityId::from_uuid(*community.id().as_uuid()).map_err(|_| corrupted())?,
+            owner_id: community.owner_id().clone(),
+            slug: CommunitySlug::new(community.slug().as_str().to_string())
+                .map_err(|_| corrupted())?,
+            name: CommunityName::new(community.name().as_str().to_string())
+                .map_err(|_| corrupted())?,
+            public: community.is_public(),
+            membership_policy: community.effective_membership_policy(),
+        })
+    }
+}
+
+#[async_trait]
+impl CommunityDirectoryPort for CommunityDirectoryAdapter {
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<ListedCommunity>, CommunityDirectoryError> {
+        let Ok(slug) = community_slug::CommunitySlug::new(slug.to_string()) else {
+            return Ok(None);
+        };
+
+        let community = self
+            .communities
+            .find_by_slug(slug.as_str())
+            .await
+            .map_err(|e| CommunityDirectoryError::Unavailable(e.to_string()))?;
+
+        community.as_ref().map(Self::listed).transpose()
+    }
+
+    async fn member_joined_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community_id: &CommunityId,
+    ) -> Result<(), CommunityDirectoryError> {
+        let id = community_id::CommunityId::from_uuid(*community_id.as_uuid())
+            .map_err(|_| CommunityDirectoryError::Corrupted(community_id.as_uuid().to_string()))?;
+
+        self.communities
+            .record_member_joined_in(unit_of_work, &id)
+            .await
+            .map_err(|e| CommunityDirectoryError::Unavailable(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use communities::{
+        domain::{policies::membership_policy::MembershipPolicy, value_objects::community_name},
+        infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository,
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::{
+        application::ports::unit_of_work::UnitOfWorkPort,
+        infrastructure::{outbox::InMemoryOutboxStore, unit_of_work::InMemoryUnitOfWorkFactory},
+    };
+    use std::time::SystemTime;
+
+    fn community(public: bool, policy: MembershipPolicy) -> Community {
+        Community::create(
+            community_id::CommunityId::generate(),
+            AccountId::generate(),
+            community_slug::CommunitySlug::new("rust-community".to_string()).unwrap(),
+            community_name::CommunityName::new("Rust Community".to_string()).unwrap(),
+            public,
+            policy,
+            SystemTime::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn lists_communities_with_their_membership_policy() {
+        let communities = Arc::new(InMemoryCommunityRepository::new(Arc::new(
+            InMemoryOutboxStore::new(),
+        )));
+        let saved = community(false, MembershipPolicy::ByApplication);
+        communities.save(&saved).await.unwrap();
+        let directory = CommunityDirectoryAdapter::new(communities);
+
+        let listed = directory
+            .find_by_slug("Rust-Community")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(listed.id.as_uuid(), saved.id().as_uuid());
+        assert!(!listed.public);
+        assert_eq!(listed.membership_policy, MembershipPolicy::ByApplication);
+        assert!(
+            directory
+                .find_by_slug("not a slug")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn counts_joined_members() {
+        let communities = Arc::new(InMemoryCommunityRepository::new(Arc::new(
+            InMemoryOutboxStore::new(),
+        )));
+        let saved = community(true, MembershipPolicy::Open);
+        communities.save(&saved).await.unwrap();
+        let directory = CommunityDirectoryAdapter::new(communities.clone());
+        let listed = directory
+            .find_by_slug("rust-community")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+        directory
+            .member_joined_in(work.as_mut(), &listed.id)
+            .await
+            .unwrap();
+        work.commit().await.unwrap();
+
+        let reloaded = communities
+            .find_by_id(&saved.id().as_uuid().to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reloaded.member_count(), saved.member_count() + 1);
+    }
+}
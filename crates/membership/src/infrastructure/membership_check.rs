@@ -0,0 +1,121 @@
+use crate::{
+    application::ports::outbound::membership_repository::MembershipRepositoryPort,
+    domain::{entities::membership::Membership, value_objects::community_id::CommunityId},
+};
+use async_trait::async_trait;
+use communities::{
+    application::{
+        errors::membership_check::MembershipCheckError,
+        ports::outbound::membership_check::MembershipCheckPort,
+    },
+    domain::value_objects::community_id,
+};
+use iam::domain::value_objects::AccountId;
+use std::sync::Arc;
+
+pub struct MembershipCheckAdapter {
+    memberships: Arc<dyn MembershipRepositoryPort>,
+}
+
+impl MembershipCheckAdapter {
+    pub fn new(memberships: Arc<dyn MembershipRepositoryPort>) -> Self {
+        Self { memberships }
+    }
+
+    async fn active_membership(
+        &self,
+        community: &community_id::CommunityId,
+        account_id: &AccountId,
+    ) -> Result<Option<Membership>, MembershipCheckError> {
+        let Ok(community_id) = CommunityId::from_uuid(*community.as_uuid()) else {
+            return Ok(None);
+        };
+
+        let membership = self
+            .memberships
+            .find(&community_id, account_id)
+            .await
+            .map_err(|e| MembershipCheckError::Unavailable(e.to_string()))?;
+
+        Ok(membership.filter(|membership| membership.status().can_interact()))
+    }
+}
+
+#[async_trait]
+impl MembershipCheckPort for MembershipCheckAdapter {
+    async fn is_member(
+        &self,
+        community: &community_id::CommunityId,
+        account_id: &AccountId,
+    ) -> Result<bool, MembershipCheckError> {
+        let membership = self.active_membership(community, account_id).await?;
+
+        Ok(membership.is_some())
+    }
+
+    async fn is_admin(
+        &self,
+        community: &community_id::CommunityId,
+        account_id: &AccountId,
+    ) -> Result<bool, MembershipCheckError> {
+        let membership = self.active_membership(community, account_id).await?;
+
+        Ok(membership.is_some_and(|membership| membership.role().can_manage_members()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::ports::outbound::community_repository::CommunityRepositoryPort,
+        domain::{aggregates::community::Community, value_objects::role::Role},
+        infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository,
+    };
+
+    fn communities_id(community: &Community) -> community_id::CommunityId {
+        community_id::CommunityId::from_uuid(*community.id().as_uuid()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn only_active_memberships_count() {
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        let mut community = Community::dummy_private_community();
+        let owner = community.owner_id().clone();
+        let pending = AccountId::generate();
+        community
+            .add_member(&owner, pending.clone(), Role::Member, None)
+            .unwrap();
+        repository.save(&community).await.unwrap();
+        let check = MembershipCheckAdapter::new(repository);
+        let id = communities_id(&community);
+
+        assert!(check.is_member(&id, &owner).await.unwrap());
+        assert!(!check.is_member(&id, &pending).await.unwrap());
+        assert!(!check.is_member(&id, &AccountId::generate()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn owners_and_admins_count_as_admins() {
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        let mut community = Community::dummy_private_community();
+        let owner = community.owner_id().clone();
+        let admin = AccountId::generate();
+        let member = AccountId::generate();
+        community
+            .add_member(&owner, admin.clone(), Role::Admin, None)
+            .unwrap();
+        community.activate_member(&owner, &admin).unwrap();
+        community
+            .add_member(&owner, member.clone(), Role::Member, None)
+            .unwrap();
+        community.activate_member(&owner, &member).unwrap();
+        repository.save(&community).await.unwrap();
+        let check = MembershipCheckAdapter::new(repository);
+        let id = communities_id(&community);
+
+        assert!(check.is_admin(&id, &owner).await.unwrap());
+        assert!(check.is_admin(&id, &admin).await.unwrap());
+        assert!(!check.is_admin(&id, &member).await.unwrap());
+    }
+}
@@ -1 +1,4 @@
+pub mod community_directory;
+pub mod membership_check;
+pub mod owner_membership;
 pub mod persistence;
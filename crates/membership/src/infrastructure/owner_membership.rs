@@ -0,0 +1,110 @@
+use crate::{
+    application::ports::outbound::community_repository::CommunityRepositoryPort,
+    domain::{
+        aggregates::community::Community,
+        value_objects::{
+            community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+        },
+    },
+};
+use async_trait::async_trait;
+use communities::{
+    application::{
+        errors::owner_membership::OwnerMembershipError,
+        ports::outbound::owner_membership::OwnerMembershipPort,
+    },
+    domain::aggregates::community,
+};
+use shared::application::ports::unit_of_work::UnitOfWork;
+use std::sync::Arc;
+
+pub struct OwnerMembershipAdapter {
+    communities: Arc<dyn CommunityRepositoryPort>,
+}
+
+impl OwnerMembershipAdapter {
+    pub fn new(communities: Arc<dyn CommunityRepositoryPort>) -> Self {
+        Self { communities }
+    }
+
+    fn founded(community: &community::Community) -> Result<Community, OwnerMembershipError> {
+        let invalid = || {
+            OwnerMembershipError::Unavailable(format!(
+                "invalid community {}",
+                community.id().as_uuid()
+            ))
+        };
+
+        Ok(Community::create(
+            CommunityId::from_uuid(*community.id().as_uuid()).map_err(|_| invalid())?,
+            community.owner_id().clone(),
+            CommunitySlug::new(community.slug().as_str().to_string()).map_err(|_| invalid())?,
+            CommunityName::new(community.name().as_str().to_string()).map_err(|_| invalid())?,
+            community.is_public(),
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl OwnerMembershipPort for OwnerMembershipAdapter {
+    async fn register_owner_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &community::Community,
+    ) -> Result<(), OwnerMembershipError> {
+        let founded = Self::founded(community)?;
+
+        self.communities
+            .save_in(unit_of_work, &founded)
+            .await
+            .map_err(|e| OwnerMembershipError::Unavailable(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::membership_status::MembershipStatus,
+        infrastructure::persistence::in_memory::community_repository::InMemoryCommunityRepository,
+    };
+    use communities::domain::{
+        policies::membership_policy::MembershipPolicy,
+        value_objects::{community_id, community_name, community_slug},
+    };
+    use iam::domain::value_objects::AccountId;
+    use shared::{
+        application::ports::unit_of_work::UnitOfWorkPort,
+        infrastructure::unit_of_work::InMemoryUnitOfWorkFactory,
+    };
+    use std::time::SystemTime;
+
+    #[tokio::test]
+    async fn registers_the_owner_when_the_unit_of_work_commits() {
+        let repository = Arc::new(InMemoryCommunityRepository::new());
+        let adapter = OwnerMembershipAdapter::new(repository.clone());
+        let community = community::Community::create(
+            community_id::CommunityId::generate(),
+            AccountId::generate(),
+            community_slug::CommunitySlug::new("rust-community".to_string()).unwrap(),
+            community_name::CommunityName::new("Rust Community".to_string()).unwrap(),
+            false,
+            MembershipPolicy::ByInvitation,
+            SystemTime::now(),
+        );
+        let id = community.id().as_uuid().to_string();
+        let mut work = InMemoryUnitOfWorkFactory::new().begin();
+
+        adapter
+            .register_owner_in(work.as_mut(), &community)
+            .await
+            .unwrap();
+        assert!(repository.find_by_id(&id).await.unwrap().is_none());
+        work.commit().await.unwrap();
+
+        let founded = repository.find_by_id(&id).await.unwrap().unwrap();
+        let owner = founded.member(community.owner_id()).unwrap();
+        assert_eq!(owner.status(), MembershipStatus::Active);
+    }
+}
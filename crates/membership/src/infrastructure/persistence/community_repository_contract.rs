@@ -0,0 +1,301 @@
+use crate::domain::{
+    aggregates::community::Community,
+    value_objects::{
+        community_id::CommunityId, community_name::CommunityName, community_slug::CommunitySlug,
+        nickname::Nickname,
+    },
+};
+use iam::domain::value_objects::AccountId;
+
+pub fn community(slug: &str, name: &str) -> Community {
+    Community::create(
+        CommunityId::generate(),
+        AccountId::generate(),
+        CommunitySlug::new(slug.to_string()).unwrap(),
+        CommunityName::new(name.to_string()).unwrap(),
+        true,
+        Some(Nickname::new("founder".to_string()).unwrap()),
+    )
+}
+
+macro_rules! community_repository_contract {
+    ($repository:expr, $with_unit_of_work:expr) => {
+        mod contract {
+            use super::*;
+            use crate::{
+                application::{
+                    errors::{
+                        community_repository::CommunityRepositoryError,
+                        membership_repository::MembershipRepositoryError,
+                    },
+                    ports::outbound::{
+                        community_repository::CommunityRepositoryPort,
+                        membership_repository::MembershipRepositoryPort,
+                    },
+                },
+                domain::{
+                    entities::membership::Membership,
+                    value_objects::{
+                        community_id::CommunityId, membership_status::MembershipStatus,
+                        nickname::Nickname, role::Role,
+                    },
+                },
+                infrastructure::persistence::community_repository_contract::community,
+            };
+            use iam::domain::value_objects::AccountId;
+            use shared::application::{
+                errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWorkPort,
+            };
+
+            #[tokio::test]
+            async fn finds_saved_community_with_owner_membership() {
+                let repository = $repository;
+                let saved = community("rust-lang", "Rust Lang");
+
+                repository.save(&saved).await.unwrap();
+
+                let by_id = repository
+                    .find_by_id(&saved.id().as_uuid().to_string())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                let by_slug = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                let owner = by_slug.member(saved.owner_id()).unwrap();
+
+                assert_eq!(by_id.id(), saved.id());
+                assert_eq!(by_slug.owner_id(), saved.owner_id());
+                assert_eq!(owner.role(), Role::Owner);
+                assert_eq!(owner.status(), MembershipStatus::Active);
+                assert_eq!(owner.nickname().unwrap().as_str(), "founder");
+            }
+
+            #[tokio::test]
+            async fn returns_none_for_unknown_communities() {
+                let repository = $repository;
+
+                assert!(repository.find_by_slug("ghost").await.unwrap().is_none());
+                assert!(repository.find_by_id("not-an-id").await.unwrap().is_none());
+            }
+
+            #[tokio::test]
+            async fn persists_membership_changes() {
+                let repository = $repository;
+                let created = community("rust-lang", "Rust Lang");
+                let owner_id = created.owner_id().clone();
+                let admin_id = AccountId::generate();
+                let removed_id = AccountId::generate();
+                repository.save(&created).await.unwrap();
+
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                saved
+                    .add_member(&owner_id, admin_id.clone(), Role::Member, None)
+                    .unwrap();
+                saved
+                    .add_member(&owner_id, removed_id.clone(), Role::Member, None)
+                    .unwrap();
+                repository.save(&saved).await.unwrap();
+
+                let mut saved = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                saved.activate_member(&owner_id, &admin_id).unwrap();
+                saved
+                    .change_member_role(&owner_id, &admin_id, Role::Admin)
+                    .unwrap();
+                saved.remove_member(&owner_id, &removed_id).unwrap();
+                repository.save(&saved).await.unwrap();
+
+                let found = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                let admin = found.member(&admin_id).unwrap();
+                assert_eq!(admin.role(), Role::Admin);
+                assert_eq!(admin.status(), MembershipStatus::Active);
+                assert!(!found.is_member(&removed_id));
+                assert_eq!(found.memberships().count(), 2);
+                assert_eq!(found.version(), 3);
+            }
+
+            #[tokio::test]
+            async fn rejects_stale_writes() {
+                let repository = $repository;
+                let created = community("rust-lang", "Rust Lang");
+                let owner_id = created.owner_id().clone();
+                let first_member = AccountId::generate();
+                let second_member = AccountId::generate();
+                repository.save(&created).await.unwrap();
+                let mut first = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                let mut second = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                first
+                    .add_member(&owner_id, first_member.clone(), Role::Member, None)
+                    .unwrap();
+                repository.save(&first).await.unwrap();
+                second
+                    .add_member(&owner_id, second_member.clone(), Role::Member, None)
+                    .unwrap();
+                let result = repository.save(&second).await;
+
+                let found = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+                assert_eq!(result, Err(CommunityRepositoryError::Conflict));
+                assert!(found.is_member(&first_member));
+                assert!(!found.is_member(&second_member));
+            }
+
+            #[tokio::test]
+            async fn saving_a_membership_invalidates_loaded_copies() {
+                let repository = $repository;
+                let created = community("rust-lang", "Rust Lang");
+                repository.save(&created).await.unwrap();
+                let loaded = repository.find_by_slug("rust-lang").await.unwrap().unwrap();
+
+                repository
+                    .save_membership(
+                        created.id(),
+                        &AccountId::generate(),
+                        &Membership::member(Role::Member, None).unwrap(),
+                    )
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    repository.save(&loaded).await,
+                    Err(CommunityRepositoryError::Conflict)
+                );
+            }
+
+            #[tokio::test]
+            async fn rejects_another_community_with_the_same_slug() {
+                let repository = $repository;
+                repository
+                    .save(&community("rust-lang", "Rust Lang"))
+                    .await
+                    .unwrap();
+
+                let result = repository.save(&community("rust-lang", "Other Rust")).await;
+
+                assert_eq!(result, Err(CommunityRepositoryError::SlugAlreadyExists));
+            }
+
+            #[tokio::test]
+            async fn finds_single_membership_by_community_and_account() {
+                let repository = $repository;
+                let saved = community("rust-lang", "Rust Lang");
+                repository.save(&saved).await.unwrap();
+
+                let owner = repository.find(saved.id(), saved.owner_id()).await.unwrap();
+                let stranger = repository
+                    .find(saved.id(), &AccountId::generate())
+                    .await
+                    .unwrap();
+
+                assert_eq!(owner.unwrap().role(), Role::Owner);
+                assert!(stranger.is_none());
+            }
+
+            #[tokio::test]
+            async fn saves_and_lists_memberships_of_a_community() {
+                let repository = $repository;
+                let saved = community("rust-lang", "Rust Lang");
+                let other = community("go-lang", "Go Lang");
+                let member_id = AccountId::generate();
+                let nickname = Nickname::new("gopher".to_string()).unwrap();
+                repository.save(&saved).await.unwrap();
+                repository.save(&other).await.unwrap();
+
+                repository
+                    .save_membership(
+                        saved.id(),
+                        &member_id,
+                        &Membership::reconstitute(
+                            Role::Member,
+                            Some(nickname),
+                            MembershipStatus::Suspended,
+                        ),
+                    )
+                    .await
+                    .unwrap();
+
+                let memberships = repository.list_by_community(saved.id()).await.unwrap();
+                let member = repository
+                    .find(saved.id(), &member_id)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(memberships.len(), 2);
+                assert_eq!(member.status(), MembershipStatus::Suspended);
+                assert_eq!(member.nickname().unwrap().as_str(), "gopher");
+                assert_eq!(
+                    repository
+                        .list_by_community(other.id())
+                        .await
+                        .unwrap()
+                        .len(),
+                    1
+                );
+            }
+
+            #[tokio::test]
+            async fn rejects_membership_for_unknown_community() {
+                let repository = $repository;
+
+                let result = repository
+                    .save_membership(
+                        &CommunityId::generate(),
+                        &AccountId::generate(),
+                        &Membership::owner(None),
+                    )
+                    .await;
+
+                assert_eq!(result, Err(MembershipRepositoryError::CommunityNotFound));
+            }
+
+            #[tokio::test]
+            async fn save_in_writes_only_when_the_unit_of_work_commits() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let saved = community("rust-lang", "Rust Lang");
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &saved).await.unwrap();
+                assert!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+                work.commit().await.unwrap();
+
+                assert_eq!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .version(),
+                    1
+                );
+            }
+
+            #[tokio::test]
+            async fn failing_step_undoes_earlier_steps() {
+                let (repository, unit_of_work) = $with_unit_of_work;
+                let existing = community("go-lang", "Go Lang");
+                repository.save(&existing).await.unwrap();
+                let created = community("rust-lang", "Rust Lang");
+                let mut work = unit_of_work.begin();
+
+                repository.save_in(work.as_mut(), &created).await.unwrap();
+                repository.save_in(work.as_mut(), &existing).await.unwrap();
+                let result = work.commit().await;
+
+                assert!(matches!(result, Err(UnitOfWorkError::Conflict(_))));
+                assert!(
+                    repository
+                        .find_by_slug("rust-lang")
+                        .await
+                        .unwrap()
+                        .is_none()
+                );
+            }
+        }
+    };
+}
+
+pub(crate) use community_repository_contract;
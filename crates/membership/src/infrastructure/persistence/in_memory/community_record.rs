@@ -0,0 +1,97 @@
+use crate::{
+    application::errors::community_repository::CommunityRepositoryError,
+    domain::{
+        aggregates::community::Community,
+        entities::membership::Membership,
+        value_objects::{
+            community_id::CommunityId, community_name::CommunityName,
+            community_slug::CommunitySlug, membership_status::MembershipStatus, nickname::Nickname,
+            role::Role,
+        },
+    },
+};
+use iam::domain::value_objects::AccountId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct CommunityRecord {
+    id: String,
+    owner_id: String,
+    slug: String,
+    name: String,
+    public: bool,
+    memberships: Vec<MembershipRecord>,
+    version: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MembershipRecord {
+    account_id: String,
+    role: String,
+    status: String,
+    nickname: Option<String>,
+}
+
+impl From<&Community> for CommunityRecord {
+    fn from(community: &Community) -> Self {
+        Self {
+            id: community.id().as_uuid().to_string(),
+            owner_id: community.owner_id().as_uuid().to_string(),
+            slug: community.slug().as_str().to_string(),
+            name: community.name().as_str().to_string(),
+            public: community.is_public(),
+            memberships: community
+                .memberships()
+                .map(|(account_id, membership)| MembershipRecord {
+                    account_id: account_id.as_uuid().to_string(),
+                    role: membership.role().as_str().to_string(),
+                    status: membership.status().as_str().to_string(),
+                    nickname: membership
+                        .nickname()
+                        .map(|nickname| nickname.as_str().to_string()),
+                })
+                .collect(),
+            version: community.version(),
+        }
+    }
+}
+
+impl TryFrom<CommunityRecord> for Community {
+    type Error = CommunityRepositoryError;
+
+    fn try_from(record: CommunityRecord) -> Result<Self, Self::Error> {
+        let corrupted = || CommunityRepositoryError::Corrupted(record.id.clone());
+
+        let memberships = record
+            .memberships
+            .iter()
+            .map(|membership| {
+                let nickname = membership
+                    .nickname
+                    .clone()
+                    .map(|nickname| Nickname::new(nickname).map_err(|_| corrupted()))
+                    .transpose()?;
+
+                Ok((
+                    AccountId::from_str(&membership.account_id).map_err(|_| corrupted())?,
+                    Membership::reconstitute(
+                        Role::parse(&membership.role).ok_or_else(corrupted)?,
+                        nickname,
+                        MembershipStatus::parse(&membership.status).ok_or_else(corrupted)?,
+                    ),
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, CommunityRepositoryError>>()?;
+
+        Ok(Community::reconstitute(
+            CommunityId::from_str(&record.id).map_err(|_| corrupted())?,
+            AccountId::from_str(&record.owner_id).map_err(|_| corrupted())?,
+            CommunitySlug::new(record.slug.clone()).map_err(|_| corrupted())?,
+            CommunityName::new(record.name.clone()).map_err(|_| corrupted())?,
+            record.public,
+            memberships,
+            record.version,
+        ))
+    }
+}
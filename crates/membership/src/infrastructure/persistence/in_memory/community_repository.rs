@@ -1,44 +1,370 @@
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use super::community_record::CommunityRecord;
+use crate::{
+    application::{
+        errors::{
+            community_repository::CommunityRepositoryError,
+            membership_repository::MembershipRepositoryError,
+        },
+        ports::outbound::{
+            community_repository::CommunityRepositoryPort,
+            membership_repository::MembershipRepositoryPort,
+        },
+    },
+    domain::{
+        aggregates::community::Community, entities::membership::Membership,
+        value_objects::community_id::CommunityId,
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use shared::{
+    application::{errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWork},
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::json_snapshot::{
+            JsonSnapshotStore, LogEntry, Recovered, SnapshotError, SnapshotSource,
+        },
+        unit_of_work::{InMemoryUnitOfWork, Undo},
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
-use crate::application::ports::outbound::community_repository::CommunityRepositoryPort;
-use crate::domain::aggregates::community::Community;
-use crate::domain::value_objects::community_id::CommunityId;
+#[derive(Default)]
+struct Communities {
+    by_id: HashMap<CommunityId, Community>,
+    by_slug: HashMap<String, CommunityId>,
+    log: Option<Arc<JsonSnapshotStore>>,
+}
+
+impl Communities {
+    fn insert(&mut self, community: Community) -> Option<Community> {
+        let previous = self.remove(community.id());
+
+        self.by_slug.insert(
+            community.slug().as_str().to_string(),
+            community.id().clone(),
+        );
+        self.by_id.insert(community.id().clone(), community);
+
+        previous
+    }
+
+    fn remove(&mut self, id: &CommunityId) -> Option<Community> {
+        let removed = self.by_id.remove(id)?;
+        self.by_slug.remove(removed.slug().as_str());
+
+        Some(removed)
+    }
+
+    fn restore(
+        &mut self,
+        id: &CommunityId,
+        written_version: u64,
+        previous: Option<Community>,
+    ) -> Result<(), SnapshotError> {
+        if self.by_id.get(id).map(Community::version) != Some(written_version) {
+            return Ok(());
+        }
+
+        match previous {
+            Some(previous) => {
+                self.log(LogEntry::Put(CommunityRecord::from(&previous)))?;
+                self.insert(previous);
+            }
+            None => {
+                self.log(LogEntry::Remove(id.as_uuid().to_string()))?;
+                self.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    fn log(&self, entry: LogEntry<CommunityRecord>) -> Result<(), SnapshotError> {
+        match &self.log {
+            Some(log) => log.append(&entry),
+            None => Ok(()),
+        }
+    }
+}
 
+#[derive(Default)]
 pub struct InMemoryCommunityRepository {
-    communities: Arc<Mutex<HashMap<CommunityId, Community>>>,
+    communities: Arc<RwLock<Communities>>,
 }
 
 impl InMemoryCommunityRepository {
     pub fn new() -> Self {
-        Self {
-            communities: Arc::new(Mutex::new(HashMap::new())),
+        Self::default()
+    }
+
+    pub fn with_snapshots(snapshots: Arc<JsonSnapshotStore>) -> Result<Self, SnapshotError> {
+        let recovered: Recovered<Vec<CommunityRecord>, LogEntry<CommunityRecord>> =
+            snapshots.load()?;
+        let corrupted = |e: CommunityRepositoryError| SnapshotError::Corrupted(e.to_string());
+
+        let mut communities = Communities::default();
+        for record in recovered.snapshot.into_iter().flatten() {
+            communities.insert(Community::try_from(record).map_err(corrupted)?);
+        }
+        for entry in recovered.log {
+            match entry {
+                LogEntry::Put(record) => {
+                    communities.insert(Community::try_from(record).map_err(corrupted)?);
+                }
+                LogEntry::Remove(id) => {
+                    let community_id = CommunityId::from_str(&id).map_err(|_| {
+                        SnapshotError::Corrupted(format!("Corrupted community record {}", id))
+                    })?;
+                    communities.remove(&community_id);
+                }
+            }
         }
+        communities.log = Some(snapshots);
+
+        Ok(Self {
+            communities: Arc::new(RwLock::new(communities)),
+        })
     }
 }
 
-impl CommunityRepositoryPort for InMemoryCommunityRepository {
-    fn find_by_id(&self, id: &str) -> Option<Community> {
-        let communities = self.communities.lock().expect("mutex poisoned");
+impl InMemoryCommunityRepository {
+    fn store(
+        communities: &RwLock<Communities>,
+        community: &Community,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let mut communities = communities.write().expect("lock poisoned");
+
+        let slug_taken = communities
+            .by_slug
+            .get(community.slug().as_str())
+            .is_some_and(|id| id != community.id());
+        if slug_taken {
+            return Err(CommunityRepositoryError::SlugAlreadyExists);
+        }
+
+        let stored_version = communities
+            .by_id
+            .get(community.id())
+            .map_or(0, Community::version);
+        if stored_version != community.version() {
+            return Err(CommunityRepositoryError::Conflict);
+        }
+
+        let mut stored = community.clone();
+        stored.increment_version();
         communities
-            .values()
-            .find(|community| community.id().as_uuid().to_string() == id)
-            .cloned()
+            .log(LogEntry::Put(CommunityRecord::from(&stored)))
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        Ok(communities.insert(stored))
     }
 
-    fn find_by_slug(&self, slug: &str) -> Option<Community> {
-        let communities = self.communities.lock().expect("mutex poisoned");
-        communities
-            .values()
-            .find(|community| community.slug().as_str() == slug)
+    fn store_membership(
+        communities: &RwLock<Communities>,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+        membership: &Membership,
+    ) -> Result<(), MembershipRepositoryError> {
+        let mut communities = communities.write().expect("lock poisoned");
+
+        let mut community = communities
+            .by_id
+            .get(community_id)
             .cloned()
+            .ok_or(MembershipRepositoryError::CommunityNotFound)?;
+        community.restore_membership(account_id.clone(), membership.clone());
+        community.increment_version();
+        communities
+            .log(LogEntry::Put(CommunityRecord::from(&community)))
+            .map_err(|e| MembershipRepositoryError::Storage(e.to_string()))?;
+        communities.insert(community);
+
+        Ok(())
     }
+}
 
-    fn save(&self, community: &Community) -> Result<(), String> {
-        let mut communities = self.communities.lock().expect("mutex poisoned");
+#[async_trait]
+impl CommunityRepositoryPort for InMemoryCommunityRepository {
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError> {
+        let Ok(id) = CommunityId::from_str(id) else {
+            return Ok(None);
+        };
+        let communities = self.communities.read().expect("lock poisoned");
+        Ok(communities.by_id.get(&id).cloned())
+    }
 
-        communities.insert(community.id().clone(), community.clone());
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let communities = self.communities.read().expect("lock poisoned");
+        Ok(communities
+            .by_slug
+            .get(slug)
+            .and_then(|id| communities.by_id.get(id))
+            .cloned())
+    }
+
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
+        let communities = self.communities.clone();
+        let community = community.clone();
+
+        run_blocking(move || Self::store(&communities, &community).map(|_| ())).await
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = InMemoryUnitOfWork::of(unit_of_work)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let communities = self.communities.clone();
+        let community = community.clone();
+
+        unit_of_work.register(move || {
+            let previous = Self::store(&communities, &community).map_err(|e| match e {
+                CommunityRepositoryError::Conflict => UnitOfWorkError::Conflict(e.to_string()),
+                _ => UnitOfWorkError::Failed(e.to_string()),
+            })?;
+            let id = community.id().clone();
+            let written_version = community.version() + 1;
+            Ok(Box::new(move || {
+                communities
+                    .write()
+                    .expect("lock poisoned")
+                    .restore(&id, written_version, previous)
+                    .map_err(|e| UnitOfWorkError::Failed(e.to_string()))
+            }) as Undo)
+        });
 
         Ok(())
     }
 }
+
+#[async_trait]
+impl MembershipRepositoryPort for InMemoryCommunityRepository {
+    async fn find(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+    ) -> Result<Option<Membership>, MembershipRepositoryError> {
+        let communities = self.communities.read().expect("lock poisoned");
+
+        Ok(communities
+            .by_id
+            .get(community_id)
+            .and_then(|community| community.member(account_id))
+            .cloned())
+    }
+
+    async fn list_by_community(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<Vec<(AccountId, Membership)>, MembershipRepositoryError> {
+        let communities = self.communities.read().expect("lock poisoned");
+
+        Ok(communities
+            .by_id
+            .get(community_id)
+            .map(|community| {
+                community
+                    .memberships()
+                    .map(|(account_id, membership)| (account_id.clone(), membership.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn save_membership(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+        membership: &Membership,
+    ) -> Result<(), MembershipRepositoryError> {
+        let communities = self.communities.clone();
+        let community_id = community_id.clone();
+        let account_id = account_id.clone();
+        let membership = membership.clone();
+
+        run_blocking(move || {
+            Self::store_membership(&communities, &community_id, &account_id, &membership)
+        })
+        .await
+    }
+}
+
+impl SnapshotSource for InMemoryCommunityRepository {
+    fn write_snapshot(&self) -> Result<(), SnapshotError> {
+        let communities = self.communities.read().expect("lock poisoned");
+        let Some(log) = &communities.log else {
+            return Ok(());
+        };
+
+        log.write_snapshot(|| {
+            communities
+                .by_id
+                .values()
+                .map(CommunityRecord::from)
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::{membership_status::MembershipStatus, role::Role},
+        infrastructure::persistence::community_repository_contract::{
+            community, community_repository_contract,
+        },
+    };
+    use shared::infrastructure::unit_of_work::InMemoryUnitOfWorkFactory;
+
+    community_repository_contract!(
+        InMemoryCommunityRepository::new(),
+        (
+            InMemoryCommunityRepository::new(),
+            InMemoryUnitOfWorkFactory::new()
+        )
+    );
+
+    fn snapshotting_repository(dir: &tempfile::TempDir) -> InMemoryCommunityRepository {
+        InMemoryCommunityRepository::with_snapshots(Arc::new(
+            JsonSnapshotStore::open(dir.path(), "membership_communities").unwrap(),
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn restores_communities_and_memberships_from_the_snapshot_and_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = snapshotting_repository(&dir);
+        let saved = community("rust-lang", "Rust Lang");
+        let member_id = AccountId::generate();
+        repository.save(&saved).await.unwrap();
+        repository.write_snapshot().unwrap();
+        repository
+            .save_membership(
+                saved.id(),
+                &member_id,
+                &Membership::reconstitute(Role::Member, None, MembershipStatus::Suspended),
+            )
+            .await
+            .unwrap();
+        drop(repository);
+
+        let restored = snapshotting_repository(&dir);
+
+        let found = restored.find_by_slug("rust-lang").await.unwrap().unwrap();
+        assert_eq!(found.version(), 2);
+        assert_eq!(found.member(saved.owner_id()).unwrap().role(), Role::Owner);
+        assert_eq!(
+            found.member(&member_id).unwrap().status(),
+            MembershipStatus::Suspended
+        );
+    }
+}
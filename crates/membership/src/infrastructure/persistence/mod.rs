@@ -1 +1,4 @@
+#[cfg(test)]
+pub mod community_repository_contract;
 pub mod in_memory;
+pub mod sqlite;
@@ -0,0 +1,504 @@
+use crate::{
+    application::{
+        errors::{
+            community_repository::CommunityRepositoryError,
+            membership_repository::MembershipRepositoryError,
+        },
+        ports::outbound::{
+            community_repository::CommunityRepositoryPort,
+            membership_repository::MembershipRepositoryPort,
+        },
+    },
+    domain::{
+        aggregates::community::Community,
+        entities::membership::Membership,
+        value_objects::{
+            community_id::CommunityId, community_name::CommunityName,
+            community_slug::CommunitySlug, membership_status::MembershipStatus, nickname::Nickname,
+            role::Role,
+        },
+    },
+};
+use async_trait::async_trait;
+use iam::domain::value_objects::AccountId;
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Row, params};
+use shared::{
+    application::{errors::unit_of_work::UnitOfWorkError, ports::unit_of_work::UnitOfWork},
+    infrastructure::{
+        blocking::run_blocking,
+        persistence::sqlite::{SqliteDatabase, SqliteUnitOfWork},
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+struct CommunityRow {
+    id: String,
+    owner_id: String,
+    slug: String,
+    name: String,
+    public: bool,
+    version: i64,
+}
+
+impl CommunityRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get("id")?,
+            owner_id: row.get("owner_id")?,
+            slug: row.get("slug")?,
+            name: row.get("name")?,
+            public: row.get("public")?,
+            version: row.get("version")?,
+        })
+    }
+
+    fn into_community(
+        self,
+        memberships: HashMap<AccountId, Membership>,
+    ) -> Result<Community, CommunityRepositoryError> {
+        let corrupted = || CommunityRepositoryError::Corrupted(self.id.clone());
+
+        Ok(Community::reconstitute(
+            CommunityId::from_str(&self.id).map_err(|_| corrupted())?,
+            AccountId::from_str(&self.owner_id).map_err(|_| corrupted())?,
+            CommunitySlug::new(self.slug.clone()).map_err(|_| corrupted())?,
+            CommunityName::new(self.name.clone()).map_err(|_| corrupted())?,
+            self.public,
+            memberships,
+            self.version as u64,
+        ))
+    }
+}
+
+struct MembershipRow {
+    account_id: String,
+    role: String,
+    status: String,
+    nickname: Option<String>,
+}
+
+impl MembershipRow {
+    fn read(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            account_id: row.get("account_id")?,
+            role: row.get("role")?,
+            status: row.get("status")?,
+            nickname: row.get("nickname")?,
+        })
+    }
+
+    fn into_membership(self) -> Result<(AccountId, Membership), MembershipRepositoryError> {
+        let corrupted = || MembershipRepositoryError::Corrupted(self.account_id.clone());
+
+        let nickname = self
+            .nickname
+            .clone()
+            .map(|nickname| Nickname::new(nickname).map_err(|_| corrupted()))
+            .transpose()?;
+
+        Ok((
+            AccountId::from_str(&self.account_id).map_err(|_| corrupted())?,
+            Membership::reconstitute(
+                Role::parse(&self.role).ok_or_else(corrupted)?,
+                nickname,
+                MembershipStatus::parse(&self.status).ok_or_else(corrupted)?,
+            ),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct SqliteCommunityRepository {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteCommunityRepository {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+
+    fn find_one(
+        &self,
+        column: &str,
+        value: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let connection = self.database.connection();
+
+        let row = connection
+            .query_row(
+                &format!("SELECT * FROM membership_communities WHERE {} = ?1", column),
+                params![value],
+                CommunityRow::read,
+            )
+            .optional()
+            .map_err(Self::storage_error)?;
+
+        row.map(|row| {
+            let memberships =
+                Self::load_memberships(&connection, &row.id).map_err(|e| match e {
+                    MembershipRepositoryError::Storage(message) => {
+                        CommunityRepositoryError::Storage(message)
+                    }
+                    MembershipRepositoryError::Unavailable(message) => {
+                        CommunityRepositoryError::Unavailable(message)
+                    }
+                    _ => CommunityRepositoryError::Corrupted(row.id.clone()),
+                })?;
+            row.into_community(memberships.into_iter().collect())
+        })
+        .transpose()
+    }
+
+    fn load_memberships(
+        connection: &Connection,
+        community_id: &str,
+    ) -> Result<Vec<(AccountId, Membership)>, MembershipRepositoryError> {
+        connection
+            .prepare_cached(
+                "SELECT account_id, role, status, nickname FROM memberships
+                 WHERE community_id = ?1",
+            )
+            .and_then(|mut statement| {
+                statement
+                    .query_map(params![community_id], MembershipRow::read)?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            })
+            .map_err(Self::membership_storage_error)?
+            .into_iter()
+            .map(MembershipRow::into_membership)
+            .collect()
+    }
+
+    fn write(connection: &Connection, community: &Community) -> rusqlite::Result<bool> {
+        let community_id = community.id().as_uuid().to_string();
+        let params = params![
+            community_id,
+            community.owner_id().as_uuid().to_string(),
+            community.slug().as_str(),
+            community.name().as_str(),
+            community.is_public(),
+            community.version() as i64,
+        ];
+        let written = if community.version() == 0 {
+            connection.execute(
+                "INSERT INTO membership_communities (id, owner_id, slug, name, public, version)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6 + 1)
+                 ON CONFLICT (id) DO NOTHING",
+                params,
+            )?
+        } else {
+            connection.execute(
+                "UPDATE membership_communities SET
+                    owner_id = ?2,
+                    slug = ?3,
+                    name = ?4,
+                    public = ?5,
+                    version = ?6 + 1
+                 WHERE id = ?1 AND version = ?6",
+                params,
+            )?
+        };
+        if written == 0 {
+            return Ok(false);
+        }
+
+        for account_id in community.changed_members() {
+            match community.member(account_id) {
+                Some(membership) => {
+                    Self::upsert_membership(connection, &community_id, account_id, membership)?;
+                }
+                None => {
+                    connection.execute(
+                        "DELETE FROM memberships WHERE community_id = ?1 AND account_id = ?2",
+                        params![community_id, account_id.as_uuid().to_string()],
+                    )?;
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn storage_error(e: rusqlite::Error) -> CommunityRepositoryError {
+        if SqliteDatabase::is_unavailable(&e) {
+            CommunityRepositoryError::Unavailable(e.to_string())
+        } else {
+            CommunityRepositoryError::Storage(e.to_string())
+        }
+    }
+
+    fn membership_storage_error(e: rusqlite::Error) -> MembershipRepositoryError {
+        if SqliteDatabase::is_unavailable(&e) {
+            MembershipRepositoryError::Unavailable(e.to_string())
+        } else {
+            MembershipRepositoryError::Storage(e.to_string())
+        }
+    }
+
+    fn write_error(e: rusqlite::Error) -> CommunityRepositoryError {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::ConstraintViolation)
+                if e.to_string().contains("membership_communities.slug") =>
+            {
+                CommunityRepositoryError::SlugAlreadyExists
+            }
+            _ => Self::storage_error(e),
+        }
+    }
+
+    fn upsert_membership(
+        connection: &Connection,
+        community_id: &str,
+        account_id: &AccountId,
+        membership: &Membership,
+    ) -> rusqlite::Result<usize> {
+        connection.execute(
+            "INSERT INTO memberships (community_id, account_id, role, status, nickname)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (community_id, account_id) DO UPDATE SET
+                role = excluded.role,
+                status = excluded.status,
+                nickname = excluded.nickname",
+            params![
+                community_id,
+                account_id.as_uuid().to_string(),
+                membership.role().as_str(),
+                membership.status().as_str(),
+                membership.nickname().map(|nickname| nickname.as_str()),
+            ],
+        )
+    }
+}
+
+#[async_trait]
+impl CommunityRepositoryPort for SqliteCommunityRepository {
+    async fn find_by_id(&self, id: &str) -> Result<Option<Community>, CommunityRepositoryError> {
+        let repository = self.clone();
+        let id = id.to_string();
+        run_blocking(move || repository.find_one("id", &id)).await
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<Option<Community>, CommunityRepositoryError> {
+        let repository = self.clone();
+        let slug = slug.to_string();
+        run_blocking(move || repository.find_one("slug", &slug)).await
+    }
+
+    async fn save(&self, community: &Community) -> Result<(), CommunityRepositoryError> {
+        let database = self.database.clone();
+        let community = community.clone();
+
+        let written = run_blocking(move || database.transaction(|tx| Self::write(tx, &community)))
+            .await
+            .map_err(Self::write_error)?;
+
+        if written {
+            Ok(())
+        } else {
+            Err(CommunityRepositoryError::Conflict)
+        }
+    }
+
+    async fn save_in(
+        &self,
+        unit_of_work: &mut dyn UnitOfWork,
+        community: &Community,
+    ) -> Result<(), CommunityRepositoryError> {
+        let unit_of_work = SqliteUnitOfWork::of(unit_of_work, &self.database)
+            .map_err(|e| CommunityRepositoryError::Storage(e.to_string()))?;
+        let community = community.clone();
+
+        unit_of_work.register(move |tx| match Self::write(tx, &community) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(UnitOfWorkError::Conflict(
+                CommunityRepositoryError::Conflict.to_string(),
+            )),
+            Err(e) => Err(UnitOfWorkError::Failed(Self::write_error(e).to_string())),
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MembershipRepositoryPort for SqliteCommunityRepository {
+    async fn find(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+    ) -> Result<Option<Membership>, MembershipRepositoryError> {
+        let database = self.database.clone();
+        let community_id = community_id.as_uuid().to_string();
+        let account_id = account_id.as_uuid().to_string();
+
+        let row = run_blocking(move || {
+            database
+                .connection()
+                .query_row(
+                    "SELECT account_id, role, status, nickname FROM memberships
+                     WHERE community_id = ?1 AND account_id = ?2",
+                    params![community_id, account_id],
+                    MembershipRow::read,
+                )
+                .optional()
+        })
+        .await
+        .map_err(Self::membership_storage_error)?;
+
+        row.map(|row| row.into_membership().map(|(_, membership)| membership))
+            .transpose()
+    }
+
+    async fn list_by_community(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<Vec<(AccountId, Membership)>, MembershipRepositoryError> {
+        let database = self.database.clone();
+        let community_id = community_id.as_uuid().to_string();
+
+        run_blocking(move || Self::load_memberships(&database.connection(), &community_id)).await
+    }
+
+    async fn save_membership(
+        &self,
+        community_id: &CommunityId,
+        account_id: &AccountId,
+        membership: &Membership,
+    ) -> Result<(), MembershipRepositoryError> {
+        let database = self.database.clone();
+        let community_id = community_id.as_uuid().to_string();
+        let account_id = account_id.clone();
+        let membership = membership.clone();
+
+        let found = run_blocking(move || {
+            database.transaction(|tx| {
+                let touched = tx.execute(
+                    "UPDATE membership_communities SET version = version + 1 WHERE id = ?1",
+                    params![community_id],
+                )?;
+                if touched == 0 {
+                    return Ok(false);
+                }
+
+                Self::upsert_membership(tx, &community_id, &account_id, &membership)?;
+                Ok(true)
+            })
+        })
+        .await
+        .map_err(Self::membership_storage_error)?;
+
+        if found {
+            Ok(())
+        } else {
+            Err(MembershipRepositoryError::CommunityNotFound)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::{
+        community_repository_contract::community_repository_contract,
+        sqlite::migrations::MIGRATIONS,
+    };
+    use communities::domain::policies::membership_policy::MembershipPolicy;
+    use shared::infrastructure::persistence::sqlite::SqliteUnitOfWorkFactory;
+
+    fn database() -> Arc<SqliteDatabase> {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&MIGRATIONS).unwrap();
+        Arc::new(database)
+    }
+
+    fn repository() -> SqliteCommunityRepository {
+        SqliteCommunityRepository::new(database())
+    }
+
+    fn repository_with_unit_of_work() -> (SqliteCommunityRepository, SqliteUnitOfWorkFactory) {
+        let database = database();
+        (
+            SqliteCommunityRepository::new(database.clone()),
+            SqliteUnitOfWorkFactory::new(database),
+        )
+    }
+
+    community_repository_contract!(repository(), repository_with_unit_of_work());
+
+    #[tokio::test]
+    async fn save_writes_only_the_memberships_that_changed() {
+        let database = database();
+        let repository = SqliteCommunityRepository::new(database.clone());
+        let created = Community::dummy_community();
+        repository.save(&created).await.unwrap();
+        let mut community = repository
+            .find_by_id(&created.id().as_uuid().to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        let untouched = AccountId::generate();
+        database
+            .connection()
+            .execute(
+                "INSERT INTO memberships (community_id, account_id, role, status)
+                 VALUES (?1, ?2, 'member', 'active')",
+                params![
+                    created.id().as_uuid().to_string(),
+                    untouched.as_uuid().to_string()
+                ],
+            )
+            .unwrap();
+
+        community
+            .join(AccountId::generate(), MembershipPolicy::Open)
+            .unwrap();
+        repository.save(&community).await.unwrap();
+
+        let memberships = repository.list_by_community(created.id()).await.unwrap();
+        assert_eq!(memberships.len(), 3);
+        assert!(
+            memberships
+                .iter()
+                .any(|(account_id, _)| account_id == &untouched)
+        );
+    }
+
+    #[test]
+    fn reports_other_constraint_violations_as_storage_errors() {
+        let violation = database()
+            .connection()
+            .execute(
+                "INSERT INTO membership_communities (id, slug, name, public)
+                 VALUES ('id', 'slug', 'name', 1)",
+                [],
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            SqliteCommunityRepository::write_error(violation),
+            CommunityRepositoryError::Storage(_)
+        ));
+    }
+
+    #[test]
+    fn reports_a_busy_database_as_unavailable() {
+        let busy = || {
+            rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                None,
+            )
+        };
+
+        assert!(matches!(
+            SqliteCommunityRepository::storage_error(busy()),
+            CommunityRepositoryError::Unavailable(_)
+        ));
+        assert!(matches!(
+            SqliteCommunityRepository::membership_storage_error(busy()),
+            MembershipRepositoryError::Unavailable(_)
+        ));
+    }
+}
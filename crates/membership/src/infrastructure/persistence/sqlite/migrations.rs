@@ -0,0 +1,17 @@
+use shared::infrastructure::persistence::sqlite::{Migration, MigrationSet};
+
+pub const MIGRATIONS: MigrationSet = MigrationSet {
+    context: "membership",
+    migrations: &[
+        Migration {
+            version: 1,
+            name: "create_memberships",
+            sql: include_str!("../../../../migrations/0001_create_memberships.sql"),
+        },
+        Migration {
+            version: 2,
+            name: "add_community_version",
+            sql: include_str!("../../../../migrations/0002_add_community_version.sql"),
+        },
+    ],
+};
@@ -0,0 +1,2 @@
+pub mod community_repository;
+pub mod migrations;
@@ -0,0 +1,4 @@
+pub const SHARED_EVENT_PUBLISHER_ERROR: &str = "SHARED_EVENT_PUBLISHER_ERROR";
+pub const SHARED_OUTBOX_STORE_ERROR: &str = "SHARED_OUTBOX_STORE_ERROR";
+pub const SHARED_UNIT_OF_WORK_ERROR: &str = "SHARED_UNIT_OF_WORK_ERROR";
+pub const SHARED_UNIT_OF_WORK_CONFLICT: &str = "SHARED_UNIT_OF_WORK_CONFLICT";
@@ -0,0 +1,4 @@
+pub mod error_codes;
+pub mod event_publisher;
+pub mod outbox_store;
+pub mod unit_of_work;
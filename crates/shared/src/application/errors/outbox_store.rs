@@ -0,0 +1,28 @@
+use super::error_codes::SHARED_OUTBOX_STORE_ERROR;
+use crate::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct OutboxStoreError(pub String);
+
+impl fmt::Display for OutboxStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for OutboxStoreError {}
+
+impl LayerError for OutboxStoreError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        SHARED_OUTBOX_STORE_ERROR
+    }
+
+    fn message(&self) -> &'static str {
+        "We couldn't complete your request right now. Please try again."
+    }
+}
@@ -0,0 +1,45 @@
+use super::error_codes::{SHARED_UNIT_OF_WORK_CONFLICT, SHARED_UNIT_OF_WORK_ERROR};
+use crate::error::{ErrorCategory, LayerError};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum UnitOfWorkError {
+    Conflict(String),
+    Failed(String),
+}
+
+impl fmt::Display for UnitOfWorkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitOfWorkError::Conflict(message) | UnitOfWorkError::Failed(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UnitOfWorkError {}
+
+impl LayerError for UnitOfWorkError {
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Application
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            UnitOfWorkError::Conflict(_) => SHARED_UNIT_OF_WORK_CONFLICT,
+            UnitOfWorkError::Failed(_) => SHARED_UNIT_OF_WORK_ERROR,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            UnitOfWorkError::Conflict(_) => {
+                "The data was changed by another request. Please try again."
+            }
+            UnitOfWorkError::Failed(_) => {
+                "We couldn't complete your request right now. Please try again."
+            }
+        }
+    }
+}
@@ -1,2 +1,6 @@
 pub mod auth_context;
 pub mod common_application_error;
+pub mod errors;
+pub mod outbox;
+pub mod ports;
+pub mod request_context;
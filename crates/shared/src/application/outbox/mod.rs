@@ -0,0 +1,7 @@
+pub mod outbox_message;
+pub mod outbox_relay;
+pub mod retry_policy;
+
+pub use outbox_message::{OutboxMessage, OutboxStatus};
+pub use outbox_relay::{OutboxRelay, RelayReport};
+pub use retry_policy::RetryPolicy;
@@ -0,0 +1,86 @@
+use crate::domain::events::DomainEvent;
+use std::{any::Any, time::SystemTime};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl OutboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Delivered => "delivered",
+            OutboxStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(OutboxStatus::Pending),
+            "delivered" => Some(OutboxStatus::Delivered),
+            "failed" => Some(OutboxStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: serde_json::Value,
+    pub occurred_at: SystemTime,
+    pub status: OutboxStatus,
+    pub attempts: u32,
+    pub next_attempt_at: SystemTime,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<SystemTime>,
+}
+
+impl OutboxMessage {
+    pub fn from_event(event: &dyn DomainEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: event.payload(),
+            occurred_at: event.occurred_at(),
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: event.occurred_at(),
+            last_error: None,
+            delivered_at: None,
+        }
+    }
+
+    pub fn is_due(&self, now: SystemTime) -> bool {
+        self.status == OutboxStatus::Pending && self.next_attempt_at <= now
+    }
+}
+
+impl DomainEvent for OutboxMessage {
+    fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.aggregate_id.clone()
+    }
+
+    fn occurred_at(&self) -> SystemTime {
+        self.occurred_at
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        self.payload.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
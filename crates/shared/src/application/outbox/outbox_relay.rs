@@ -0,0 +1,208 @@
+use crate::{
+    application::{
+        errors::outbox_store::OutboxStoreError,
+        outbox::RetryPolicy,
+        ports::{
+            clock::ClockPort, event_publisher::EventPublisherPort, outbox_store::OutboxStorePort,
+        },
+    },
+    domain::events::DomainEvent,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RelayReport {
+    pub delivered: usize,
+    pub retried: usize,
+    pub failed: usize,
+}
+
+pub struct OutboxRelay {
+    outbox: Arc<dyn OutboxStorePort>,
+    publisher: Arc<dyn EventPublisherPort>,
+    clock: Arc<dyn ClockPort>,
+    retry_policy: RetryPolicy,
+    batch_size: usize,
+}
+
+impl OutboxRelay {
+    pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+    pub fn new(
+        outbox: Arc<dyn OutboxStorePort>,
+        publisher: Arc<dyn EventPublisherPort>,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self {
+            outbox,
+            publisher,
+            clock,
+            retry_policy: RetryPolicy::default(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn relay_due(&self) -> Result<RelayReport, OutboxStoreError> {
+        let mut report = RelayReport::default();
+
+        for message in self.outbox.due(self.clock.now(), self.batch_size)? {
+            let id = message.id;
+            let attempts = message.attempts + 1;
+            let event: Arc<dyn DomainEvent> = Arc::new(message);
+
+            match self.publisher.publish(vec![event]) {
+                Ok(()) => {
+                    self.outbox.mark_delivered(&id, self.clock.now())?;
+                    report.delivered += 1;
+                }
+                Err(err) if self.retry_policy.is_exhausted(attempts) => {
+                    self.outbox.mark_failed(&id, err.0)?;
+                    report.failed += 1;
+                }
+                Err(err) => {
+                    let next_attempt_at = self.clock.now() + self.retry_policy.delay_for(attempts);
+                    self.outbox.mark_retry(&id, err.0, next_attempt_at)?;
+                    report.retried += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::{
+            errors::event_publisher::EventPublisherError,
+            outbox::{OutboxMessage, OutboxStatus},
+        },
+        infrastructure::{
+            clock::FixedClock, events::RecordingEventPublisher, outbox::InMemoryOutboxStore,
+        },
+    };
+    use std::{
+        any::Any,
+        time::{Duration, SystemTime},
+    };
+
+    #[derive(Debug)]
+    struct Happened(SystemTime);
+
+    impl DomainEvent for Happened {
+        fn event_type(&self) -> &str {
+            "test.happened"
+        }
+
+        fn aggregate_id(&self) -> String {
+            "aggregate".to_string()
+        }
+
+        fn occurred_at(&self) -> SystemTime {
+            self.0
+        }
+
+        fn payload(&self) -> serde_json::Value {
+            serde_json::json!({ "ok": true })
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    struct RejectingPublisher;
+
+    impl EventPublisherPort for RejectingPublisher {
+        fn publish(&self, _events: Vec<Arc<dyn DomainEvent>>) -> Result<(), EventPublisherError> {
+            Err(EventPublisherError("subscriber unavailable".to_string()))
+        }
+    }
+
+    fn outbox_with_one_message(clock: &FixedClock) -> Arc<InMemoryOutboxStore> {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        outbox
+            .append(vec![OutboxMessage::from_event(&Happened(clock.now()))])
+            .unwrap();
+        outbox
+    }
+
+    #[test]
+    fn delivers_due_messages_and_marks_them_delivered() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(100));
+        let outbox = outbox_with_one_message(&clock);
+        let publisher = Arc::new(RecordingEventPublisher::new());
+        let relay = OutboxRelay::new(outbox.clone(), publisher.clone(), clock.clone());
+
+        let report = relay.relay_due().unwrap();
+        let second = relay.relay_due().unwrap();
+
+        assert_eq!(report.delivered, 1);
+        assert_eq!(second, RelayReport::default());
+        assert_eq!(publisher.published_types(), vec!["test.happened"]);
+        assert_eq!(outbox.messages()[0].status, OutboxStatus::Delivered);
+        assert_eq!(outbox.messages()[0].delivered_at, Some(clock.now()));
+    }
+
+    #[test]
+    fn retries_with_backoff_after_a_failed_delivery() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(100));
+        let outbox = outbox_with_one_message(&clock);
+        let relay = OutboxRelay::new(outbox.clone(), Arc::new(RejectingPublisher), clock.clone())
+            .with_retry_policy(RetryPolicy {
+                base_delay: Duration::from_secs(10),
+                max_delay: Duration::from_secs(60),
+                max_attempts: 5,
+            });
+
+        assert_eq!(relay.relay_due().unwrap().retried, 1);
+        assert_eq!(relay.relay_due().unwrap(), RelayReport::default());
+
+        clock.advance(Duration::from_secs(10));
+
+        assert_eq!(relay.relay_due().unwrap().retried, 1);
+
+        let message = &outbox.messages()[0];
+        assert_eq!(message.status, OutboxStatus::Pending);
+        assert_eq!(message.attempts, 2);
+        assert_eq!(
+            message.next_attempt_at,
+            clock.now() + Duration::from_secs(20)
+        );
+        assert_eq!(
+            message.last_error.as_deref(),
+            Some("subscriber unavailable")
+        );
+    }
+
+    #[test]
+    fn marks_message_failed_once_attempts_are_exhausted() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(100));
+        let outbox = outbox_with_one_message(&clock);
+        let relay = OutboxRelay::new(outbox.clone(), Arc::new(RejectingPublisher), clock.clone())
+            .with_retry_policy(RetryPolicy {
+                base_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+                max_attempts: 2,
+            });
+
+        relay.relay_due().unwrap();
+        let report = relay.relay_due().unwrap();
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(outbox.messages()[0].status, OutboxStatus::Failed);
+        assert_eq!(relay.relay_due().unwrap(), RelayReport::default());
+    }
+}
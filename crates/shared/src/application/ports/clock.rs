@@ -0,0 +1,5 @@
+use std::time::SystemTime;
+
+pub trait ClockPort: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
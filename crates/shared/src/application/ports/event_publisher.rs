@@ -0,0 +1,12 @@
+use crate::{
+    application::errors::event_publisher::EventPublisherError, domain::events::DomainEvent,
+};
+use std::sync::Arc;
+
+pub trait EventPublisherPort: Send + Sync {
+    fn publish(&self, events: Vec<Arc<dyn DomainEvent>>) -> Result<(), EventPublisherError>;
+}
+
+pub trait EventHandler: Send + Sync {
+    fn handle(&self, event: &dyn DomainEvent) -> Result<(), EventPublisherError>;
+}
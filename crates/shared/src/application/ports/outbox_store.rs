@@ -0,0 +1,16 @@
+use crate::application::{errors::outbox_store::OutboxStoreError, outbox::OutboxMessage};
+use std::time::SystemTime;
+use uuid::Uuid;
+
+pub trait OutboxStorePort: Send + Sync {
+    fn append(&self, messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError>;
+    fn due(&self, now: SystemTime, limit: usize) -> Result<Vec<OutboxMessage>, OutboxStoreError>;
+    fn mark_delivered(&self, id: &Uuid, delivered_at: SystemTime) -> Result<(), OutboxStoreError>;
+    fn mark_retry(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: SystemTime,
+    ) -> Result<(), OutboxStoreError>;
+    fn mark_failed(&self, id: &Uuid, error: String) -> Result<(), OutboxStoreError>;
+}
@@ -0,0 +1,15 @@
+use crate::application::errors::unit_of_work::UnitOfWorkError;
+use async_trait::async_trait;
+use std::any::Any;
+
+pub trait UnitOfWorkPort: Send + Sync {
+    fn begin(&self) -> Box<dyn UnitOfWork>;
+}
+
+#[async_trait]
+pub trait UnitOfWork: Send {
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn on_commit(&mut self, hook: Box<dyn FnOnce() + Send>);
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError>;
+    fn rollback(self: Box<Self>);
+}
@@ -0,0 +1,5 @@
+#[derive(Clone, Debug, Default)]
+pub struct RequestContext {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
@@ -0,0 +1,9 @@
+use std::{any::Any, fmt::Debug, time::SystemTime};
+
+pub trait DomainEvent: Debug + Send + Sync + 'static {
+    fn event_type(&self) -> &str;
+    fn aggregate_id(&self) -> String;
+    fn occurred_at(&self) -> SystemTime;
+    fn payload(&self) -> serde_json::Value;
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,29 @@
+use crate::domain::events::DomainEvent;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Default)]
+pub struct DomainEvents {
+    pending: Vec<Arc<dyn DomainEvent>>,
+}
+
+impl DomainEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, event: impl DomainEvent) {
+        self.pending.push(Arc::new(event));
+    }
+
+    pub fn pending(&self) -> &[Arc<dyn DomainEvent>] {
+        &self.pending
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn take(&mut self) -> Vec<Arc<dyn DomainEvent>> {
+        std::mem::take(&mut self.pending)
+    }
+}
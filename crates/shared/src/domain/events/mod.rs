@@ -0,0 +1,5 @@
+pub mod domain_event;
+pub mod domain_events;
+
+pub use domain_event::DomainEvent;
+pub use domain_events::DomainEvents;
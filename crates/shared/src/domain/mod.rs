@@ -1,3 +1,5 @@
 pub mod domain_error;
+pub mod events;
+pub mod timestamps;
 
 pub use domain_error::DomainError;
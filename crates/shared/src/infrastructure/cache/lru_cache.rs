@@ -0,0 +1,276 @@
+use crate::application::ports::clock::ClockPort;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    pub capacity: usize,
+    pub ttl: Duration,
+    pub negative_ttl: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl std::ops::Add for CacheStats {
+    type Output = CacheStats;
+
+    fn add(self, other: CacheStats) -> CacheStats {
+        CacheStats {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
+struct Entry<V> {
+    value: V,
+    expires_at: SystemTime,
+    used_at: u64,
+}
+
+struct Entries<K, V> {
+    by_key: HashMap<K, Entry<V>>,
+    by_use: BTreeMap<u64, K>,
+    tick: u64,
+    generation: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> Entries<K, V> {
+    fn touch(&mut self, key: &K) {
+        self.tick += 1;
+        if let Some(entry) = self.by_key.get_mut(key) {
+            self.by_use.remove(&entry.used_at);
+            entry.used_at = self.tick;
+            self.by_use.insert(self.tick, key.clone());
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<Entry<V>> {
+        let removed = self.by_key.remove(key)?;
+        self.by_use.remove(&removed.used_at);
+        Some(removed)
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, key)) = self.by_use.pop_first() {
+            self.by_key.remove(&key);
+        }
+    }
+}
+
+pub struct LruCache<K, V> {
+    capacity: usize,
+    ttl: Duration,
+    clock: Arc<dyn ClockPort>,
+    entries: Mutex<Entries<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    pub fn new(capacity: usize, ttl: Duration, clock: Arc<dyn ClockPort>) -> Self {
+        Self {
+            capacity,
+            ttl,
+            clock,
+            entries: Mutex::new(Entries {
+                by_key: HashMap::new(),
+                by_use: BTreeMap::new(),
+                tick: 0,
+                generation: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().expect("mutex poisoned");
+
+        let fresh = entries
+            .by_key
+            .get(key)
+            .map(|entry| entry.expires_at > self.clock.now());
+        match fresh {
+            Some(true) => {
+                entries.touch(key);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                entries.by_key.get(key).map(|entry| entry.value.clone())
+            }
+            Some(false) => {
+                entries.remove(key);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub fn peek(&self, key: &K) -> Option<V> {
+        let entries = self.entries.lock().expect("mutex poisoned");
+        entries
+            .by_key
+            .get(key)
+            .filter(|entry| entry.expires_at > self.clock.now())
+            .map(|entry| entry.value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.insert_with_ttl(key, value, self.ttl);
+    }
+
+    pub fn insert_with_ttl(&self, key: K, value: V, ttl: Duration) {
+        let mut entries = self.entries.lock().expect("mutex poisoned");
+        self.store(&mut entries, key, value, ttl);
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.entries.lock().expect("mutex poisoned").generation
+    }
+
+    pub fn fill(&self, generation: u64, key: K, value: V, ttl: Duration) {
+        let mut entries = self.entries.lock().expect("mutex poisoned");
+        if entries.generation == generation {
+            self.store(&mut entries, key, value, ttl);
+        }
+    }
+
+    fn store(&self, entries: &mut Entries<K, V>, key: K, value: V, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        entries.remove(&key);
+        while entries.by_key.len() >= self.capacity {
+            entries.evict_least_recently_used();
+        }
+        entries.tick += 1;
+        let used_at = entries.tick;
+        entries.by_use.insert(used_at, key.clone());
+        entries.by_key.insert(
+            key,
+            Entry {
+                value,
+                expires_at: self.clock.now() + ttl,
+                used_at,
+            },
+        );
+    }
+
+    pub fn invalidate(&self, keys: &[K]) {
+        let mut entries = self.entries.lock().expect("mutex poisoned");
+        entries.generation += 1;
+        for key in keys {
+            entries.remove(key);
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().expect("mutex poisoned");
+        entries.generation += 1;
+        entries.by_key.clear();
+        entries.by_use.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("mutex poisoned").by_key.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::FixedClock;
+
+    fn cache(capacity: usize, clock: Arc<FixedClock>) -> LruCache<&'static str, u32> {
+        LruCache::new(capacity, Duration::from_secs(60), clock)
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let cache = cache(4, Arc::new(FixedClock::at_unix_seconds(0)));
+        cache.insert("a", 1);
+
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry() {
+        let cache = cache(2, Arc::new(FixedClock::at_unix_seconds(0)));
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.get(&"a");
+
+        cache.insert("c", 3);
+
+        assert_eq!(cache.peek(&"a"), Some(1));
+        assert_eq!(cache.peek(&"b"), None);
+        assert_eq!(cache.peek(&"c"), Some(3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn expires_entries_after_their_ttl() {
+        let clock = Arc::new(FixedClock::at_unix_seconds(0));
+        let cache = cache(4, clock.clone());
+        cache.insert("a", 1);
+        cache.insert_with_ttl("b", 2, Duration::from_secs(5));
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"b"), None);
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(cache.get(&"a"), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn skips_fills_started_before_an_invalidation() {
+        let cache = cache(4, Arc::new(FixedClock::at_unix_seconds(0)));
+        let generation = cache.generation();
+
+        cache.invalidate(&["a"]);
+        cache.fill(generation, "a", 1, Duration::from_secs(60));
+        cache.fill(cache.generation(), "b", 2, Duration::from_secs(60));
+
+        assert_eq!(cache.peek(&"a"), None);
+        assert_eq!(cache.peek(&"b"), Some(2));
+    }
+
+    #[test]
+    fn caches_nothing_without_capacity() {
+        let cache = cache(0, Arc::new(FixedClock::at_unix_seconds(0)));
+
+        cache.insert("a", 1);
+
+        assert_eq!(cache.get(&"a"), None);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod lru_cache;
+
+pub use lru_cache::{CachePolicy, CacheStats, LruCache};
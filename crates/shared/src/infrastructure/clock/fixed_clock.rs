@@ -0,0 +1,53 @@
+use crate::application::ports::clock::ClockPort;
+use std::{
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<SystemTime>,
+}
+
+impl FixedClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    pub fn at_unix_seconds(seconds: u64) -> Self {
+        Self::new(UNIX_EPOCH + Duration::from_secs(seconds))
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().expect("mutex poisoned");
+        *now += duration;
+    }
+
+    pub fn set(&self, time: SystemTime) {
+        *self.now.lock().expect("mutex poisoned") = time;
+    }
+}
+
+impl ClockPort for FixedClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().expect("mutex poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_the_same_instant_until_advanced() {
+        let clock = FixedClock::at_unix_seconds(1_000);
+
+        assert_eq!(clock.now(), clock.now());
+
+        clock.advance(Duration::from_secs(60));
+
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(1_060));
+    }
+}
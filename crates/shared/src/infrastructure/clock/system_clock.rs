@@ -0,0 +1,17 @@
+use crate::application::ports::clock::ClockPort;
+use std::time::SystemTime;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ClockPort for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    application::{
+        errors::event_publisher::EventPublisherError,
+        ports::event_publisher::{EventHandler, EventPublisherPort},
+    },
+    domain::events::DomainEvent,
+    infrastructure::events::subscribers::EventSubscribers,
+};
+use std::{
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Sender},
+    },
+    thread::{self, JoinHandle},
+};
+
+pub struct AsyncEventBus {
+    subscribers: Arc<EventSubscribers>,
+    sender: Mutex<Option<Sender<Arc<dyn DomainEvent>>>>,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AsyncEventBus {
+    pub fn start() -> Self {
+        let subscribers = Arc::new(EventSubscribers::default());
+        let (sender, receiver) = mpsc::channel::<Arc<dyn DomainEvent>>();
+
+        let worker_subscribers = subscribers.clone();
+        let worker = thread::spawn(move || {
+            for event in receiver {
+                if let Err(err) = worker_subscribers.dispatch(event.as_ref()) {
+                    tracing::error!(
+                        event_type = event.event_type(),
+                        aggregate_id = %event.aggregate_id(),
+                        error = %err,
+                        "Event handler failed"
+                    );
+                }
+            }
+        });
+
+        Self {
+            subscribers,
+            sender: Mutex::new(Some(sender)),
+            worker: Mutex::new(Some(worker)),
+        }
+    }
+
+    pub fn subscribe(&self, event_type: &'static str, handler: Arc<dyn EventHandler>) {
+        self.subscribers.subscribe(event_type, handler);
+    }
+
+    pub fn shutdown(&self) {
+        self.sender.lock().expect("mutex poisoned").take();
+        if let Some(worker) = self.worker.lock().expect("mutex poisoned").take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl EventPublisherPort for AsyncEventBus {
+    fn publish(&self, events: Vec<Arc<dyn DomainEvent>>) -> Result<(), EventPublisherError> {
+        let sender = self.sender.lock().expect("mutex poisoned");
+        let sender = sender
+            .as_ref()
+            .ok_or_else(|| EventPublisherError("Event bus has been shut down".to_string()))?;
+
+        for event in events {
+            sender
+                .send(event)
+                .map_err(|e| EventPublisherError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for AsyncEventBus {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        any::Any,
+        thread::ThreadId,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    #[derive(Debug)]
+    struct Pinged(&'static str);
+
+    impl DomainEvent for Pinged {
+        fn event_type(&self) -> &str {
+            self.0
+        }
+
+        fn aggregate_id(&self) -> String {
+            "aggregate".to_string()
+        }
+
+        fn occurred_at(&self) -> SystemTime {
+            UNIX_EPOCH
+        }
+
+        fn payload(&self) -> serde_json::Value {
+            serde_json::Value::Null
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+        threads: Mutex<Vec<ThreadId>>,
+        delay: Duration,
+    }
+
+    impl Recorder {
+        fn slow(delay: Duration) -> Self {
+            Self {
+                delay,
+                ..Self::default()
+            }
+        }
+
+        fn events(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl EventHandler for Recorder {
+        fn handle(&self, event: &dyn DomainEvent) -> Result<(), EventPublisherError> {
+            thread::sleep(self.delay);
+            self.events
+                .lock()
+                .unwrap()
+                .push(event.event_type().to_string());
+            self.threads.lock().unwrap().push(thread::current().id());
+            Ok(())
+        }
+    }
+
+    struct Failing;
+
+    impl EventHandler for Failing {
+        fn handle(&self, _event: &dyn DomainEvent) -> Result<(), EventPublisherError> {
+            Err(EventPublisherError("handler failed".to_string()))
+        }
+    }
+
+    #[test]
+    fn dispatches_to_every_subscriber_on_the_worker_thread() {
+        let bus = AsyncEventBus::start();
+        let first = Arc::new(Recorder::default());
+        let second = Arc::new(Recorder::default());
+        bus.subscribe("ping", first.clone());
+        bus.subscribe("ping", second.clone());
+
+        bus.publish(vec![Arc::new(Pinged("ping"))]).unwrap();
+        bus.shutdown();
+
+        assert_eq!(first.events(), vec!["ping"]);
+        assert_eq!(second.events(), vec!["ping"]);
+        let caller = thread::current().id();
+        assert!(first.threads.lock().unwrap().iter().all(|id| *id != caller));
+        assert!(
+            second
+                .threads
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|id| *id != caller)
+        );
+    }
+
+    #[test]
+    fn a_failing_handler_does_not_stop_the_others() {
+        let bus = AsyncEventBus::start();
+        let recorder = Arc::new(Recorder::default());
+        bus.subscribe("ping", Arc::new(Failing));
+        bus.subscribe("ping", recorder.clone());
+
+        bus.publish(vec![Arc::new(Pinged("ping")), Arc::new(Pinged("ping"))])
+            .unwrap();
+        bus.shutdown();
+
+        assert_eq!(recorder.events(), vec!["ping", "ping"]);
+    }
+
+    #[test]
+    fn shutdown_drains_queued_events_and_rejects_new_ones() {
+        let bus = AsyncEventBus::start();
+        let recorder = Arc::new(Recorder::slow(Duration::from_millis(5)));
+        bus.subscribe(EventSubscribers::ALL_EVENTS, recorder.clone());
+
+        bus.publish((0..20).map(|_| Arc::new(Pinged("ping")) as _).collect())
+            .unwrap();
+        bus.shutdown();
+
+        assert_eq!(recorder.events().len(), 20);
+        assert!(bus.publish(vec![Arc::new(Pinged("ping"))]).is_err());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod async_event_bus;
+pub mod recording_event_publisher;
+pub mod subscribers;
+pub mod sync_event_bus;
+
+pub use async_event_bus::AsyncEventBus;
+pub use recording_event_publisher::RecordingEventPublisher;
+pub use subscribers::EventSubscribers;
+pub use sync_event_bus::SyncEventBus;
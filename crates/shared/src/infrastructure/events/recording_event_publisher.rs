@@ -0,0 +1,39 @@
+use crate::{
+    application::{
+        errors::event_publisher::EventPublisherError, ports::event_publisher::EventPublisherPort,
+    },
+    domain::events::DomainEvent,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+pub struct RecordingEventPublisher {
+    published: Mutex<Vec<Arc<dyn DomainEvent>>>,
+}
+
+impl RecordingEventPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn published(&self) -> Vec<Arc<dyn DomainEvent>> {
+        self.published.lock().expect("mutex poisoned").clone()
+    }
+
+    pub fn published_types(&self) -> Vec<String> {
+        self.published()
+            .iter()
+            .map(|event| event.event_type().to_string())
+            .collect()
+    }
+}
+
+impl EventPublisherPort for RecordingEventPublisher {
+    fn publish(&self, events: Vec<Arc<dyn DomainEvent>>) -> Result<(), EventPublisherError> {
+        self.published
+            .lock()
+            .expect("mutex poisoned")
+            .extend(events);
+        Ok(())
+    }
+}
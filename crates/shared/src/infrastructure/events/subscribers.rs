@@ -0,0 +1,52 @@
+use crate::{
+    application::{
+        errors::event_publisher::EventPublisherError, ports::event_publisher::EventHandler,
+    },
+    domain::events::DomainEvent,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct EventSubscribers {
+    handlers: RwLock<HashMap<String, Vec<Arc<dyn EventHandler>>>>,
+}
+
+impl EventSubscribers {
+    pub const ALL_EVENTS: &'static str = "*";
+
+    pub fn subscribe(&self, event_type: &'static str, handler: Arc<dyn EventHandler>) {
+        let mut handlers = self.handlers.write().expect("lock poisoned");
+        handlers
+            .entry(event_type.to_string())
+            .or_default()
+            .push(handler);
+    }
+
+    pub fn handlers_for(&self, event_type: &str) -> Vec<Arc<dyn EventHandler>> {
+        let handlers = self.handlers.read().expect("lock poisoned");
+        [event_type, Self::ALL_EVENTS]
+            .iter()
+            .filter_map(|key| handlers.get(*key))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    pub fn dispatch(&self, event: &dyn DomainEvent) -> Result<(), EventPublisherError> {
+        let mut failures = Vec::new();
+        for handler in self.handlers_for(event.event_type()) {
+            if let Err(err) = handler.handle(event) {
+                failures.push(err.0);
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(EventPublisherError(failures.join("; ")))
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    application::{
+        errors::event_publisher::EventPublisherError,
+        ports::event_publisher::{EventHandler, EventPublisherPort},
+    },
+    domain::events::DomainEvent,
+    infrastructure::events::subscribers::EventSubscribers,
+};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct SyncEventBus {
+    subscribers: EventSubscribers,
+}
+
+impl SyncEventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(&self, event_type: &'static str, handler: Arc<dyn EventHandler>) {
+        self.subscribers.subscribe(event_type, handler);
+    }
+}
+
+impl EventPublisherPort for SyncEventBus {
+    fn publish(&self, events: Vec<Arc<dyn DomainEvent>>) -> Result<(), EventPublisherError> {
+        for event in events {
+            self.subscribers.dispatch(event.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        any::Any,
+        sync::Mutex,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    #[derive(Debug)]
+    struct Pinged(&'static str);
+
+    impl DomainEvent for Pinged {
+        fn event_type(&self) -> &str {
+            self.0
+        }
+
+        fn aggregate_id(&self) -> String {
+            "aggregate".to_string()
+        }
+
+        fn occurred_at(&self) -> SystemTime {
+            UNIX_EPOCH
+        }
+
+        fn payload(&self) -> serde_json::Value {
+            serde_json::Value::Null
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl EventHandler for Recorder {
+        fn handle(&self, event: &dyn DomainEvent) -> Result<(), EventPublisherError> {
+            self.0.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn dispatches_only_to_subscribers_of_the_event_type() {
+        let bus = SyncEventBus::new();
+        let pings = Arc::new(Recorder::default());
+        let everything = Arc::new(Recorder::default());
+        bus.subscribe("ping", pings.clone());
+        bus.subscribe(EventSubscribers::ALL_EVENTS, everything.clone());
+
+        bus.publish(vec![Arc::new(Pinged("ping")), Arc::new(Pinged("pong"))])
+            .unwrap();
+
+        assert_eq!(*pings.0.lock().unwrap(), vec!["ping"]);
+        assert_eq!(*everything.0.lock().unwrap(), vec!["ping", "pong"]);
+    }
+}
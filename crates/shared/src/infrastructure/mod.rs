@@ -1,3 +1,10 @@
+pub mod blocking;
+pub mod cache;
+pub mod clock;
+pub mod events;
 pub mod infrastructure_error;
+pub mod outbox;
+pub mod persistence;
+pub mod unit_of_work;
 
 pub use infrastructure_error::InfrastructureError;
@@ -0,0 +1,200 @@
+use super::outbox_record::OutboxRecord;
+use crate::{
+    application::{
+        errors::outbox_store::OutboxStoreError,
+        outbox::{OutboxMessage, OutboxStatus},
+        ports::outbox_store::OutboxStorePort,
+    },
+    infrastructure::persistence::json_snapshot::{
+        JsonSnapshotStore, LogEntry, Recovered, SnapshotError, SnapshotSource,
+    },
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryOutboxStore {
+    messages: Mutex<Vec<OutboxMessage>>,
+    log: Option<Arc<JsonSnapshotStore>>,
+}
+
+impl InMemoryOutboxStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_snapshots(snapshots: Arc<JsonSnapshotStore>) -> Result<Self, SnapshotError> {
+        let recovered: Recovered<Vec<OutboxRecord>, LogEntry<OutboxRecord>> = snapshots.load()?;
+
+        let mut messages: Vec<OutboxMessage> = Vec::new();
+        for record in recovered.snapshot.into_iter().flatten() {
+            messages.push(OutboxMessage::try_from(record)?);
+        }
+        for entry in recovered.log {
+            match entry {
+                LogEntry::Put(record) => {
+                    let message = OutboxMessage::try_from(record)?;
+                    match messages.iter_mut().find(|stored| stored.id == message.id) {
+                        Some(stored) => *stored = message,
+                        None => messages.push(message),
+                    }
+                }
+                LogEntry::Remove(id) => {
+                    messages.retain(|message| message.id.to_string() != id);
+                }
+            }
+        }
+
+        Ok(Self {
+            messages: Mutex::new(messages),
+            log: Some(snapshots),
+        })
+    }
+
+    pub fn messages(&self) -> Vec<OutboxMessage> {
+        self.messages.lock().expect("mutex poisoned").clone()
+    }
+
+    fn update(
+        &self,
+        id: &Uuid,
+        apply: impl FnOnce(&mut OutboxMessage),
+    ) -> Result<(), OutboxStoreError> {
+        let mut messages = self.messages.lock().expect("mutex poisoned");
+        let message = messages
+            .iter_mut()
+            .find(|message| &message.id == id)
+            .ok_or_else(|| OutboxStoreError(format!("Outbox message {} not found", id)))?;
+        let mut updated = message.clone();
+        apply(&mut updated);
+        self.log(&updated)?;
+        *message = updated;
+        Ok(())
+    }
+
+    fn log(&self, message: &OutboxMessage) -> Result<(), OutboxStoreError> {
+        match &self.log {
+            Some(log) => log
+                .append(&LogEntry::Put(OutboxRecord::from(message)))
+                .map_err(|e| OutboxStoreError(e.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl OutboxStorePort for InMemoryOutboxStore {
+    fn append(&self, messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError> {
+        let mut stored = self.messages.lock().expect("mutex poisoned");
+        for message in &messages {
+            self.log(message)?;
+        }
+        stored.extend(messages);
+        Ok(())
+    }
+
+    fn due(&self, now: SystemTime, limit: usize) -> Result<Vec<OutboxMessage>, OutboxStoreError> {
+        let messages = self.messages.lock().expect("mutex poisoned");
+        Ok(messages
+            .iter()
+            .filter(|message| message.is_due(now))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    fn mark_delivered(&self, id: &Uuid, delivered_at: SystemTime) -> Result<(), OutboxStoreError> {
+        self.update(id, |message| {
+            message.status = OutboxStatus::Delivered;
+            message.attempts += 1;
+            message.delivered_at = Some(delivered_at);
+            message.last_error = None;
+        })
+    }
+
+    fn mark_retry(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: SystemTime,
+    ) -> Result<(), OutboxStoreError> {
+        self.update(id, |message| {
+            message.attempts += 1;
+            message.next_attempt_at = next_attempt_at;
+            message.last_error = Some(error);
+        })
+    }
+
+    fn mark_failed(&self, id: &Uuid, error: String) -> Result<(), OutboxStoreError> {
+        self.update(id, |message| {
+            message.status = OutboxStatus::Failed;
+            message.attempts += 1;
+            message.last_error = Some(error);
+        })
+    }
+}
+
+impl SnapshotSource for InMemoryOutboxStore {
+    fn write_snapshot(&self) -> Result<(), SnapshotError> {
+        let messages = self.messages.lock().expect("mutex poisoned");
+        let Some(log) = &self.log else {
+            return Ok(());
+        };
+
+        log.write_snapshot(|| messages.iter().map(OutboxRecord::from).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn message(event_type: &str) -> OutboxMessage {
+        OutboxMessage {
+            id: Uuid::new_v4(),
+            event_type: event_type.to_string(),
+            aggregate_id: "aggregate".to_string(),
+            payload: serde_json::json!({ "event": event_type }),
+            occurred_at: UNIX_EPOCH,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: UNIX_EPOCH,
+            last_error: None,
+            delivered_at: None,
+        }
+    }
+
+    fn snapshotting_store(dir: &tempfile::TempDir) -> InMemoryOutboxStore {
+        InMemoryOutboxStore::with_snapshots(Arc::new(
+            JsonSnapshotStore::open(dir.path(), "outbox").unwrap(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn restores_messages_from_the_snapshot_and_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = snapshotting_store(&dir);
+        let delivered = message("delivered");
+        let pending = message("pending");
+        store.append(vec![delivered.clone()]).unwrap();
+        store.write_snapshot().unwrap();
+        store.append(vec![pending.clone()]).unwrap();
+        store
+            .mark_delivered(&delivered.id, UNIX_EPOCH + Duration::from_secs(1))
+            .unwrap();
+        drop(store);
+
+        let restored = snapshotting_store(&dir);
+
+        let due = restored.due(UNIX_EPOCH, 10).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, pending.id);
+        let messages = restored.messages();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].status, OutboxStatus::Delivered);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod in_memory_outbox_store;
+mod outbox_record;
+pub mod outbox_relay_worker;
+
+pub use in_memory_outbox_store::InMemoryOutboxStore;
+pub use outbox_relay_worker::OutboxRelayWorker;
@@ -0,0 +1,60 @@
+use crate::{
+    application::outbox::{OutboxMessage, OutboxStatus},
+    domain::timestamps::{from_unix_millis, to_unix_millis},
+    infrastructure::persistence::json_snapshot::SnapshotError,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct OutboxRecord {
+    id: String,
+    event_type: String,
+    aggregate_id: String,
+    payload: serde_json::Value,
+    occurred_at_ms: i64,
+    status: String,
+    attempts: u32,
+    next_attempt_at_ms: i64,
+    last_error: Option<String>,
+    delivered_at_ms: Option<i64>,
+}
+
+impl From<&OutboxMessage> for OutboxRecord {
+    fn from(message: &OutboxMessage) -> Self {
+        Self {
+            id: message.id.to_string(),
+            event_type: message.event_type.clone(),
+            aggregate_id: message.aggregate_id.clone(),
+            payload: message.payload.clone(),
+            occurred_at_ms: to_unix_millis(message.occurred_at),
+            status: message.status.as_str().to_string(),
+            attempts: message.attempts,
+            next_attempt_at_ms: to_unix_millis(message.next_attempt_at),
+            last_error: message.last_error.clone(),
+            delivered_at_ms: message.delivered_at.map(to_unix_millis),
+        }
+    }
+}
+
+impl TryFrom<OutboxRecord> for OutboxMessage {
+    type Error = SnapshotError;
+
+    fn try_from(record: OutboxRecord) -> Result<Self, Self::Error> {
+        let corrupted =
+            || SnapshotError::Corrupted(format!("Corrupted outbox record {}", record.id));
+
+        Ok(Self {
+            id: Uuid::parse_str(&record.id).map_err(|_| corrupted())?,
+            status: OutboxStatus::parse(&record.status).ok_or_else(corrupted)?,
+            event_type: record.event_type,
+            aggregate_id: record.aggregate_id,
+            payload: record.payload,
+            occurred_at: from_unix_millis(record.occurred_at_ms),
+            attempts: record.attempts,
+            next_attempt_at: from_unix_millis(record.next_attempt_at_ms),
+            last_error: record.last_error,
+            delivered_at: record.delivered_at_ms.map(from_unix_millis),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::application::outbox::OutboxRelay;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+pub struct OutboxRelayWorker {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl OutboxRelayWorker {
+    pub fn spawn(relay: OutboxRelay, interval: Duration) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let worker_running = running.clone();
+
+        let handle = thread::spawn(move || {
+            while worker_running.load(Ordering::Acquire) {
+                if let Err(err) = relay.relay_due() {
+                    tracing::error!(error = %err, "Outbox relay failed");
+                }
+                thread::park_timeout(interval);
+            }
+        });
+
+        Self {
+            running,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.running.store(false, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for OutboxRelayWorker {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
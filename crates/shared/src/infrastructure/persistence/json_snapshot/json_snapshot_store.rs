@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    UnsupportedVersion { path: PathBuf, version: u32 },
+    Io(io::Error),
+    Json(serde_json::Error),
+    Corrupted(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::UnsupportedVersion { path, version } => write!(
+                f,
+                "Snapshot {} has unsupported format version {}",
+                path.display(),
+                version
+            ),
+            SnapshotError::Io(e) => write!(f, "{}", e),
+            SnapshotError::Json(e) => write!(f, "{}", e),
+            SnapshotError::Corrupted(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(e: io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(e: serde_json::Error) -> Self {
+        SnapshotError::Json(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotFile<S> {
+    version: u32,
+    state: S,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogEntry<R> {
+    Put(R),
+    Remove(String),
+}
+
+pub struct Recovered<S, E> {
+    pub snapshot: Option<S>,
+    pub log: Vec<E>,
+}
+
+pub struct JsonSnapshotStore {
+    directory: PathBuf,
+    snapshot_path: PathBuf,
+    temp_path: PathBuf,
+    log_path: PathBuf,
+    log: Mutex<File>,
+}
+
+impl JsonSnapshotStore {
+    pub fn open(directory: impl AsRef<Path>, name: &str) -> Result<Self, SnapshotError> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+        let log_path = directory.join(format!("{}.wal.jsonl", name));
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            snapshot_path: directory.join(format!("{}.snapshot.json", name)),
+            temp_path: directory.join(format!("{}.snapshot.json.tmp", name)),
+            log_path,
+            log: Mutex::new(log),
+        })
+    }
+
+    pub fn load<S: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+    ) -> Result<Recovered<S, E>, SnapshotError> {
+        let log_file = self.log.lock().expect("mutex poisoned");
+
+        let snapshot = match File::open(&self.snapshot_path) {
+            Ok(file) => {
+                let file: SnapshotFile<S> = serde_json::from_reader(BufReader::new(file))?;
+                if file.version != FORMAT_VERSION {
+                    return Err(SnapshotError::UnsupportedVersion {
+                        path: self.snapshot_path.clone(),
+                        version: file.version,
+                    });
+                }
+                Some(file.state)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let contents = fs::read_to_string(&self.log_path)?;
+        let complete = contents.rfind('\n').map_or(0, |end| end + 1);
+        if complete < contents.len() {
+            log_file.set_len(complete as u64)?;
+        }
+
+        let log = contents[..complete]
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Recovered { snapshot, log })
+    }
+
+    pub fn append<E: Serialize>(&self, entry: &E) -> Result<(), SnapshotError> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let mut log = self.log.lock().expect("mutex poisoned");
+        log.write_all(line.as_bytes())?;
+        log.sync_data()?;
+        Ok(())
+    }
+
+    pub fn write_snapshot<S: Serialize>(
+        &self,
+        capture: impl FnOnce() -> S,
+    ) -> Result<(), SnapshotError> {
+        let log = self.log.lock().expect("mutex poisoned");
+        let file = SnapshotFile {
+            version: FORMAT_VERSION,
+            state: capture(),
+        };
+
+        let mut temp = File::create(&self.temp_path)?;
+        serde_json::to_writer(&mut temp, &file)?;
+        temp.sync_all()?;
+        fs::rename(&self.temp_path, &self.snapshot_path)?;
+        File::open(&self.directory)?.sync_all()?;
+
+        log.set_len(0)?;
+        log.sync_all()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Entry {
+        key: String,
+        value: u32,
+    }
+
+    fn entry(key: &str, value: u32) -> Entry {
+        Entry {
+            key: key.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn starts_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonSnapshotStore::open(dir.path(), "entries").unwrap();
+
+        let recovered: Recovered<Vec<Entry>, Entry> = store.load().unwrap();
+
+        assert!(recovered.snapshot.is_none());
+        assert!(recovered.log.is_empty());
+    }
+
+    #[test]
+    fn replays_log_appended_after_the_last_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonSnapshotStore::open(dir.path(), "entries").unwrap();
+        store.append(&entry("a", 1)).unwrap();
+        store.write_snapshot(|| vec![entry("a", 1)]).unwrap();
+        store.append(&entry("b", 2)).unwrap();
+        drop(store);
+
+        let reopened = JsonSnapshotStore::open(dir.path(), "entries").unwrap();
+        let recovered: Recovered<Vec<Entry>, Entry> = reopened.load().unwrap();
+
+        assert_eq!(recovered.snapshot, Some(vec![entry("a", 1)]));
+        assert_eq!(recovered.log, vec![entry("b", 2)]);
+        assert!(!dir.path().join("entries.snapshot.json.tmp").exists());
+    }
+
+    #[test]
+    fn drops_a_torn_last_log_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonSnapshotStore::open(dir.path(), "entries").unwrap();
+        store.append(&entry("a", 1)).unwrap();
+        OpenOptions::new()
+            .append(true)
+            .open(dir.path().join("entries.wal.jsonl"))
+            .unwrap()
+            .write_all(b"{\"key\":\"b\",\"val")
+            .unwrap();
+
+        let recovered: Recovered<Vec<Entry>, Entry> = store.load().unwrap();
+        store.append(&entry("c", 3)).unwrap();
+        let reloaded: Recovered<Vec<Entry>, Entry> = store.load().unwrap();
+
+        assert_eq!(recovered.log, vec![entry("a", 1)]);
+        assert_eq!(reloaded.log, vec![entry("a", 1), entry("c", 3)]);
+    }
+
+    #[test]
+    fn rejects_snapshots_of_an_unknown_format_version() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("entries.snapshot.json"),
+            r#"{"version":99,"state":[]}"#,
+        )
+        .unwrap();
+        let store = JsonSnapshotStore::open(dir.path(), "entries").unwrap();
+
+        let result = store.load::<Vec<Entry>, Entry>();
+
+        assert!(matches!(
+            result,
+            Err(SnapshotError::UnsupportedVersion { version: 99, .. })
+        ));
+    }
+}
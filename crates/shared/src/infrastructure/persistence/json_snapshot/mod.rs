@@ -0,0 +1,5 @@
+pub mod json_snapshot_store;
+pub mod snapshot_worker;
+
+pub use json_snapshot_store::{JsonSnapshotStore, LogEntry, Recovered, SnapshotError};
+pub use snapshot_worker::{SnapshotSource, SnapshotWorker};
@@ -0,0 +1,83 @@
+use super::json_snapshot_store::SnapshotError;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+pub trait SnapshotSource: Send + Sync {
+    fn write_snapshot(&self) -> Result<(), SnapshotError>;
+}
+
+pub struct SnapshotWorker {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SnapshotWorker {
+    pub fn spawn(sources: Vec<Arc<dyn SnapshotSource>>, interval: Duration) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let worker_running = running.clone();
+
+        let handle = thread::spawn(move || {
+            loop {
+                thread::park_timeout(interval);
+                for source in &sources {
+                    if let Err(err) = source.write_snapshot() {
+                        tracing::error!(error = %err, "Snapshot failed");
+                    }
+                }
+                if !worker_running.load(Ordering::Acquire) {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            running,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.running.store(false, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for SnapshotWorker {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    struct CountingSource(AtomicUsize);
+
+    impl SnapshotSource for CountingSource {
+        fn write_snapshot(&self) -> Result<(), SnapshotError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_a_final_snapshot_when_stopped() {
+        let source = Arc::new(CountingSource(AtomicUsize::new(0)));
+        let mut worker = SnapshotWorker::spawn(vec![source.clone()], Duration::from_secs(3600));
+
+        worker.stop();
+
+        assert_eq!(source.0.load(Ordering::SeqCst), 1);
+    }
+}
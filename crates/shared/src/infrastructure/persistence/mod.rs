@@ -0,0 +1,2 @@
+pub mod json_snapshot;
+pub mod sqlite;
@@ -0,0 +1,338 @@
+use crate::domain::timestamps::to_unix_millis;
+use rusqlite::{Connection, params};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fmt, time::SystemTime};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS schema_migrations (
+        context TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        checksum TEXT NOT NULL,
+        applied_at INTEGER NOT NULL,
+        PRIMARY KEY (context, version)
+    );
+";
+
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+impl Migration {
+    pub fn checksum(&self) -> String {
+        Sha256::digest(self.sql.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+pub struct MigrationSet {
+    pub context: &'static str,
+    pub migrations: &'static [Migration],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub context: &'static str,
+    pub version: u32,
+    pub name: &'static str,
+}
+
+#[derive(Debug)]
+pub enum MigrationError {
+    SchemaAhead { context: &'static str, version: u32 },
+    ChecksumMismatch { context: &'static str, version: u32 },
+    OutOfOrder { context: &'static str, version: u32 },
+    Sqlite(rusqlite::Error),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::SchemaAhead { context, version } => write!(
+                f,
+                "Database schema is ahead of this binary: unknown {} migration {}",
+                context, version
+            ),
+            MigrationError::ChecksumMismatch { context, version } => write!(
+                f,
+                "Applied {} migration {} does not match the embedded one",
+                context, version
+            ),
+            MigrationError::OutOfOrder { context, version } => write!(
+                f,
+                "{} migration {} is not in ascending version order",
+                context, version
+            ),
+            MigrationError::Sqlite(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+impl From<rusqlite::Error> for MigrationError {
+    fn from(e: rusqlite::Error) -> Self {
+        MigrationError::Sqlite(e)
+    }
+}
+
+pub fn migrate(
+    connection: &mut Connection,
+    set: &MigrationSet,
+) -> Result<Vec<AppliedMigration>, MigrationError> {
+    migrate_all(connection, &[set])
+}
+
+pub fn migrate_all(
+    connection: &mut Connection,
+    sets: &[&MigrationSet],
+) -> Result<Vec<AppliedMigration>, MigrationError> {
+    connection.execute_batch(SCHEMA)?;
+
+    let mut verified = Vec::with_capacity(sets.len());
+    for set in sets {
+        verified.push((*set, verify(connection, set)?));
+    }
+
+    let mut newly_applied = Vec::new();
+    for (set, applied) in verified {
+        newly_applied.extend(apply_pending(connection, set, &applied)?);
+    }
+
+    Ok(newly_applied)
+}
+
+fn verify(
+    connection: &Connection,
+    set: &MigrationSet,
+) -> Result<HashMap<u32, String>, MigrationError> {
+    let known = known_versions(set)?;
+    let applied = applied_checksums(connection, set.context)?;
+
+    for (version, checksum) in &applied {
+        match known.get(version) {
+            None => {
+                return Err(MigrationError::SchemaAhead {
+                    context: set.context,
+                    version: *version,
+                });
+            }
+            Some(migration) if &migration.checksum() != checksum => {
+                return Err(MigrationError::ChecksumMismatch {
+                    context: set.context,
+                    version: *version,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(applied)
+}
+
+fn apply_pending(
+    connection: &mut Connection,
+    set: &MigrationSet,
+    applied: &HashMap<u32, String>,
+) -> Result<Vec<AppliedMigration>, MigrationError> {
+    let mut newly_applied = Vec::new();
+    for migration in set.migrations {
+        if applied.contains_key(&migration.version) {
+            continue;
+        }
+
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(migration.sql)?;
+        transaction.execute(
+            "INSERT INTO schema_migrations (context, version, name, checksum, applied_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                set.context,
+                migration.version,
+                migration.name,
+                migration.checksum(),
+                to_unix_millis(SystemTime::now()),
+            ],
+        )?;
+        transaction.commit()?;
+
+        newly_applied.push(AppliedMigration {
+            context: set.context,
+            version: migration.version,
+            name: migration.name,
+        });
+    }
+
+    Ok(newly_applied)
+}
+
+fn known_versions(set: &MigrationSet) -> Result<HashMap<u32, &Migration>, MigrationError> {
+    let mut previous = 0;
+    let mut known = HashMap::new();
+
+    for migration in set.migrations {
+        if migration.version <= previous {
+            return Err(MigrationError::OutOfOrder {
+                context: set.context,
+                version: migration.version,
+            });
+        }
+        previous = migration.version;
+        known.insert(migration.version, migration);
+    }
+
+    Ok(known)
+}
+
+fn applied_checksums(
+    connection: &Connection,
+    context: &str,
+) -> rusqlite::Result<HashMap<u32, String>> {
+    connection
+        .prepare("SELECT version, checksum FROM schema_migrations WHERE context = ?1")?
+        .query_map(params![context], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteDatabase;
+
+    const FIRST: Migration = Migration {
+        version: 1,
+        name: "create_things",
+        sql: "CREATE TABLE things (id TEXT PRIMARY KEY);",
+    };
+    const SECOND: Migration = Migration {
+        version: 2,
+        name: "add_thing_name",
+        sql: "ALTER TABLE things ADD COLUMN name TEXT;",
+    };
+
+    const V1: MigrationSet = MigrationSet {
+        context: "things",
+        migrations: &[FIRST],
+    };
+    const V2: MigrationSet = MigrationSet {
+        context: "things",
+        migrations: &[FIRST, SECOND],
+    };
+
+    #[test]
+    fn applies_pending_migrations_once_in_order() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+
+        let first_run = database.migrate(&V2).unwrap();
+        let second_run = database.migrate(&V2).unwrap();
+
+        assert_eq!(
+            first_run.iter().map(|m| m.version).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(second_run.is_empty());
+        database
+            .connection()
+            .execute("INSERT INTO things (id, name) VALUES ('a', 'b')", [])
+            .unwrap();
+    }
+
+    #[test]
+    fn applies_only_new_migrations_on_upgrade() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&V1).unwrap();
+
+        let applied = database.migrate(&V2).unwrap();
+
+        assert_eq!(
+            applied,
+            vec![AppliedMigration {
+                context: "things",
+                version: 2,
+                name: "add_thing_name"
+            }]
+        );
+    }
+
+    #[test]
+    fn refuses_a_schema_ahead_of_the_binary() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&V2).unwrap();
+
+        let result = database.migrate(&V1);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::SchemaAhead {
+                context: "things",
+                version: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn refuses_edited_migrations() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&V1).unwrap();
+        let edited = MigrationSet {
+            context: "things",
+            migrations: &[Migration {
+                version: 1,
+                name: "create_things",
+                sql: "CREATE TABLE things (id INTEGER PRIMARY KEY);",
+            }],
+        };
+
+        let result = database.migrate(&edited);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::ChecksumMismatch { version: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn applies_nothing_when_any_set_is_ahead_of_the_binary() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&V2).unwrap();
+        let other = MigrationSet {
+            context: "others",
+            migrations: &[Migration {
+                version: 1,
+                name: "create_others",
+                sql: "CREATE TABLE others (id TEXT PRIMARY KEY);",
+            }],
+        };
+
+        let result = database.migrate_all(&[&other, &V1]);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::SchemaAhead {
+                context: "things",
+                version: 2
+            })
+        ));
+        assert_eq!(database.migrate(&other).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn keeps_contexts_independent() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        database.migrate(&V2).unwrap();
+        let other = MigrationSet {
+            context: "others",
+            migrations: &[Migration {
+                version: 1,
+                name: "create_others",
+                sql: "CREATE TABLE others (id TEXT PRIMARY KEY);",
+            }],
+        };
+
+        assert_eq!(database.migrate(&other).unwrap().len(), 1);
+    }
+}
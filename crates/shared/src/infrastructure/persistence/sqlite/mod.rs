@@ -0,0 +1,9 @@
+pub mod migrations;
+pub mod sqlite_database;
+pub mod sqlite_outbox_store;
+pub mod sqlite_unit_of_work;
+
+pub use migrations::{AppliedMigration, Migration, MigrationError, MigrationSet};
+pub use sqlite_database::SqliteDatabase;
+pub use sqlite_outbox_store::SqliteOutboxStore;
+pub use sqlite_unit_of_work::{SqliteUnitOfWork, SqliteUnitOfWorkFactory};
@@ -0,0 +1,76 @@
+use crate::infrastructure::persistence::sqlite::migrations::{
+    self, AppliedMigration, MigrationError, MigrationSet,
+};
+use rusqlite::{Connection, ErrorCode, Transaction};
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+pub struct SqliteDatabase {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteDatabase {
+    const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.pragma_update(None, "journal_mode", "WAL")?;
+        Self::initialize(connection)
+    }
+
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::initialize(Connection::open_in_memory()?)
+    }
+
+    fn initialize(connection: Connection) -> rusqlite::Result<Self> {
+        connection.busy_timeout(Self::BUSY_TIMEOUT)?;
+        connection.pragma_update(None, "foreign_keys", "ON")?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    pub fn migrate(&self, set: &MigrationSet) -> Result<Vec<AppliedMigration>, MigrationError> {
+        migrations::migrate(&mut self.connection(), set)
+    }
+
+    pub fn migrate_all(
+        &self,
+        sets: &[&MigrationSet],
+    ) -> Result<Vec<AppliedMigration>, MigrationError> {
+        migrations::migrate_all(&mut self.connection(), sets)
+    }
+
+    pub fn is_unavailable(error: &rusqlite::Error) -> bool {
+        matches!(
+            error.sqlite_error_code(),
+            Some(
+                ErrorCode::DatabaseBusy
+                    | ErrorCode::DatabaseLocked
+                    | ErrorCode::CannotOpen
+                    | ErrorCode::SystemIoFailure
+                    | ErrorCode::DiskFull
+                    | ErrorCode::OutOfMemory
+            )
+        )
+    }
+
+    pub fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().expect("mutex poisoned")
+    }
+
+    pub fn transaction<T>(
+        &self,
+        work: impl FnOnce(&Transaction<'_>) -> rusqlite::Result<T>,
+    ) -> rusqlite::Result<T> {
+        let mut connection = self.connection();
+        let transaction = connection.transaction()?;
+        let result = work(&transaction)?;
+        transaction.commit()?;
+        Ok(result)
+    }
+}
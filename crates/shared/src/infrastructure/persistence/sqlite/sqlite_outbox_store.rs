@@ -0,0 +1,236 @@
+use crate::{
+    application::{
+        errors::outbox_store::OutboxStoreError,
+        outbox::{OutboxMessage, OutboxStatus},
+        ports::outbox_store::OutboxStorePort,
+    },
+    domain::{
+        events::DomainEvent,
+        timestamps::{from_unix_millis, to_unix_millis},
+    },
+    infrastructure::persistence::sqlite::{Migration, MigrationSet, SqliteDatabase},
+};
+use rusqlite::{Connection, Row, params, types::Type};
+use std::{sync::Arc, time::SystemTime};
+use uuid::Uuid;
+
+pub struct SqliteOutboxStore {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteOutboxStore {
+    pub const MIGRATIONS: MigrationSet = MigrationSet {
+        context: "outbox",
+        migrations: &[Migration {
+            version: 1,
+            name: "create_outbox",
+            sql: include_str!("../../../../migrations/0001_create_outbox.sql"),
+        }],
+    };
+
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+
+    pub fn record_events(
+        connection: &Connection,
+        events: &[Arc<dyn DomainEvent>],
+    ) -> rusqlite::Result<()> {
+        for event in events {
+            Self::insert(connection, &OutboxMessage::from_event(event.as_ref()))?;
+        }
+        Ok(())
+    }
+
+    fn insert(connection: &Connection, message: &OutboxMessage) -> rusqlite::Result<()> {
+        connection.execute(
+            "INSERT INTO outbox (id, event_type, aggregate_id, payload, occurred_at, status, attempts, next_attempt_at, last_error, delivered_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                message.id.to_string(),
+                message.event_type,
+                message.aggregate_id,
+                message.payload.to_string(),
+                to_unix_millis(message.occurred_at),
+                message.status.as_str(),
+                message.attempts,
+                to_unix_millis(message.next_attempt_at),
+                message.last_error,
+                message.delivered_at.map(to_unix_millis),
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn from_row(row: &Row<'_>) -> rusqlite::Result<OutboxMessage> {
+        let id: String = row.get("id")?;
+        let payload: String = row.get("payload")?;
+        let status: String = row.get("status")?;
+        let corrupted = |column: usize, err: Box<dyn std::error::Error + Send + Sync>| {
+            rusqlite::Error::FromSqlConversionFailure(column, Type::Text, err)
+        };
+
+        Ok(OutboxMessage {
+            id: Uuid::parse_str(&id).map_err(|e| corrupted(0, e.into()))?,
+            event_type: row.get("event_type")?,
+            aggregate_id: row.get("aggregate_id")?,
+            payload: serde_json::from_str(&payload).map_err(|e| corrupted(3, e.into()))?,
+            occurred_at: from_unix_millis(row.get("occurred_at")?),
+            status: OutboxStatus::parse(&status)
+                .ok_or_else(|| corrupted(5, format!("Unknown outbox status {}", status).into()))?,
+            attempts: row.get("attempts")?,
+            next_attempt_at: from_unix_millis(row.get("next_attempt_at")?),
+            last_error: row.get("last_error")?,
+            delivered_at: row
+                .get::<_, Option<i64>>("delivered_at")?
+                .map(from_unix_millis),
+        })
+    }
+
+    fn update(&self, sql: &str, params: impl rusqlite::Params) -> Result<(), OutboxStoreError> {
+        let updated = self
+            .database
+            .connection()
+            .execute(sql, params)
+            .map_err(|e| OutboxStoreError(e.to_string()))?;
+
+        if updated == 0 {
+            return Err(OutboxStoreError("Outbox message not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl OutboxStorePort for SqliteOutboxStore {
+    fn append(&self, messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError> {
+        self.database
+            .transaction(|tx| {
+                for message in &messages {
+                    Self::insert(tx, message)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| OutboxStoreError(e.to_string()))
+    }
+
+    fn due(&self, now: SystemTime, limit: usize) -> Result<Vec<OutboxMessage>, OutboxStoreError> {
+        let connection = self.database.connection();
+        let mut statement = connection
+            .prepare(
+                "SELECT * FROM outbox
+                 WHERE status = ?1 AND next_attempt_at <= ?2
+                 ORDER BY occurred_at, rowid
+                 LIMIT ?3",
+            )
+            .map_err(|e| OutboxStoreError(e.to_string()))?;
+
+        statement
+            .query_map(
+                params![
+                    OutboxStatus::Pending.as_str(),
+                    to_unix_millis(now),
+                    limit as i64
+                ],
+                Self::from_row,
+            )
+            .and_then(|rows| rows.collect())
+            .map_err(|e| OutboxStoreError(e.to_string()))
+    }
+
+    fn mark_delivered(&self, id: &Uuid, delivered_at: SystemTime) -> Result<(), OutboxStoreError> {
+        self.update(
+            "UPDATE outbox SET status = ?2, attempts = attempts + 1, delivered_at = ?3, last_error = NULL WHERE id = ?1",
+            params![
+                id.to_string(),
+                OutboxStatus::Delivered.as_str(),
+                to_unix_millis(delivered_at)
+            ],
+        )
+    }
+
+    fn mark_retry(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: SystemTime,
+    ) -> Result<(), OutboxStoreError> {
+        self.update(
+            "UPDATE outbox SET attempts = attempts + 1, next_attempt_at = ?2, last_error = ?3 WHERE id = ?1",
+            params![id.to_string(), to_unix_millis(next_attempt_at), error],
+        )
+    }
+
+    fn mark_failed(&self, id: &Uuid, error: String) -> Result<(), OutboxStoreError> {
+        self.update(
+            "UPDATE outbox SET status = ?2, attempts = attempts + 1, last_error = ?3 WHERE id = ?1",
+            params![id.to_string(), OutboxStatus::Failed.as_str(), error],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        any::Any,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    #[derive(Debug)]
+    struct Happened;
+
+    impl DomainEvent for Happened {
+        fn event_type(&self) -> &str {
+            "test.happened"
+        }
+
+        fn aggregate_id(&self) -> String {
+            "aggregate".to_string()
+        }
+
+        fn occurred_at(&self) -> SystemTime {
+            UNIX_EPOCH + Duration::from_secs(10)
+        }
+
+        fn payload(&self) -> serde_json::Value {
+            serde_json::json!({ "answer": 42 })
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn tracks_delivery_state_of_recorded_events() {
+        let database = Arc::new(SqliteDatabase::open_in_memory().unwrap());
+        database.migrate(&SqliteOutboxStore::MIGRATIONS).unwrap();
+        let store = SqliteOutboxStore::new(database.clone());
+        database
+            .transaction(|tx| SqliteOutboxStore::record_events(tx, &[Arc::new(Happened)]))
+            .unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(20);
+
+        let due = store.due(now, 10).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].payload, serde_json::json!({ "answer": 42 }));
+
+        store
+            .mark_retry(&due[0].id, "boom".to_string(), now + Duration::from_secs(5))
+            .unwrap();
+        assert!(store.due(now, 10).unwrap().is_empty());
+
+        let retried = store.due(now + Duration::from_secs(5), 10).unwrap();
+        assert_eq!(retried[0].attempts, 1);
+        assert_eq!(retried[0].last_error.as_deref(), Some("boom"));
+
+        store.mark_delivered(&retried[0].id, now).unwrap();
+        assert!(
+            store
+                .due(now + Duration::from_secs(60), 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
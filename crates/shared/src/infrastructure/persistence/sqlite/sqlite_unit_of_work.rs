@@ -0,0 +1,189 @@
+use crate::{
+    application::{
+        errors::unit_of_work::UnitOfWorkError,
+        ports::unit_of_work::{UnitOfWork, UnitOfWorkPort},
+    },
+    infrastructure::{blocking::run_blocking, persistence::sqlite::SqliteDatabase},
+};
+use async_trait::async_trait;
+use rusqlite::Transaction;
+use std::{any::Any, sync::Arc};
+
+type Step = Box<dyn FnOnce(&Transaction<'_>) -> Result<(), UnitOfWorkError> + Send>;
+
+pub struct SqliteUnitOfWork {
+    database: Arc<SqliteDatabase>,
+    steps: Vec<Step>,
+    after_commit: Vec<Box<dyn FnOnce() + Send>>,
+}
+
+impl SqliteUnitOfWork {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self {
+            database,
+            steps: Vec::new(),
+            after_commit: Vec::new(),
+        }
+    }
+
+    pub fn of<'a>(
+        unit_of_work: &'a mut dyn UnitOfWork,
+        database: &Arc<SqliteDatabase>,
+    ) -> Result<&'a mut Self, UnitOfWorkError> {
+        let sqlite = unit_of_work
+            .as_any_mut()
+            .downcast_mut::<Self>()
+            .ok_or_else(|| UnitOfWorkError::Failed("Expected a SQLite unit of work".to_string()))?;
+
+        if !Arc::ptr_eq(&sqlite.database, database) {
+            return Err(UnitOfWorkError::Failed(
+                "Unit of work belongs to another database".to_string(),
+            ));
+        }
+
+        Ok(sqlite)
+    }
+
+    pub fn register(
+        &mut self,
+        step: impl FnOnce(&Transaction<'_>) -> Result<(), UnitOfWorkError> + Send + 'static,
+    ) {
+        self.steps.push(Box::new(step));
+    }
+
+    fn commit_blocking(self) -> Result<(), UnitOfWorkError> {
+        let failed = |e: rusqlite::Error| UnitOfWorkError::Failed(e.to_string());
+        let mut connection = self.database.connection();
+        let transaction = connection.transaction().map_err(failed)?;
+
+        for step in self.steps {
+            step(&transaction)?;
+        }
+
+        transaction.commit().map_err(failed)?;
+        drop(connection);
+        for hook in self.after_commit {
+            hook();
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn on_commit(&mut self, hook: Box<dyn FnOnce() + Send>) {
+        self.after_commit.push(hook);
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        run_blocking(move || self.commit_blocking()).await
+    }
+
+    fn rollback(self: Box<Self>) {}
+}
+
+pub struct SqliteUnitOfWorkFactory {
+    database: Arc<SqliteDatabase>,
+}
+
+impl SqliteUnitOfWorkFactory {
+    pub fn new(database: Arc<SqliteDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+impl UnitOfWorkPort for SqliteUnitOfWorkFactory {
+    fn begin(&self) -> Box<dyn UnitOfWork> {
+        Box::new(SqliteUnitOfWork::new(self.database.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::params;
+
+    fn database() -> Arc<SqliteDatabase> {
+        let database = Arc::new(SqliteDatabase::open_in_memory().unwrap());
+        database
+            .connection()
+            .execute_batch("CREATE TABLE things (id TEXT PRIMARY KEY);")
+            .unwrap();
+        database
+    }
+
+    fn insert(unit_of_work: &mut dyn UnitOfWork, database: &Arc<SqliteDatabase>, id: &'static str) {
+        SqliteUnitOfWork::of(unit_of_work, database)
+            .unwrap()
+            .register(move |tx| {
+                tx.execute("INSERT INTO things (id) VALUES (?1)", params![id])
+                    .map(|_| ())
+                    .map_err(|e| UnitOfWorkError::Failed(e.to_string()))
+            });
+    }
+
+    fn count(database: &SqliteDatabase) -> i64 {
+        database
+            .connection()
+            .query_row("SELECT COUNT(*) FROM things", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn commits_all_steps_in_one_transaction() {
+        let database = database();
+        let mut unit_of_work = SqliteUnitOfWorkFactory::new(database.clone()).begin();
+        insert(unit_of_work.as_mut(), &database, "a");
+        insert(unit_of_work.as_mut(), &database, "b");
+
+        unit_of_work.commit().await.unwrap();
+
+        assert_eq!(count(&database), 2);
+    }
+
+    #[tokio::test]
+    async fn rolls_back_every_step_when_one_fails() {
+        let database = database();
+        let mut unit_of_work = SqliteUnitOfWorkFactory::new(database.clone()).begin();
+        insert(unit_of_work.as_mut(), &database, "a");
+        insert(unit_of_work.as_mut(), &database, "a");
+
+        let result = unit_of_work.commit().await;
+
+        assert!(matches!(result, Err(UnitOfWorkError::Failed(_))));
+        assert_eq!(count(&database), 0);
+    }
+
+    #[tokio::test]
+    async fn runs_commit_hooks_only_after_a_successful_commit() {
+        let database = database();
+        let committed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let factory = SqliteUnitOfWorkFactory::new(database.clone());
+        for id in ["a", "a"] {
+            let mut unit_of_work = factory.begin();
+            insert(unit_of_work.as_mut(), &database, id);
+            let committed = committed.clone();
+            unit_of_work.on_commit(Box::new(move || {
+                committed.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }));
+            let _ = unit_of_work.commit().await;
+        }
+
+        assert_eq!(committed.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn refuses_work_for_another_database() {
+        let database = database();
+        let mut unit_of_work = SqliteUnitOfWorkFactory::new(database).begin();
+
+        let result = SqliteUnitOfWork::of(unit_of_work.as_mut(), &self::database());
+
+        assert!(result.is_err());
+    }
+}
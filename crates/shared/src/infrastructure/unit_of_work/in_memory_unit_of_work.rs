@@ -0,0 +1,308 @@
+use crate::application::{
+    errors::unit_of_work::UnitOfWorkError,
+    outbox::OutboxMessage,
+    ports::{
+        outbox_store::OutboxStorePort,
+        unit_of_work::{UnitOfWork, UnitOfWorkPort},
+    },
+};
+use async_trait::async_trait;
+use std::{any::Any, sync::Arc};
+
+pub type Undo = Box<dyn FnOnce() -> Result<(), UnitOfWorkError> + Send>;
+type Step = Box<dyn FnOnce() -> Result<Undo, UnitOfWorkError> + Send>;
+type Hook = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+pub struct InMemoryUnitOfWork {
+    steps: Vec<Step>,
+    outbox: Vec<(Arc<dyn OutboxStorePort>, Vec<OutboxMessage>)>,
+    after_commit: Vec<Hook>,
+}
+
+impl InMemoryUnitOfWork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn of(unit_of_work: &mut dyn UnitOfWork) -> Result<&mut Self, UnitOfWorkError> {
+        unit_of_work
+            .as_any_mut()
+            .downcast_mut::<Self>()
+            .ok_or_else(|| {
+                UnitOfWorkError::Failed("Expected an in-memory unit of work".to_string())
+            })
+    }
+
+    pub fn register(
+        &mut self,
+        step: impl FnOnce() -> Result<Undo, UnitOfWorkError> + Send + 'static,
+    ) {
+        self.steps.push(Box::new(step));
+    }
+
+    pub fn append_to_outbox(
+        &mut self,
+        outbox: Arc<dyn OutboxStorePort>,
+        messages: Vec<OutboxMessage>,
+    ) {
+        if messages.is_empty() {
+            return;
+        }
+
+        match self
+            .outbox
+            .iter_mut()
+            .find(|(pending, _)| Arc::ptr_eq(pending, &outbox))
+        {
+            Some((_, pending)) => pending.extend(messages),
+            None => self.outbox.push((outbox, messages)),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn on_commit(&mut self, hook: Box<dyn FnOnce() + Send>) {
+        self.after_commit.push(hook);
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        let mut applied: Vec<Undo> = Vec::with_capacity(self.steps.len());
+        let undo_all = |applied: Vec<Undo>| {
+            applied
+                .into_iter()
+                .rev()
+                .map(|undo| undo())
+                .fold(Ok(()), Result::and)
+        };
+
+        for step in self.steps {
+            match step() {
+                Ok(undo) => applied.push(undo),
+                Err(err) => {
+                    undo_all(applied)?;
+                    return Err(err);
+                }
+            }
+        }
+
+        for (outbox, messages) in self.outbox {
+            if let Err(err) = outbox.append(messages) {
+                undo_all(applied)?;
+                return Err(UnitOfWorkError::Failed(err.0));
+            }
+        }
+
+        for hook in self.after_commit {
+            hook();
+        }
+
+        Ok(())
+    }
+
+    fn rollback(self: Box<Self>) {}
+}
+
+#[derive(Default)]
+pub struct InMemoryUnitOfWorkFactory;
+
+impl InMemoryUnitOfWorkFactory {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl UnitOfWorkPort for InMemoryUnitOfWorkFactory {
+    fn begin(&self) -> Box<dyn UnitOfWork> {
+        Box::new(InMemoryUnitOfWork::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::errors::outbox_store::OutboxStoreError, domain::events::DomainEvent,
+        infrastructure::outbox::InMemoryOutboxStore,
+    };
+    use std::{
+        sync::{Arc, Mutex},
+        time::SystemTime,
+    };
+
+    struct FailingOutbox;
+
+    impl OutboxStorePort for FailingOutbox {
+        fn append(&self, _messages: Vec<OutboxMessage>) -> Result<(), OutboxStoreError> {
+            Err(OutboxStoreError("outbox is full".to_string()))
+        }
+
+        fn due(
+            &self,
+            _now: SystemTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboxMessage>, OutboxStoreError> {
+            Ok(Vec::new())
+        }
+
+        fn mark_delivered(
+            &self,
+            _id: &uuid::Uuid,
+            _at: SystemTime,
+        ) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_retry(
+            &self,
+            _id: &uuid::Uuid,
+            _error: String,
+            _next_attempt_at: SystemTime,
+        ) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+
+        fn mark_failed(&self, _id: &uuid::Uuid, _error: String) -> Result<(), OutboxStoreError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Happened;
+
+    impl DomainEvent for Happened {
+        fn event_type(&self) -> &str {
+            "test.happened"
+        }
+
+        fn aggregate_id(&self) -> String {
+            "aggregate".to_string()
+        }
+
+        fn occurred_at(&self) -> SystemTime {
+            SystemTime::UNIX_EPOCH
+        }
+
+        fn payload(&self) -> serde_json::Value {
+            serde_json::json!({ "ok": true })
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn push(log: &Arc<Mutex<Vec<&'static str>>>, value: &'static str) -> Undo {
+        log.lock().unwrap().push(value);
+        let log = log.clone();
+        Box::new(move || {
+            log.lock().unwrap().retain(|entry| *entry != value);
+            Ok(())
+        })
+    }
+
+    #[tokio::test]
+    async fn applies_steps_and_hooks_on_commit() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unit_of_work: Box<dyn UnitOfWork> = InMemoryUnitOfWorkFactory::new().begin();
+        let in_memory = InMemoryUnitOfWork::of(unit_of_work.as_mut()).unwrap();
+        let (first, second, hook) = (log.clone(), log.clone(), log.clone());
+        in_memory.register(move || Ok(push(&first, "first")));
+        in_memory.register(move || Ok(push(&second, "second")));
+        in_memory.on_commit(Box::new(move || hook.lock().unwrap().push("hook")));
+
+        unit_of_work.commit().await.unwrap();
+
+        assert_eq!(*log.lock().unwrap(), vec!["first", "second", "hook"]);
+    }
+
+    #[tokio::test]
+    async fn undoes_applied_steps_when_a_later_step_fails() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unit_of_work: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        let in_memory = InMemoryUnitOfWork::of(unit_of_work.as_mut()).unwrap();
+        let (first, hook) = (log.clone(), log.clone());
+        in_memory.register(move || Ok(push(&first, "first")));
+        in_memory.register(|| Err(UnitOfWorkError::Conflict("stale".to_string())));
+        in_memory.on_commit(Box::new(move || hook.lock().unwrap().push("hook")));
+
+        let result = unit_of_work.commit().await;
+
+        assert!(matches!(result, Err(UnitOfWorkError::Conflict(_))));
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_undo_failures_instead_of_the_step_failure() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unit_of_work: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        let in_memory = InMemoryUnitOfWork::of(unit_of_work.as_mut()).unwrap();
+        let first = log.clone();
+        in_memory.register(move || {
+            first.lock().unwrap().push("first");
+            Ok(Box::new(|| Err(UnitOfWorkError::Failed("log is full".to_string()))) as Undo)
+        });
+        in_memory.register(|| Err(UnitOfWorkError::Conflict("stale".to_string())));
+
+        let result = unit_of_work.commit().await;
+
+        assert!(
+            matches!(result, Err(UnitOfWorkError::Failed(message)) if message == "log is full")
+        );
+    }
+
+    #[test]
+    fn rollback_discards_registered_work() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unit_of_work: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        let first = log.clone();
+        InMemoryUnitOfWork::of(unit_of_work.as_mut())
+            .unwrap()
+            .register(move || Ok(push(&first, "first")));
+
+        unit_of_work.rollback();
+
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn appends_outbox_messages_only_when_committed() {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        let mut committed: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        InMemoryUnitOfWork::of(committed.as_mut())
+            .unwrap()
+            .append_to_outbox(outbox.clone(), vec![OutboxMessage::from_event(&Happened)]);
+        let mut failed: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        let in_memory = InMemoryUnitOfWork::of(failed.as_mut()).unwrap();
+        in_memory.append_to_outbox(outbox.clone(), vec![OutboxMessage::from_event(&Happened)]);
+        in_memory.register(|| Err(UnitOfWorkError::Conflict("stale".to_string())));
+
+        committed.commit().await.unwrap();
+        let _ = failed.commit().await;
+
+        assert_eq!(outbox.messages().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn undoes_applied_steps_when_the_outbox_rejects_messages() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unit_of_work: Box<dyn UnitOfWork> = Box::new(InMemoryUnitOfWork::new());
+        let in_memory = InMemoryUnitOfWork::of(unit_of_work.as_mut()).unwrap();
+        let first = log.clone();
+        in_memory.register(move || Ok(push(&first, "first")));
+        in_memory.append_to_outbox(
+            Arc::new(FailingOutbox),
+            vec![OutboxMessage::from_event(&Happened)],
+        );
+
+        let result = unit_of_work.commit().await;
+
+        assert!(matches!(result, Err(UnitOfWorkError::Failed(_))));
+        assert!(log.lock().unwrap().is_empty());
+    }
+}
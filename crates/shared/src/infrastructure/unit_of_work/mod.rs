@@ -0,0 +1,3 @@
+pub mod in_memory_unit_of_work;
+
+pub use in_memory_unit_of_work::{InMemoryUnitOfWork, InMemoryUnitOfWorkFactory, Undo};